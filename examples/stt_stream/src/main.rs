@@ -0,0 +1,154 @@
+use std::{
+    error::Error,
+    io::{stdout, Write},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use cpal::{
+    traits::{DeviceTrait, HostTrait, StreamTrait},
+    FromSample, SampleFormat, SampleRate, SizedSample, StreamConfig, SupportedStreamConfig,
+};
+use futures_util::{stream, StreamExt};
+use ibm_watson::{
+    auth::IamAuthenticator,
+    stt::{models::ModelID, SpeechToText},
+};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+
+use clap::Parser;
+
+/// The sampling rate that the broadband and multimedia models expect. Audio that is recorded at a
+/// higher rate is downsampled by the service
+const SAMPLE_RATE: SampleRate = SampleRate(16000);
+
+/// Transcribing speech from the microphone with the IBM Watson Speech To Text API
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    /// Your API Key for that particular service
+    #[clap(short, long, value_parser)]
+    api_key: String,
+    /// The Watson service url
+    #[clap(short, long, value_parser)]
+    service_url: String,
+    /// The model to transcribe with, for example en-GB_Multimedia
+    #[clap(short, long, value_parser)]
+    model: Option<String>,
+    /// How many seconds to record for
+    #[clap(short = 'd', long, value_parser, default_value_t = 10)]
+    seconds: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    let auth = IamAuthenticator::new(args.api_key).await?;
+    let stt = SpeechToText::new(&auth, &args.service_url);
+
+    let device = cpal::default_host()
+        .default_input_device()
+        .ok_or("there is no microphone to record from")?;
+    let config = input_config(&device)?;
+    // The service is sent 16-bit PCM of a single channel, at whatever rate the microphone records
+    let content_type = format!(
+        "audio/l16;rate={};channels=1;endianness=little-endian",
+        config.sample_rate().0
+    );
+
+    let (sender, receiver) = unbounded_channel();
+    let recording = match config.sample_format() {
+        SampleFormat::I16 => record::<i16>(&device, &config.into(), sender)?,
+        SampleFormat::U16 => record::<u16>(&device, &config.into(), sender)?,
+        SampleFormat::F32 => record::<f32>(&device, &config.into(), sender)?,
+        format => return Err(format!("the microphone records {format:?} samples").into()),
+    };
+    let audio = stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|chunk| (chunk, receiver))
+    });
+
+    let mut results = stt
+        .recognize_stream(audio, &content_type, args.model.map(ModelID::from))
+        .await?;
+    recording.play()?;
+    eprintln!("Recording for {} seconds, start speaking", args.seconds);
+
+    // Dropping the recording ends the audio stream, after which the service sends the final
+    // transcript and closes the connection
+    let mut recording = Some(recording);
+    let stop = tokio::time::sleep(Duration::from_secs(args.seconds));
+    tokio::pin!(stop);
+    loop {
+        tokio::select! {
+            _ = &mut stop, if recording.is_some() => {
+                recording = None;
+                eprintln!("\nStopped recording");
+            }
+            results = results.next() => {
+                let results = match results {
+                    Some(results) => results?,
+                    None => break,
+                };
+                for result in results.results {
+                    let transcript = match result.alternatives.first() {
+                        Some(alternative) => alternative.transcript.trim(),
+                        None => continue,
+                    };
+                    // Interim transcripts are overwritten in place until the final one arrives
+                    if result.final_results {
+                        println!("\r\x1b[2K{transcript}");
+                    } else {
+                        print!("\r\x1b[2K{transcript}");
+                        stdout().flush()?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The 16 kHz mono configuration of the microphone if it has one, as that is what the models
+/// expect. Otherwise, its default configuration
+fn input_config(device: &cpal::Device) -> Result<SupportedStreamConfig, Box<dyn Error>> {
+    let preferred = device
+        .supported_input_configs()?
+        .filter(|range| {
+            range.channels() == 1
+                && range.min_sample_rate() <= SAMPLE_RATE
+                && SAMPLE_RATE <= range.max_sample_rate()
+        })
+        .max_by_key(|range| range.sample_format() == SampleFormat::I16)
+        .map(|range| range.with_sample_rate(SAMPLE_RATE));
+    match preferred {
+        Some(config) => Ok(config),
+        None => Ok(device.default_input_config()?),
+    }
+}
+
+/// Sends what the microphone records to `audio` as 16-bit little-endian PCM. Only the first
+/// channel is kept
+fn record<T>(
+    device: &cpal::Device,
+    config: &StreamConfig,
+    audio: UnboundedSender<Bytes>,
+) -> Result<cpal::Stream, cpal::BuildStreamError>
+where
+    T: SizedSample,
+    i16: FromSample<T>,
+{
+    let channels = usize::from(config.channels);
+    device.build_input_stream(
+        config,
+        move |data: &[T], _: &cpal::InputCallbackInfo| {
+            let chunk: Vec<u8> = data
+                .chunks(channels)
+                .flat_map(|frame| frame[0].to_sample::<i16>().to_le_bytes())
+                .collect();
+            // The receiver is only dropped once the transcription has ended
+            let _ = audio.send(Bytes::from(chunk));
+        },
+        |e| eprintln!("Recording failed: {e}"),
+        None,
+    )
+}
@@ -29,7 +29,10 @@ async fn main() {
     let tts = TextToSpeech::new(&auth, &args.service_url);
     let voices = tts.list_voices().await.unwrap();
     println!("{:#?}", voices);
-    let voice = tts.get_voice(WatsonVoice::EnGbKateV3, None).await.unwrap();
+    let voice = tts
+        .get_voice(WatsonVoice::EnGbKateV3, None, None)
+        .await
+        .unwrap();
     println!("{:#?}", voice);
     let synth = tts.synthesise(args.text, None, None).await.unwrap();
     let mut file = File::create("file.ogg").unwrap();
@@ -20,6 +20,9 @@ pub enum AuthenticationError {
     #[error("{0}")]
     /// Network Error
     ConnectionError(String),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response code the server returnes
     #[error("{0}")]
     UnmappedResponse(u16),
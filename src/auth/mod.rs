@@ -1,14 +1,22 @@
 mod errors;
+use std::{
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
 use reqwest::{
     header::{HeaderValue, CONTENT_TYPE},
-    Body, ClientBuilder, Method, Request, StatusCode, Url,
+    Body, ClientBuilder, Method, Proxy, Request, StatusCode, Url,
 };
 use serde::{Deserialize, Serialize};
 
 pub use errors::AuthenticationError;
 
 const AUTH_URL: &str = "https://iam.cloud.ibm.com/identity/token";
-#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// How long before it expires that a token is treated as expired, so that it is not used for a
+/// request that it would expire during
+const EXPIRY_MARGIN_SECS: i64 = 60;
+#[derive(Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct TokenResponse {
     #[serde(rename = "access_token")]
@@ -56,12 +64,41 @@ impl TokenResponse {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-/// Holds the IAM Access token generated by IBM Watson
+#[derive(Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// Holds the IAM Access token generated by IBM Watson. Its [`Debug`] output leaves the token out,
+/// so that it can be logged safely
+///
+/// [`Debug`]: std::fmt::Debug
 pub struct IamAuthenticator {
     access_token: TokenResponse,
 }
 
+/// Written in place of secrets in [`Debug`] output
+///
+/// [`Debug`]: std::fmt::Debug
+const REDACTED: &str = "<redacted>";
+
+impl fmt::Debug for TokenResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenResponse")
+            .field("access_token", &format_args!("{REDACTED}"))
+            .field("refresh_token", &format_args!("{REDACTED}"))
+            .field("token_type", &self.token_type)
+            .field("expires_in", &self.expires_in)
+            .field("expiration", &self.expiration)
+            .field("scope", &self.scope)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Debug for IamAuthenticator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IamAuthenticator")
+            .field("access_token", &format_args!("{REDACTED}"))
+            .finish()
+    }
+}
+
 impl IamAuthenticator {
     /// Get an IAM Access token from an API key
     ///
@@ -78,6 +115,43 @@ impl IamAuthenticator {
     /// # }
     /// ```
     pub async fn new(api_key: impl AsRef<str>) -> Result<Self, AuthenticationError> {
+        Self::request_token(api_key.as_ref(), ClientBuilder::new()).await
+    }
+
+    /// Get an IAM Access token from an API key like [`new()`], but send the request through a
+    /// proxy. The services are configured separately, for example with
+    /// [`TextToSpeechBuilder::proxy()`] or [`SpeechToTextBuilder::proxy()`]
+    ///
+    /// # Parameters
+    ///
+    /// * `api_key` - The API key for your Watson service
+    /// * `proxy` - The [`Proxy`] to send the request through
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::auth::IamAuthenticator;
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// let proxy = reqwest::Proxy::https("http://proxy.example.com:8080")?;
+    /// let auth = IamAuthenticator::with_proxy("api_key", proxy).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`new()`]: Self::new()
+    /// [`TextToSpeechBuilder::proxy()`]: crate::tts::TextToSpeechBuilder::proxy()
+    /// [`SpeechToTextBuilder::proxy()`]: crate::stt::SpeechToTextBuilder::proxy()
+    /// [`Proxy`]: reqwest::Proxy
+    pub async fn with_proxy(
+        api_key: impl AsRef<str>,
+        proxy: Proxy,
+    ) -> Result<Self, AuthenticationError> {
+        Self::request_token(api_key.as_ref(), ClientBuilder::new().proxy(proxy)).await
+    }
+
+    async fn request_token(
+        api_key: &str,
+        client: ClientBuilder,
+    ) -> Result<Self, AuthenticationError> {
         let url = Url::parse(AUTH_URL).unwrap();
         let mut req = Request::new(Method::POST, url);
         let headers = req.headers_mut();
@@ -86,13 +160,10 @@ impl IamAuthenticator {
             HeaderValue::from_str("application/x-www-form-urlencoded").unwrap(),
         );
         let body = req.body_mut();
-        *body = Some(Body::from(format!(
-            "grant_type=urn:ibm:params:oauth:grant-type:apikey&apikey={}",
-            api_key.as_ref()
-        )));
-        let client = ClientBuilder::new();
-
-        let client = client.build().unwrap();
+        *body = Some(Body::from(token_request_body(api_key)));
+        let client = client
+            .build()
+            .map_err(|e| AuthenticationError::ConnectionError(e.to_string()))?;
         let resp = client
             .execute(req)
             .await
@@ -100,7 +171,88 @@ impl IamAuthenticator {
         match resp.status() {
             StatusCode::OK => {
                 // asynchronously aggregate the chunks of the body
-                let access_token: TokenResponse = resp.json().await.unwrap();
+                let access_token: TokenResponse = resp
+                    .json()
+                    .await
+                    .map_err(|e| AuthenticationError::DeserializationError(e.to_string()))?;
+                Ok(Self { access_token })
+            }
+            StatusCode::BAD_REQUEST => Err(AuthenticationError::ParameterValidationFailed),
+            _ => Err(AuthenticationError::UnmappedResponse(resp.status().into())),
+        }
+    }
+
+    /// Get an IAM Access token from an API key without an async runtime. This blocks the current thread until the token is received, so it must not be called from within an async runtime
+    ///
+    /// # Parameters
+    ///
+    /// * `api_key` - The API key for your Watson service
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::auth::IamAuthenticator;
+    /// # fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// let auth = IamAuthenticator::new_blocking("api_key")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "blocking")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+    pub fn new_blocking(api_key: impl AsRef<str>) -> Result<Self, AuthenticationError> {
+        Self::request_token_blocking(api_key.as_ref(), reqwest::blocking::ClientBuilder::new())
+    }
+
+    /// Get an IAM Access token from an API key like [`new_blocking()`], but send the request
+    /// through a proxy. This blocks the current thread until the token is received, so it must not
+    /// be called from within an async runtime
+    ///
+    /// # Parameters
+    ///
+    /// * `api_key` - The API key for your Watson service
+    /// * `proxy` - The [`Proxy`] to send the request through
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::auth::IamAuthenticator;
+    /// # fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// let proxy = reqwest::Proxy::https("http://proxy.example.com:8080")?;
+    /// let auth = IamAuthenticator::with_proxy_blocking("api_key", proxy)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`new_blocking()`]: Self::new_blocking()
+    /// [`Proxy`]: reqwest::Proxy
+    #[cfg(feature = "blocking")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+    pub fn with_proxy_blocking(
+        api_key: impl AsRef<str>,
+        proxy: Proxy,
+    ) -> Result<Self, AuthenticationError> {
+        Self::request_token_blocking(
+            api_key.as_ref(),
+            reqwest::blocking::ClientBuilder::new().proxy(proxy),
+        )
+    }
+
+    #[cfg(feature = "blocking")]
+    fn request_token_blocking(
+        api_key: &str,
+        client: reqwest::blocking::ClientBuilder,
+    ) -> Result<Self, AuthenticationError> {
+        let resp = client
+            .build()
+            .map_err(|e| AuthenticationError::ConnectionError(e.to_string()))?
+            .post(AUTH_URL)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(token_request_body(api_key))
+            .send()
+            .map_err(|e| AuthenticationError::ConnectionError(e.to_string()))?;
+        match resp.status() {
+            StatusCode::OK => {
+                let access_token: TokenResponse = resp
+                    .json()
+                    .map_err(|e| AuthenticationError::DeserializationError(e.to_string()))?;
                 Ok(Self { access_token })
             }
             StatusCode::BAD_REQUEST => Err(AuthenticationError::ParameterValidationFailed),
@@ -108,8 +260,84 @@ impl IamAuthenticator {
         }
     }
 
-    #[cfg(any(feature = "tts", feature = "stt"))]
+    /// Create an authenticator from an access token that was issued earlier, for example one that a previous run cached with [`access_token()`] and [`expiration()`]. No request is made to IAM, so a short-lived process can skip the round trip on every start. Check [`is_expired()`] to know when to request a new token with [`new()`]
+    ///
+    /// # Parameters
+    ///
+    /// * `access_token` - The IAM access token
+    /// * `expires_at` - When the token expires, in seconds since the Unix epoch
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::auth::IamAuthenticator;
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// let auth = IamAuthenticator::new("api_key").await?;
+    /// let cached = (auth.access_token().to_owned(), auth.expiration());
+    ///
+    /// // in a later run
+    /// let auth = IamAuthenticator::from_token(cached.0, cached.1);
+    /// let auth = if auth.is_expired() {
+    ///     IamAuthenticator::new("api_key").await?
+    /// } else {
+    ///     auth
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`access_token()`]: Self::access_token()
+    /// [`expiration()`]: Self::expiration()
+    /// [`is_expired()`]: Self::is_expired()
+    /// [`new()`]: Self::new()
+    pub fn from_token(access_token: String, expires_at: i64) -> Self {
+        Self {
+            access_token: TokenResponse {
+                access_token,
+                token_type: "Bearer".to_owned(),
+                expires_in: expires_at.saturating_sub(now()).max(0),
+                expiration: expires_at,
+                ..TokenResponse::default()
+            },
+        }
+    }
+
+    /// The IAM access token that is sent with every request. Store it along with
+    /// [`expiration()`] to reuse it later with [`from_token()`]. Keep it secret, as it grants
+    /// access to your services until it expires
+    ///
+    /// [`expiration()`]: Self::expiration()
+    /// [`from_token()`]: Self::from_token()
+    pub fn access_token(&self) -> &str {
+        &self.access_token.access_token
+    }
+
+    /// When the access token expires, in seconds since the Unix epoch
+    pub fn expiration(&self) -> i64 {
+        self.access_token.expiration
+    }
+
+    /// Whether the access token has expired, or will within the next minute, so that a request
+    /// made with it now could be rejected
+    pub fn is_expired(&self) -> bool {
+        self.expiration() <= now().saturating_add(EXPIRY_MARGIN_SECS)
+    }
+
+    #[cfg(any(feature = "tts", feature = "stt", feature = "lt"))]
     pub(crate) fn token_response(&self) -> &TokenResponse {
         &self.access_token
     }
 }
+
+/// The current time in seconds since the Unix epoch
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| i64::try_from(now.as_secs()).unwrap_or(i64::MAX))
+}
+
+fn token_request_body(api_key: &str) -> String {
+    url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("grant_type", "urn:ibm:params:oauth:grant-type:apikey")
+        .append_pair("apikey", api_key)
+        .finish()
+}
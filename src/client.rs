@@ -0,0 +1,360 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use url::form_urlencoded::byte_serialize;
+
+use reqwest::{
+    header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION},
+    Client, ClientBuilder, IntoUrl, Method, Request, RequestBuilder, Response, StatusCode, Url,
+    Version,
+};
+
+use crate::error::ServiceError;
+#[cfg(any(feature = "tts", feature = "stt"))]
+use crate::error::{PingError, WatsonError};
+
+const LEARNING_OPT_OUT: &str = "x-watson-learning-opt-out";
+/// The content type of the responses of every endpoint except synthesis
+pub(crate) const JSON: &str = "application/json";
+
+/// How long `ping()` waits for the service to respond
+#[cfg(any(feature = "tts", feature = "stt"))]
+pub(crate) const PING_TIMEOUT: Duration = Duration::from_secs(5);
+/// The delay before the first retry. It doubles with every attempt
+const BASE_DELAY: Duration = Duration::from_millis(250);
+/// The longest that a single retry is delayed for
+const MAX_DELAY: Duration = Duration::from_secs(8);
+
+/// The [`Client`] used by the services. It attaches the authentication headers of the service
+/// to every request, so it works the same whether the client was built by this crate or passed
+/// in by the user
+#[derive(Clone, Debug)]
+pub(crate) struct ServiceClient {
+    client: Client,
+    headers: HeaderMap<HeaderValue>,
+    version: Version,
+    retries: u32,
+}
+
+impl ServiceClient {
+    /// A client configured the way this crate configures clients by default
+    #[cfg(feature = "lt")]
+    pub(crate) fn new(token: &str) -> Self {
+        let http2 = cfg!(feature = "http2");
+        let client = Self::builder(http2).build().unwrap();
+        Self::with_client(token, client).http2(http2)
+    }
+
+    /// Wraps a preconfigured client
+    pub(crate) fn with_client(token: &str, client: Client) -> Self {
+        Self {
+            client,
+            headers: Self::default_headers(token),
+            version: Version::default(),
+            retries: 0,
+        }
+    }
+
+    /// Whether requests are sent as HTTP/2. This must match how the client was built
+    pub(crate) fn http2(mut self, http2: bool) -> Self {
+        self.version = if http2 {
+            Version::HTTP_2
+        } else {
+            Version::default()
+        };
+        self
+    }
+
+    /// How many times a `GET` request is retried if the service responds with a 500 or 503.
+    /// Other methods are never retried, as they may not be idempotent
+    #[cfg(feature = "tts")]
+    pub(crate) fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// The [`ClientBuilder`] that the services start from. With `http2`, the client talks HTTP/2
+    /// without negotiating it first
+    pub(crate) fn builder(http2: bool) -> ClientBuilder {
+        let client = ClientBuilder::new().use_rustls_tls();
+        if http2 {
+            client.http2_prior_knowledge()
+        } else {
+            client
+        }
+    }
+
+    /// Tags every request with a customer ID in the `X-Watson-Metadata` header, so that the data
+    /// can later be deleted by that ID. The ID is percent-encoded
+    pub(crate) fn set_customer_id(&mut self, customer_id: &str) {
+        let customer_id: String = byte_serialize(customer_id.as_bytes()).collect();
+        let value = HeaderValue::from_str(&format!("customer_id={customer_id}")).unwrap();
+        self.headers.insert("x-watson-metadata", value);
+    }
+
+    /// Whether to send `X-Watson-Learning-Opt-Out: true` with every request, which stops the
+    /// service from using the request data to improve itself
+    pub(crate) fn set_learning_opt_out(&mut self, opt_out: bool) {
+        if opt_out {
+            self.headers
+                .insert(LEARNING_OPT_OUT, HeaderValue::from_static("true"));
+        } else {
+            self.headers.remove(LEARNING_OPT_OUT);
+        }
+    }
+
+    /// The headers that are sent with every request
+    #[cfg(any(feature = "tts", feature = "stt"))]
+    pub(crate) fn headers(&self) -> &HeaderMap<HeaderValue> {
+        &self.headers
+    }
+
+    /// Sends `request` with the headers of the service, retrying it if it is a `GET`. Unless the
+    /// request sets its own `Accept` header, it asks for JSON, as almost every endpoint responds
+    /// with it. With the `tracing` feature, the request is sent within a span that carries its
+    /// method and redacted url, and the outcome is recorded as an event
+    pub(crate) async fn execute(&self, mut request: Request) -> reqwest::Result<Response> {
+        request.headers_mut().extend(self.headers.clone());
+        request
+            .headers_mut()
+            .entry(ACCEPT)
+            .or_insert_with(|| HeaderValue::from_static(JSON));
+        *request.version_mut() = self.version;
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+
+            let span = tracing::info_span!(
+                "request",
+                method = %request.method(),
+                url = %redact(request.url()),
+            );
+            async {
+                let response = self.execute_with_retries(request).await;
+                trace_response(&response);
+                response
+            }
+            .instrument(span)
+            .await
+        }
+        #[cfg(not(feature = "tracing"))]
+        self.execute_with_retries(request).await
+    }
+
+    /// Sends a request that was started with [`request()`] or [`post()`], the same way as
+    /// [`execute()`]
+    ///
+    /// [`request()`]: Self::request()
+    /// [`post()`]: Self::post()
+    /// [`execute()`]: Self::execute()
+    pub(crate) async fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        self.execute(request.build()?).await
+    }
+
+    async fn execute_with_retries(&self, request: Request) -> reqwest::Result<Response> {
+        if self.retries == 0 || request.method() != Method::GET {
+            return self.client.execute(request).await;
+        }
+        let mut attempt = 0;
+        loop {
+            let retry = match request.try_clone() {
+                Some(retry) if attempt < self.retries => retry,
+                _ => return self.client.execute(request).await,
+            };
+            let response = self.client.execute(retry).await?;
+            match response.status() {
+                StatusCode::INTERNAL_SERVER_ERROR | StatusCode::SERVICE_UNAVAILABLE => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(attempt, status = response.status().as_u16(), "retrying");
+                    tokio::time::sleep(backoff(attempt)).await;
+                    attempt += 1;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+
+    /// Checks that the service responds to an authenticated `GET` of `url` within
+    /// [`PING_TIMEOUT`]. The body of the response is not read
+    #[cfg(any(feature = "tts", feature = "stt"))]
+    pub(crate) async fn ping(&self, url: Url) -> Result<(), PingError> {
+        let mut request = Request::new(Method::GET, url);
+        *request.timeout_mut() = Some(PING_TIMEOUT);
+        let response = self.execute(request).await.map_err(|e| {
+            if e.is_timeout() {
+                PingError::Timeout(PING_TIMEOUT)
+            } else {
+                PingError::ConnectionError(e)
+            }
+        })?;
+        match response.status() {
+            StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(PingError::Unauthorised401(
+                WatsonError::from_response(response).await,
+            )),
+            StatusCode::FORBIDDEN => Err(PingError::Forbidden403(
+                WatsonError::from_response(response).await,
+            )),
+            status => Err(PingError::UnmappedResponse(status.as_u16())),
+        }
+    }
+
+    pub(crate) fn request(&self, method: Method, url: impl IntoUrl) -> RequestBuilder {
+        self.client
+            .request(method, url)
+            .headers(self.headers.clone())
+            .version(self.version)
+    }
+
+    pub(crate) fn post(&self, url: impl IntoUrl) -> RequestBuilder {
+        self.request(Method::POST, url)
+    }
+
+    /// Sets the `If-Modified-Since` header of `request`, if a time is given
+    #[cfg(feature = "tts")]
+    pub(crate) fn if_modified_since(request: &mut Request, since: Option<SystemTime>) {
+        if let Some(since) = since {
+            let value = HeaderValue::from_str(&httpdate::fmt_http_date(since)).unwrap();
+            request
+                .headers_mut()
+                .insert(reqwest::header::IF_MODIFIED_SINCE, value);
+        }
+    }
+
+    /// A blocking client that sends the authentication headers of the service with every request.
+    /// Like every blocking client, it must not be created or used from within an async runtime
+    #[cfg(feature = "blocking")]
+    pub(crate) fn blocking(token: &str, http2: bool) -> reqwest::blocking::Client {
+        let client = reqwest::blocking::ClientBuilder::new()
+            .use_rustls_tls()
+            .default_headers(Self::default_headers(token));
+        let client = if http2 {
+            client.http2_prior_knowledge()
+        } else {
+            client
+        };
+        client.build().unwrap()
+    }
+
+    fn default_headers(token: &str) -> HeaderMap<HeaderValue> {
+        let mut headers = HeaderMap::new();
+        let mut auth_value = HeaderValue::from_str(&format!("Bearer {}", token)).unwrap();
+        auth_value.set_sensitive(true);
+        headers.insert(AUTHORIZATION, auth_value);
+        headers
+    }
+}
+
+/// The options of the [`Client`] that a service builder creates, such as
+/// [`TextToSpeechBuilder`]. They are shared by the builders of every service
+///
+/// [`TextToSpeechBuilder`]: crate::tts::TextToSpeechBuilder
+#[cfg(any(feature = "tts", feature = "stt"))]
+#[derive(Debug)]
+pub(crate) struct ClientOptions {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) pool_max_idle_per_host: Option<usize>,
+    pub(crate) pool_idle_timeout: Option<Duration>,
+    pub(crate) proxy: Option<reqwest::Proxy>,
+    pub(crate) http2: bool,
+}
+
+#[cfg(any(feature = "tts", feature = "stt"))]
+// only derivable when the http2 feature is off
+#[allow(clippy::derivable_impls)]
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            proxy: None,
+            http2: cfg!(feature = "http2"),
+        }
+    }
+}
+
+#[cfg(any(feature = "tts", feature = "stt"))]
+impl ClientOptions {
+    /// Builds the client, which authenticates its requests with `token`
+    pub(crate) fn build(self, token: &str) -> Result<ServiceClient, ServiceError> {
+        let mut client = ServiceClient::builder(self.http2);
+        if let Some(timeout) = self.timeout {
+            client = client.timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            client = client.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            client = client.pool_idle_timeout(timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            client = client.proxy(proxy);
+        }
+        Ok(ServiceClient::with_client(token, client.build()?).http2(self.http2))
+    }
+}
+
+/// Parses the endpoint of a service once, so that every request can build its url from it
+pub(crate) fn parse_service_url(service_url: &str) -> Result<Url, ServiceError> {
+    match Url::parse(service_url) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => Ok(url),
+        _ => Err(ServiceError::InvalidUrl(service_url.to_owned())),
+    }
+}
+
+/// `url` with any credentials removed and the values of its query replaced, as the query may
+/// carry the text that is being processed
+#[cfg(feature = "tracing")]
+pub(crate) fn redact(url: &Url) -> Url {
+    let mut redacted = url.clone();
+    let _ = redacted.set_username("");
+    let _ = redacted.set_password(None);
+    redacted.set_fragment(None);
+    if url.query().is_some() {
+        redacted.set_query(None);
+        let mut query = redacted.query_pairs_mut();
+        for (key, _) in url.query_pairs() {
+            query.append_pair(&key, "redacted");
+        }
+    }
+    redacted
+}
+
+/// Records the status and transaction ID of a response, or why there was none. Only those are
+/// recorded, so the `Authorization` header is never logged
+#[cfg(feature = "tracing")]
+fn trace_response(response: &reqwest::Result<Response>) {
+    match response {
+        Ok(response) => {
+            let transaction_id = response
+                .headers()
+                .get("x-global-transaction-id")
+                .and_then(|id| id.to_str().ok());
+            tracing::info!(
+                status = response.status().as_u16(),
+                transaction_id,
+                "response"
+            );
+        }
+        Err(error) => {
+            let mut error_message = error.to_string();
+            if let Some(url) = error.url() {
+                error_message = error_message.replace(url.as_str(), redact(url).as_str());
+            }
+            tracing::warn!(error = error_message, "request failed");
+        }
+    }
+}
+
+/// The delay before retry number `attempt` (starting from 0): exponential backoff with full
+/// jitter, so that clients that failed together do not retry together
+pub(crate) fn backoff(attempt: u32) -> Duration {
+    let ceiling = BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_DELAY);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos();
+    ceiling.mul_f64(f64::from(nanos) / 1e9)
+}
@@ -0,0 +1,463 @@
+use std::fmt::Display;
+
+use reqwest::{header::HeaderMap, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The header that IBM uses to identify a request, which its support asks for
+const TRANSACTION_ID: &str = "x-global-transaction-id";
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+/// The error body that the service sends back with an unsuccessful response
+pub struct WatsonError {
+    /// The HTTP status code of the response
+    #[serde(rename = "code", default)]
+    pub code: u16,
+    /// A human-readable description of what went wrong, for example `Model 'en-US_Foo' not found`
+    #[serde(rename = "error", default)]
+    pub message: String,
+    /// A short description of the status code, such as `Bad Request`, if the service sent one
+    #[serde(rename = "code_description", skip_serializing_if = "Option::is_none")]
+    pub sub_code: Option<String>,
+    /// The `X-Global-Transaction-Id` header of the response, if the service sent one. Quote it when contacting IBM support about a failed request
+    #[serde(skip)]
+    pub transaction_id: Option<String>,
+}
+
+// only the Text To Speech errors carry the body so far
+#[cfg_attr(not(feature = "tts"), allow(dead_code))]
+impl WatsonError {
+    /// Reads the error body of `response`. If the body is not the JSON the service usually sends,
+    /// the message is the body as text, or the reason phrase of the status code if it is empty
+    pub(crate) async fn from_response(response: Response) -> Self {
+        let code = response.status().as_u16();
+        let transaction_id = transaction_id(response.headers());
+        let body = response.bytes().await.unwrap_or_default();
+        let mut error = Self::from_body(code, &body);
+        error.transaction_id = transaction_id;
+        error
+    }
+
+    /// Reads the error body of a response received by a blocking client
+    #[cfg(feature = "blocking")]
+    pub(crate) fn from_blocking_response(response: reqwest::blocking::Response) -> Self {
+        let code = response.status().as_u16();
+        let transaction_id = transaction_id(response.headers());
+        let body = response.bytes().unwrap_or_default();
+        let mut error = Self::from_body(code, &body);
+        error.transaction_id = transaction_id;
+        error
+    }
+
+    pub(crate) fn from_body(code: u16, body: &[u8]) -> Self {
+        match serde_json::from_slice::<WatsonError>(body) {
+            Ok(mut error) if !error.message.is_empty() => {
+                if error.code == 0 {
+                    error.code = code;
+                }
+                error
+            }
+            _ => {
+                let text = String::from_utf8_lossy(body).trim().to_owned();
+                let message = if text.is_empty() {
+                    reqwest::StatusCode::from_u16(code)
+                        .ok()
+                        .and_then(|status| status.canonical_reason())
+                        .unwrap_or_default()
+                        .to_owned()
+                } else {
+                    text
+                };
+                Self {
+                    code,
+                    message,
+                    sub_code: None,
+                    transaction_id: None,
+                }
+            }
+        }
+    }
+}
+
+impl Display for WatsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.message, self.code)?;
+        if let Some(transaction_id) = &self.transaction_id {
+            write!(f, ", transaction id: {transaction_id}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The failures that any request can end in, whichever service it is sent to. The error enum of
+/// each method has a variant for each of them, and [`impl_from_common_error!`] converts between
+/// the two, so that the status codes are only mapped here
+#[derive(Debug)]
+// only the Text To Speech errors carry the body of a 500 or 503 response so far
+#[cfg_attr(not(feature = "tts"), allow(dead_code))]
+pub(crate) enum CommonError {
+    /// The service could not be reached
+    ConnectionError(reqwest::Error),
+    /// The service experienced an internal error
+    InternalServerError500(WatsonError),
+    /// The service is currently unavailable
+    ServiceUnavailable503(WatsonError),
+    /// The response had a status code that the method does not document
+    UnmappedResponse(u16),
+}
+
+// the Language Translator maps its errors with helpers of its own
+#[cfg_attr(not(any(feature = "tts", feature = "stt")), allow(dead_code))]
+impl CommonError {
+    /// The error for an unsuccessful `response` whose status code the method does not map itself
+    pub(crate) async fn from_response(response: Response) -> Self {
+        match response.status() {
+            StatusCode::INTERNAL_SERVER_ERROR => {
+                Self::InternalServerError500(WatsonError::from_response(response).await)
+            }
+            StatusCode::SERVICE_UNAVAILABLE => {
+                Self::ServiceUnavailable503(WatsonError::from_response(response).await)
+            }
+            status => Self::UnmappedResponse(status.as_u16()),
+        }
+    }
+
+    /// The error for an unsuccessful response received by a blocking client
+    #[cfg(feature = "blocking")]
+    // only the blocking Text To Speech client reads the body so far
+    #[cfg_attr(not(feature = "tts"), allow(dead_code))]
+    pub(crate) fn from_blocking_response(response: reqwest::blocking::Response) -> Self {
+        match response.status() {
+            StatusCode::INTERNAL_SERVER_ERROR => {
+                Self::InternalServerError500(WatsonError::from_blocking_response(response))
+            }
+            StatusCode::SERVICE_UNAVAILABLE => {
+                Self::ServiceUnavailable503(WatsonError::from_blocking_response(response))
+            }
+            status => Self::UnmappedResponse(status.as_u16()),
+        }
+    }
+
+    /// The error for an unsuccessful status code when the body of the response is not read, for
+    /// example by a blocking client. The error body is the reason phrase of the status code
+    // only the Speech To Text helpers map bare status codes so far
+    #[cfg_attr(not(feature = "stt"), allow(dead_code))]
+    pub(crate) fn from_status(status: StatusCode) -> Self {
+        let error = || WatsonError::from_body(status.as_u16(), &[]);
+        match status {
+            StatusCode::INTERNAL_SERVER_ERROR => Self::InternalServerError500(error()),
+            StatusCode::SERVICE_UNAVAILABLE => Self::ServiceUnavailable503(error()),
+            status => Self::UnmappedResponse(status.as_u16()),
+        }
+    }
+}
+
+impl From<reqwest::Error> for CommonError {
+    fn from(error: reqwest::Error) -> Self {
+        Self::ConnectionError(error)
+    }
+}
+
+/// Implements `From<CommonError>` for error enums that have a `ConnectionError(reqwest::Error)`,
+/// an `InternalServerError500`, a `ServiceUnavailable503` and an `UnmappedResponse(u16)` variant.
+/// Prefix the list with `with_body:` for enums whose 500 and 503 variants carry the
+/// [`WatsonError`] that the service sent; otherwise it is dropped
+macro_rules! impl_from_common_error {
+    (with_body: $($error:ty),+ $(,)?) => {
+        $(
+            impl From<$crate::error::CommonError> for $error {
+                fn from(error: $crate::error::CommonError) -> Self {
+                    match error {
+                        $crate::error::CommonError::ConnectionError(e) => Self::ConnectionError(e),
+                        $crate::error::CommonError::InternalServerError500(e) => {
+                            Self::InternalServerError500(e)
+                        }
+                        $crate::error::CommonError::ServiceUnavailable503(e) => {
+                            Self::ServiceUnavailable503(e)
+                        }
+                        $crate::error::CommonError::UnmappedResponse(code) => {
+                            Self::UnmappedResponse(code)
+                        }
+                    }
+                }
+            }
+        )+
+    };
+    ($($error:ty),+ $(,)?) => {
+        $(
+            impl From<$crate::error::CommonError> for $error {
+                fn from(error: $crate::error::CommonError) -> Self {
+                    match error {
+                        $crate::error::CommonError::ConnectionError(e) => Self::ConnectionError(e),
+                        $crate::error::CommonError::InternalServerError500(_) => {
+                            Self::InternalServerError500
+                        }
+                        $crate::error::CommonError::ServiceUnavailable503(_) => {
+                            Self::ServiceUnavailable503
+                        }
+                        $crate::error::CommonError::UnmappedResponse(code) => {
+                            Self::UnmappedResponse(code)
+                        }
+                    }
+                }
+            }
+        )+
+    };
+}
+#[cfg_attr(
+    not(any(feature = "tts", feature = "stt", feature = "lt")),
+    allow(unused_imports)
+)]
+pub(crate) use impl_from_common_error;
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned when checking that a service can be reached with `ping()`, for
+/// example [`TextToSpeech::ping()`]. They tell rejected credentials apart from a service that
+/// cannot be reached
+///
+/// [`TextToSpeech::ping()`]: crate::tts::TextToSpeech::ping()
+pub enum PingError {
+    /// The service rejected the access token, for example because it has expired. Contains the error that the service reported
+    #[error("The credentials were rejected: {0}")]
+    Unauthorised401(WatsonError),
+    /// The access token is valid, but it does not grant access to the service instance. Contains the error that the service reported
+    #[error("The credentials do not grant access to the service: {0}")]
+    Forbidden403(WatsonError),
+    /// The service could not be reached
+    #[error("There was an error establishing the connection")]
+    ConnectionError(#[from] reqwest::Error),
+    /// The service did not respond within the timeout of the check
+    #[error("The service did not respond within {0:?}")]
+    Timeout(std::time::Duration),
+    /// The service was reached, but responded with another unsuccessful status
+    #[error("{0}")]
+    UnmappedResponse(u16),
+}
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned when creating a service client
+pub enum ServiceError {
+    /// The service url could not be parsed as an absolute `http` or `https` url
+    #[error("The service url {0} is invalid")]
+    InvalidUrl(String),
+    /// The HTTP client could not be built, for example because a proxy could not be set up or the
+    /// TLS backend could not be initialised
+    #[error("The HTTP client could not be built")]
+    ClientBuild(#[from] reqwest::Error),
+}
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Any error that this crate returns. The error of every method converts into it with [`From`],
+/// so code that calls several methods can return `Result<_, ibm_watson::Error>` and use `?`
+/// throughout. Each variant wraps the error of the method that failed, which can be matched on
+/// for the details
+///
+/// # Example
+/// ``` no_run
+/// # #[cfg(feature = "tts")]
+/// # mod example {
+/// use ibm_watson::{auth::IamAuthenticator, tts::TextToSpeech};
+///
+/// async fn customisable_voices(api_key: &str, service_url: &str) -> Result<usize, ibm_watson::Error> {
+///     let auth = IamAuthenticator::new(api_key).await?;
+///     let tts = TextToSpeech::try_new(&auth, service_url)?;
+///     let voices = tts.list_voices().await?;
+///     Ok(voices.iter().filter(|voice| voice.customisable).count())
+/// }
+/// # }
+/// ```
+pub enum Error {
+    /// Authenticating with IAM failed
+    #[error(transparent)]
+    Authentication(#[from] crate::auth::AuthenticationError),
+    /// A service client could not be created
+    #[error(transparent)]
+    Service(#[from] ServiceError),
+    /// Checking that a service can be reached failed
+    #[error(transparent)]
+    Ping(#[from] PingError),
+    /// Listing the voices failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    ListVoices(#[from] crate::tts::voices::errors::ListVoicesError),
+    /// Getting a voice failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    GetVoice(#[from] crate::tts::voices::errors::GetVoiceError),
+    /// A voice could not be parsed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    ParseVoice(#[from] crate::tts::voices::errors::ParseVoiceError),
+    /// Synthesising text failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    Synthesis(#[from] crate::tts::synthesis::errors::SynthesisError),
+    /// An audio format is invalid
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    AudioFormat(#[from] crate::tts::synthesis::errors::AudioFormatError),
+    /// Getting the pronunciation of a word failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    Pronunciation(#[from] crate::tts::pronunciation::errors::PronunciationError),
+    /// Listing the speaker models failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    ListSpeakers(#[from] crate::tts::speaker_models::errors::ListSpeakersError),
+    /// Creating a speaker model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    CreateSpeaker(#[from] crate::tts::speaker_models::errors::CreateSpeakerError),
+    /// Getting a speaker model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    GetSpeaker(#[from] crate::tts::speaker_models::errors::GetSpeakerError),
+    /// Deleting a speaker model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    DeleteSpeaker(#[from] crate::tts::speaker_models::errors::DeleteSpeakerError),
+    /// Deleting the data of a customer ID failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    DeleteLabeledData(#[from] crate::tts::user_data::errors::DeleteLabeledDataError),
+    /// Creating a custom model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    CreateCustomModel(#[from] crate::tts::customisations::errors::CreateModelError),
+    /// Listing the custom models failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    ListCustomModels(#[from] crate::tts::customisations::errors::ListModelError),
+    /// Updating a custom model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    UpdateCustomModel(#[from] crate::tts::customisations::errors::UpdateModelError),
+    /// Getting a custom model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    GetCustomModel(#[from] crate::tts::customisations::errors::GetModelError),
+    /// Deleting a custom model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    DeleteCustomModel(#[from] crate::tts::customisations::errors::DeleteModelError),
+    /// The language of a custom model could not be parsed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    ParseLanguage(#[from] crate::tts::customisations::errors::ParseLanguageError),
+    /// Adding words to a custom model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    AddWord(#[from] crate::tts::customisations::errors::AddWordError),
+    /// Listing the words of a custom model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    ListWords(#[from] crate::tts::customisations::errors::ListWordsError),
+    /// Getting a word of a custom model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    GetWord(#[from] crate::tts::customisations::errors::GetWordError),
+    /// Deleting a word from a custom model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    DeleteWord(#[from] crate::tts::customisations::errors::DeleteWordError),
+    /// Setting the part of speech of a word failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    SetPartOfSpeech(#[from] crate::tts::customisations::errors::SetPartOfSpeechError),
+    /// A part of speech could not be parsed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    ParsePartOfSpeech(#[from] crate::tts::customisations::errors::ParsePartOfSpeechError),
+    /// Listing the prompts of a custom model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    ListPrompts(#[from] crate::tts::customisations::errors::ListPromptsError),
+    /// Adding a prompt to a custom model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    AddPrompt(#[from] crate::tts::customisations::errors::AddPromptError),
+    /// Getting a prompt of a custom model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    GetPrompt(#[from] crate::tts::customisations::errors::GetPromptError),
+    /// Deleting a prompt from a custom model failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    DeletePrompt(#[from] crate::tts::customisations::errors::DeletePromptError),
+    /// Waiting for a prompt to be processed failed
+    #[cfg(feature = "tts")]
+    #[error(transparent)]
+    WaitForPrompt(#[from] crate::tts::customisations::errors::WaitForPromptError),
+    /// Listing the models failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    ListModels(#[from] crate::stt::models::errors::ListModelsError),
+    /// Getting a model failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    GetModel(#[from] crate::stt::models::errors::GetModelError),
+    /// Recognising speech failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    Recognize(#[from] crate::stt::recognition::errors::RecognizeError),
+    /// Managing an asynchronous recognition job failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    RecognitionJob(#[from] crate::stt::recognition::errors::RecognitionJobError),
+    /// Registering or unregistering a callback URL failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    Callback(#[from] crate::stt::recognition::errors::CallbackError),
+    /// Managing a custom language model failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    LanguageModel(#[from] crate::stt::customisations::errors::LanguageModelError),
+    /// Training a custom language model failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    TrainLanguageModel(#[from] crate::stt::customisations::errors::TrainLanguageModelError),
+    /// Managing a custom acoustic model failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    AcousticModel(#[from] crate::stt::customisations::errors::AcousticModelError),
+    /// Training a custom acoustic model failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    TrainAcousticModel(#[from] crate::stt::customisations::errors::TrainAcousticModelError),
+    /// Managing the audio of a custom acoustic model failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    Audio(#[from] crate::stt::customisations::errors::AudioError),
+    /// Managing a corpus of a custom language model failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    Corpus(#[from] crate::stt::customisations::errors::CorpusError),
+    /// Managing a grammar of a custom language model failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    Grammar(#[from] crate::stt::customisations::errors::GrammarError),
+    /// Managing the words of a custom language model failed
+    #[cfg(feature = "stt")]
+    #[error(transparent)]
+    Word(#[from] crate::stt::customisations::errors::WordError),
+    /// Translating text failed
+    #[cfg(feature = "lt")]
+    #[error(transparent)]
+    Translate(#[from] crate::lt::errors::TranslateError),
+    /// Identifying the language of text failed
+    #[cfg(feature = "lt")]
+    #[error(transparent)]
+    Identify(#[from] crate::lt::errors::IdentifyError),
+}
+
+/// The `X-Global-Transaction-Id` of a response, if there is one
+#[cfg_attr(not(feature = "tts"), allow(dead_code))]
+pub(crate) fn transaction_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(TRANSACTION_ID)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned)
+}
@@ -0,0 +1,58 @@
+use thiserror::Error;
+
+use crate::error::{impl_from_common_error, WatsonError};
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned when translating text
+pub enum TranslateError {
+    /// There was an error establishing the connection
+    #[error("There was an error establishing the connection")]
+    ConnectionError(#[from] reqwest::Error),
+    /// The request failed, for example because the source language could not be identified or no
+    /// model translates between the two languages. Contains the error that the service reported
+    #[error("{0}")]
+    BadRequest400(WatsonError),
+    /// No translation model was found for the source and target languages. Contains the error that the service reported
+    #[error("{0}")]
+    NotFound404(WatsonError),
+    /// The service experienced an internal error
+    #[error("The service experienced an internal error")]
+    InternalServerError500,
+    /// The service is currently unavailable
+    #[error("The service is currently unavailable")]
+    ServiceUnavailable503,
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
+    /// The response code the server returned
+    #[error("{0}")]
+    UnmappedResponse(u16),
+}
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned when identifying the language of text, or listing the languages
+/// that can be identified
+pub enum IdentifyError {
+    /// There was an error establishing the connection
+    #[error("There was an error establishing the connection")]
+    ConnectionError(#[from] reqwest::Error),
+    /// The request failed. Contains the error that the service reported
+    #[error("{0}")]
+    BadRequest400(WatsonError),
+    /// The service experienced an internal error
+    #[error("The service experienced an internal error")]
+    InternalServerError500,
+    /// The service is currently unavailable
+    #[error("The service is currently unavailable")]
+    ServiceUnavailable503,
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
+    /// The response code the server returned
+    #[error("{0}")]
+    UnmappedResponse(u16),
+}
+
+impl_from_common_error!(TranslateError, IdentifyError);
@@ -0,0 +1,323 @@
+use reqwest::{header::CONTENT_TYPE, Method, Request, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    auth::IamAuthenticator,
+    client::{parse_service_url, ServiceClient},
+    error::{ServiceError, WatsonError},
+};
+
+/// Errors that may be returned by the Language Translator service
+pub mod errors;
+
+use self::errors::{IdentifyError, TranslateError};
+
+/// The version of the API that requests are made against
+const VERSION: &str = "2018-05-01";
+
+/// Creates a client used to send requests to your Language Translator endpoint. It owns its
+/// configuration and is cheap to clone, so it can be shared across tasks
+#[derive(Clone)]
+pub struct LanguageTranslator {
+    service_url: Url,
+    client: ServiceClient,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// The result of a translation
+pub struct Translation {
+    /// The translations of the input text
+    pub translations: Vec<TranslatedText>,
+    /// The number of words in the input text
+    #[serde(default)]
+    pub word_count: u64,
+    /// The number of characters in the input text
+    #[serde(default)]
+    pub character_count: u64,
+    /// The language that the service identified for the input text, if no source language was given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detected_language: Option<String>,
+    /// The confidence score of the [`detected_language`], between 0 and 1
+    ///
+    /// [`detected_language`]: Self::detected_language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detected_language_confidence: Option<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+/// A single translation of the input text
+pub struct TranslatedText {
+    /// The translated text
+    #[serde(rename = "translation")]
+    pub text: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+/// A language that the service can identify
+pub struct IdentifiableLanguage {
+    /// The language code, for example `en` or `zh-TW`
+    pub language: String,
+    /// The name of the language, for example `English`
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// A language that the input text may be in
+pub struct IdentifiedLanguage {
+    /// The language code, for example `en` or `zh-TW`
+    pub language: String,
+    /// How confident the service is that the text is in this language, between 0 and 1
+    pub confidence: f64,
+}
+
+#[derive(Serialize)]
+pub(crate) struct TranslateBody<'a> {
+    pub(crate) text: [&'a str; 1],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) source: Option<&'a str>,
+    pub(crate) target: &'a str,
+}
+
+#[derive(Deserialize)]
+struct Languages<T> {
+    languages: Vec<T>,
+}
+
+impl LanguageTranslator {
+    /// Create a new Language Translator instance. This instance will be used to make all the
+    /// requests to the language translator service.
+    ///
+    /// # Parameters
+    /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
+    /// * `service_url` - The endpoint for your language translator instance. All Language
+    ///   Translator requests will be made to this endpoint
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     lt::LanguageTranslator,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// let auth = IamAuthenticator::new("api_key").await?;
+    /// let lt = LanguageTranslator::new(&auth, "service_url");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `service_url` is not an absolute `http` or `https` url. Use [`try_new()`] to
+    /// handle an invalid url instead
+    ///
+    /// [`IamAuthenticator`]: super::auth::IamAuthenticator
+    /// [`try_new()`]: Self::try_new()
+    pub fn new(authenticator: &IamAuthenticator, service_url: impl Into<String>) -> Self {
+        Self::try_new(authenticator, service_url).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create a new Language Translator instance like [`new()`], but return [`InvalidUrl`]
+    /// instead of panicking if `service_url` is not an absolute `http` or `https` url
+    ///
+    /// [`new()`]: Self::new()
+    /// [`InvalidUrl`]: crate::error::ServiceError::InvalidUrl
+    pub fn try_new(
+        authenticator: &IamAuthenticator,
+        service_url: impl Into<String>,
+    ) -> Result<Self, ServiceError> {
+        let service_url = parse_service_url(&service_url.into())?;
+        let client = ServiceClient::new(authenticator.token_response().access_token());
+        Ok(Self {
+            service_url,
+            client,
+        })
+    }
+
+    /// Associate a customer ID with all the data that is sent to the service from now on, by passing it in the `X-Watson-Metadata` header. The ID is percent-encoded. The service can then delete all the data that is associated with it on request
+    ///
+    /// # Parameters
+    ///
+    /// * `customer_id` - The customer ID to associate with the data
+    pub fn set_customer_id(&mut self, customer_id: impl AsRef<str>) {
+        self.client.set_customer_id(customer_id.as_ref());
+    }
+
+    /// Opt out of having the data of requests made from now on used to improve the service, by sending `X-Watson-Learning-Opt-Out: true` with every request. By default the service may use the data, which matches IBM's default
+    ///
+    /// # Parameters
+    ///
+    /// * `opt_out` - Whether to opt out. Pass `false` to stop sending the header
+    pub fn set_learning_opt_out(&mut self, opt_out: bool) {
+        self.client.set_learning_opt_out(opt_out);
+    }
+
+    /// Translates text from one language to another
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text to translate
+    /// * `source` - The language code of the text, for example `en`. If [`None`], the service identifies the language of the text
+    /// * `target` - The language code to translate the text to, for example `es`
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     lt::LanguageTranslator,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let lt = LanguageTranslator::new(&auth, "service_url");
+    /// let translation = lt.translate("Hello, world", Some("en"), "es").await?;
+    /// println!("{}", translation.translations[0].text);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "language_translator"), err)
+    )]
+    pub async fn translate(
+        &self,
+        text: impl AsRef<str>,
+        source: Option<&str>,
+        target: impl AsRef<str>,
+    ) -> Result<Translation, TranslateError> {
+        let body = TranslateBody {
+            text: [text.as_ref()],
+            source,
+            target: target.as_ref(),
+        };
+        let request = self.client.post(self.url("v3/translate")).json(&body);
+        let response = self.client.send(request).await?;
+        let status = response.status();
+        if status == StatusCode::OK {
+            return response
+                .json()
+                .await
+                .map_err(|e| TranslateError::DeserializationError(e.to_string()));
+        }
+        match translate_error(status) {
+            Some(error) => Err(error(WatsonError::from_response(response).await)),
+            None => Err(TranslateError::UnmappedResponse(status.as_u16())),
+        }
+    }
+
+    /// Lists the languages that the service can identify, with their language codes and names
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     lt::LanguageTranslator,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let lt = LanguageTranslator::new(&auth, "service_url");
+    /// let languages = lt.list_identifiable_languages().await?;
+    /// println!("{} languages can be identified", languages.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "language_translator"), err)
+    )]
+    pub async fn list_identifiable_languages(
+        &self,
+    ) -> Result<Vec<IdentifiableLanguage>, IdentifyError> {
+        let req = Request::new(Method::GET, self.url("v3/identifiable_languages"));
+        let response = self.client.execute(req).await?;
+        Self::languages(response).await
+    }
+
+    /// Identifies the language of the text
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text whose language is to be identified
+    ///
+    /// # Returns
+    ///
+    /// The languages that the text may be in, the most likely first
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     lt::LanguageTranslator,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let lt = LanguageTranslator::new(&auth, "service_url");
+    /// let languages = lt.identify("Language translator translates text").await?;
+    /// println!("{:#?}", languages.first());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "language_translator"), err)
+    )]
+    pub async fn identify(
+        &self,
+        text: impl Into<String>,
+    ) -> Result<Vec<IdentifiedLanguage>, IdentifyError> {
+        let request = self
+            .client
+            .post(self.url("v3/identify"))
+            .header(CONTENT_TYPE, "text/plain")
+            .body(text.into());
+        let response = self.client.send(request).await?;
+        Self::languages(response).await
+    }
+
+    async fn languages<T: for<'de> Deserialize<'de>>(
+        response: reqwest::Response,
+    ) -> Result<Vec<T>, IdentifyError> {
+        let status = response.status();
+        if status == StatusCode::OK {
+            let root: Languages<T> = response
+                .json()
+                .await
+                .map_err(|e| IdentifyError::DeserializationError(e.to_string()))?;
+            return Ok(root.languages);
+        }
+        match identify_error(status) {
+            Some(error) => Err(error(WatsonError::from_response(response).await)),
+            None => Err(IdentifyError::UnmappedResponse(status.as_u16())),
+        }
+    }
+
+    /// The url of `path`, with the version of the API that the requests are made against
+    pub(crate) fn url(&self, path: &str) -> Url {
+        let mut url = self.service_url.clone();
+        url.set_path(path);
+        url.query_pairs_mut().append_pair("version", VERSION);
+        url
+    }
+}
+
+/// The error for an unsuccessful translation response, if the status is one that the service documents
+pub(crate) fn translate_error(status: StatusCode) -> Option<fn(WatsonError) -> TranslateError> {
+    match status {
+        StatusCode::BAD_REQUEST => Some(TranslateError::BadRequest400),
+        StatusCode::NOT_FOUND => Some(TranslateError::NotFound404),
+        StatusCode::INTERNAL_SERVER_ERROR => Some(|_| TranslateError::InternalServerError500),
+        StatusCode::SERVICE_UNAVAILABLE => Some(|_| TranslateError::ServiceUnavailable503),
+        _ => None,
+    }
+}
+
+/// The error for an unsuccessful response when identifying languages, if the status is one that
+/// the service documents
+pub(crate) fn identify_error(status: StatusCode) -> Option<fn(WatsonError) -> IdentifyError> {
+    match status {
+        StatusCode::BAD_REQUEST => Some(IdentifyError::BadRequest400),
+        StatusCode::INTERNAL_SERVER_ERROR => Some(|_| IdentifyError::InternalServerError500),
+        StatusCode::SERVICE_UNAVAILABLE => Some(|_| IdentifyError::ServiceUnavailable503),
+        _ => None,
+    }
+}
@@ -23,9 +23,17 @@
 //! services you intend to use.
 //!
 //! * `full` - Enables all the features listed below
+//! * `blocking` - Enables the blocking clients in `tts::blocking` and `stt::blocking`, for programs
+//!   that do not run an async runtime
 //! * `http2` - Enables support of `HTTP/2.0` requests
+//! * `tracing` - Emits a [`tracing`] span for every call to a service, named after the method and
+//!   carrying the service, with a nested span per request carrying its method and url. The values
+//!   of the url's query are redacted. The status and `X-Global-Transaction-Id` of each response
+//!   are recorded as an event, as is the error if a call fails. Headers are never recorded, so
+//!   the access token is never logged. The blocking clients are not instrumented
 //! * `tts` - Enables interacting with the Text To Speech API
 //! * `stt` - Enables interacting with the Speech To Text API
+//! * `lt` - Enables interacting with the Language Translator API
 //!
 //! # Example
 //!
@@ -101,6 +109,8 @@
 //!
 //! [MIT License]: http://opensource.org/licenses/MIT
 //!
+//! [`tracing`]: https://docs.rs/tracing
+//!
 //! # Contribution
 //!
 //! Unless you explicitly state otherwise, any contribution intentionally submitted
@@ -117,7 +127,6 @@
    // unreachable_pub,
    // rustdoc::missing_doc_code_examples
 )]
-
 /// Retrieve an IAM access token to use for authentication with your IBM Watson services
 ///
 /// # Example
@@ -145,5 +154,35 @@ pub mod tts;
 #[path = "speech-to-text/mod.rs"]
 pub mod stt;
 
+/// Interact with the IBM Watson™ Language Translator service
+#[cfg(feature = "lt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "lt")))]
+#[path = "language-translator/mod.rs"]
+pub mod lt;
+
+#[cfg(any(feature = "tts", feature = "stt", feature = "lt"))]
+mod client;
+
+/// Read the rate limits that the services report
+#[cfg(any(feature = "tts", feature = "stt", feature = "lt"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "tts", feature = "stt", feature = "lt")))
+)]
+pub mod rate_limit;
+
+/// Errors that are shared by the services
+#[cfg(any(feature = "tts", feature = "stt", feature = "lt"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "tts", feature = "stt", feature = "lt")))
+)]
+pub mod error;
+#[cfg(any(feature = "tts", feature = "stt", feature = "lt"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "tts", feature = "stt", feature = "lt")))
+)]
+pub use error::Error;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,77 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+
+const LIMIT: &str = "x-ratelimit-limit";
+const REMAINING: &str = "x-ratelimit-remaining";
+const RESET: &str = "x-ratelimit-reset";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The rate limit that the service reported in the `X-RateLimit-*` headers of a response. Use it
+/// to slow down before the service starts rejecting requests
+pub struct RateLimit {
+    /// The number of requests that are allowed in the current window
+    pub limit: u64,
+    /// The number of requests that are left in the current window
+    pub remaining: u64,
+    /// When the current window ends, in seconds since the Unix epoch
+    pub reset: i64,
+}
+
+impl RateLimit {
+    /// Reads the rate limit from the headers of a response, or [`None`] if any of the three
+    /// headers is missing or is not a number
+    ///
+    /// [`None`]: std::option::Option::None
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        fn header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+            headers.get(name)?.to_str().ok()?.trim().parse().ok()
+        }
+        Some(Self {
+            limit: header(headers, LIMIT)?,
+            remaining: header(headers, REMAINING)?,
+            reset: header(headers, RESET)?,
+        })
+    }
+
+    /// Whether no requests are left in the current window
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// How long it is until the current window ends, or zero if it already has
+    pub fn until_reset(&self) -> Duration {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        u64::try_from(self.reset)
+            .map(|reset| Duration::from_secs(reset.saturating_sub(now)))
+            .unwrap_or_default()
+    }
+
+    /// Sleeps until the current window ends if no requests are left in it, and returns straight
+    /// away otherwise
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let (voices, rate_limit) = tts.list_voices_with_meta().await?;
+    /// if let Some(rate_limit) = rate_limit {
+    ///     rate_limit.wait_if_exhausted().await;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_if_exhausted(&self) {
+        if self.is_exhausted() {
+            tokio::time::sleep(self.until_reset()).await;
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use reqwest::{header::ACCEPT, StatusCode, Url};
+
+use crate::{
+    auth::IamAuthenticator,
+    client::{parse_service_url, ServiceClient, JSON},
+    error::ServiceError,
+};
+
+use super::models::{errors::ListModelsError, list_models_error, models_url, Model, ModelList};
+
+/// A blocking client used to send requests to your Speech To Text endpoint. It mirrors the async
+/// [`SpeechToText`], for programs that do not run an async runtime. Like every blocking client, it
+/// must not be created or used from within an async runtime
+///
+/// # Example
+/// ``` no_run
+/// # use ibm_watson::{
+/// #     auth::IamAuthenticator,
+/// #     stt::blocking::SpeechToText,
+/// # };
+/// # fn foo()-> Result<(), Box<dyn std::error::Error>> {
+/// let auth = IamAuthenticator::new_blocking("api_key")?;
+/// let stt = SpeechToText::new(&auth, "service_url");
+/// let models = stt.list_models()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`SpeechToText`]: crate::stt::SpeechToText
+#[derive(Clone)]
+pub struct SpeechToText {
+    service_url: Url,
+    client: reqwest::blocking::Client,
+}
+
+impl SpeechToText {
+    /// Create a new blocking Speech To Text instance. This instance will be used to make all the
+    /// requests to the speech to text service.
+    ///
+    /// # Parameters
+    /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
+    /// * `service_url` - The endpoint for your speech to text instance. All Speech To Text
+    ///   requests will be made to this endpoint
+    ///
+    /// # Panics
+    ///
+    /// Panics if `service_url` is not an absolute `http` or `https` url. Use [`try_new()`] to
+    /// handle an invalid url instead
+    ///
+    /// [`IamAuthenticator`]: crate::auth::IamAuthenticator
+    /// [`try_new()`]: Self::try_new()
+    pub fn new(authenticator: &IamAuthenticator, service_url: impl Into<String>) -> Self {
+        Self::try_new(authenticator, service_url).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create a new blocking Speech To Text instance like [`new()`], but return [`InvalidUrl`]
+    /// instead of panicking if `service_url` is not an absolute `http` or `https` url
+    ///
+    /// [`new()`]: Self::new()
+    /// [`InvalidUrl`]: crate::error::ServiceError::InvalidUrl
+    pub fn try_new(
+        authenticator: &IamAuthenticator,
+        service_url: impl Into<String>,
+    ) -> Result<Self, ServiceError> {
+        let service_url = parse_service_url(&service_url.into())?;
+        let client = ServiceClient::blocking(
+            authenticator.token_response().access_token(),
+            cfg!(feature = "http2"),
+        );
+        Ok(Self {
+            service_url,
+            client,
+        })
+    }
+
+    /// Lists all language models that are available for use with the service
+    pub fn list_models(&self) -> Result<Vec<Model>, ListModelsError> {
+        let response = self
+            .client
+            .get(models_url(&self.service_url))
+            .header(ACCEPT, JSON)
+            .send()?;
+        match response.status() {
+            StatusCode::OK => {
+                let root: ModelList = response
+                    .json()
+                    .map_err(|e| ListModelsError::DeserializationError(e.to_string()))?;
+                Ok(root.models)
+            }
+            status => Err(list_models_error(status)),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use reqwest::Proxy;
+
+use crate::{
+    auth::IamAuthenticator,
+    client::{parse_service_url, ClientOptions},
+    error::ServiceError,
+};
+
+use super::SpeechToText;
+
+/// Configures and creates a [`SpeechToText`] instance
+///
+/// # Example
+/// ``` no_run
+/// # use ibm_watson::{
+/// #     auth::IamAuthenticator,
+/// #     stt::SpeechToText,
+/// # };
+/// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+/// let auth = IamAuthenticator::new("api_key").await?;
+/// let stt = SpeechToText::builder(&auth, "service_url")
+///     .timeout(std::time::Duration::from_secs(120))
+///     .pool_max_idle_per_host(16)
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`SpeechToText`]: super::SpeechToText
+pub struct SpeechToTextBuilder<'a> {
+    authenticator: &'a IamAuthenticator,
+    service_url: String,
+    options: ClientOptions,
+    learning_opt_out: bool,
+}
+
+impl<'a> SpeechToTextBuilder<'a> {
+    pub(crate) fn new(authenticator: &'a IamAuthenticator, service_url: impl Into<String>) -> Self {
+        Self {
+            authenticator,
+            service_url: service_url.into(),
+            options: ClientOptions::default(),
+            learning_opt_out: false,
+        }
+    }
+
+    /// Sets a timeout for every request, from when the request starts connecting until the
+    /// response body has finished. Recognising long audio can take a while, so leave room for it.
+    /// By default there is no timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// The most idle connections that are kept open to the service for reuse. Raise it if many
+    /// requests are made at once, so that new connections do not have to be opened for each of
+    /// them. By default there is no limit
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.options.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// How long an idle connection is kept open for reuse before it is closed. Defaults to 90
+    /// seconds
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.options.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sends every request through `proxy`, for example when all outgoing traffic has to go
+    /// through a corporate proxy. The [`IamAuthenticator`] is configured separately with
+    /// [`with_proxy()`]. By default, the proxy set by the `HTTP_PROXY` and `HTTPS_PROXY`
+    /// environment variables is used, if any
+    ///
+    /// [`IamAuthenticator`]: crate::auth::IamAuthenticator
+    /// [`with_proxy()`]: crate::auth::IamAuthenticator::with_proxy()
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.options.proxy = Some(proxy);
+        self
+    }
+
+    /// Whether to send requests as `HTTP/2.0` without negotiating it first. Defaults to true if
+    /// the `http2` feature is enabled
+    pub fn http2(mut self, http2: bool) -> Self {
+        self.options.http2 = http2;
+        self
+    }
+
+    /// Opt out of having request data used to improve the service, by sending
+    /// `X-Watson-Learning-Opt-Out: true` with every request. Defaults to false. It can be changed
+    /// later with [`set_learning_opt_out()`]
+    ///
+    /// [`set_learning_opt_out()`]: crate::stt::SpeechToText::set_learning_opt_out()
+    pub fn learning_opt_out(mut self, opt_out: bool) -> Self {
+        self.learning_opt_out = opt_out;
+        self
+    }
+
+    /// Creates the [`SpeechToText`] instance
+    ///
+    /// # Panics
+    ///
+    /// Panics if the service url is not an absolute `http` or `https` url, or if the HTTP client
+    /// cannot be built. Use [`try_build()`] to handle these errors instead
+    ///
+    /// [`SpeechToText`]: super::SpeechToText
+    /// [`try_build()`]: Self::try_build()
+    pub fn build(self) -> SpeechToText {
+        self.try_build().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Creates the [`SpeechToText`] instance. Returns [`InvalidUrl`] if the service url is not an
+    /// absolute `http` or `https` url, or [`ClientBuild`] if the HTTP client cannot be built, for
+    /// example because the [`proxy`] cannot be set up
+    ///
+    /// [`SpeechToText`]: super::SpeechToText
+    /// [`InvalidUrl`]: crate::error::ServiceError::InvalidUrl
+    /// [`ClientBuild`]: crate::error::ServiceError::ClientBuild
+    /// [`proxy`]: Self::proxy()
+    pub fn try_build(self) -> Result<SpeechToText, ServiceError> {
+        let service_url = parse_service_url(&self.service_url)?;
+        let mut client = self
+            .options
+            .build(self.authenticator.token_response().access_token())?;
+        client.set_learning_opt_out(self.learning_opt_out);
+        Ok(SpeechToText {
+            service_url,
+            client,
+        })
+    }
+}
@@ -0,0 +1,451 @@
+use reqwest::{Method, Request, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::CommonError,
+    stt::{models::ModelID, SpeechToText},
+};
+
+use super::{
+    errors::{AcousticModelError, TrainAcousticModelError},
+    ModelStatus, TrainingState,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+/// Information about an existing custom acoustic model
+pub struct AcousticModel {
+    /// The customisation ID (GUID) of the custom acoustic model. The create a custom acoustic model method returns only this field of the object; it does not return the other fields
+    #[serde(rename = "customization_id")]
+    pub customisation_id: String,
+    /// The name of the custom acoustic model
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The language identifier of the custom acoustic model (for example, en-US)
+    #[serde(rename = "language", skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// The name of the language model for which the custom acoustic model was created
+    #[serde(rename = "base_model_name", skip_serializing_if = "Option::is_none")]
+    pub base_model_name: Option<String>,
+    /// The description of the custom acoustic model
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The GUID of the credentials for the instance of the service that owns the custom acoustic model
+    #[serde(rename = "owner", skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// The date and time in Coordinated Universal Time (UTC) at which the custom acoustic model was created. The value is provided in full ISO 8601 format (YYYY-MM-DDThh:mm:ss.sTZD)
+    #[serde(rename = "created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// The date and time in Coordinated Universal Time (UTC) at which the custom acoustic model was last modified
+    #[serde(rename = "updated", skip_serializing_if = "Option::is_none")]
+    pub updated: Option<String>,
+    /// The current [`status`] of the custom acoustic model
+    ///
+    /// [`status`]: super::ModelStatus
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<ModelStatus>,
+    /// A percentage that indicates the progress of the custom acoustic model's current training. A value of 100 means that the model is fully trained. The progress field does not reflect the progress of the training; the field changes from 0 to 100 when training is complete
+    #[serde(rename = "progress", default)]
+    pub progress: u32,
+    /// If the request included unknown parameters, the warnings that describe them
+    #[serde(rename = "warnings", skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<String>,
+}
+
+impl AcousticModel {
+    /// The [`state`] of the training of the model, from its [`status`] and [`progress`]. The service reports no error for acoustic models, so a [`Failed`] state is always empty
+    ///
+    /// [`state`]: super::TrainingState
+    /// [`status`]: Self::status
+    /// [`progress`]: Self::progress
+    /// [`Failed`]: super::TrainingState::Failed
+    pub fn training_state(&self) -> TrainingState {
+        TrainingState::new(self.status.as_ref(), self.progress, None)
+    }
+}
+
+impl SpeechToText {
+    /// Creates a new custom acoustic model for a specified base model. The custom acoustic model can be used only with the base model for which it is created. The model is owned by the instance of the service whose credentials are used to create it
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - A user-defined name for the new custom acoustic model. Use a name that is unique among all custom acoustic models that you own
+    /// * `base_model` - The name of the base language [`model`] that is to be customised by the new custom acoustic model. The new custom model can be used only with the base model that it customises
+    /// * `description` - A description of the new custom acoustic model. Use a localised description that matches the language of the custom model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{models::ModelID, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let model = stt
+    ///     .create_acoustic_model("new model", ModelID::EnUsBroadband, Some("example"))
+    ///     .await?;
+    /// println!("model: {:#?}", model);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`model`]: crate::stt::models::ModelID
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn create_acoustic_model(
+        &self,
+        name: impl AsRef<str>,
+        base_model: ModelID,
+        description: Option<impl AsRef<str>>,
+    ) -> Result<AcousticModel, AcousticModelError> {
+        let mut url = self.service_url.clone();
+        Self::set_acoustic_models_path(&mut url);
+        #[derive(Serialize)]
+        struct FormBody<'a> {
+            name: &'a str,
+            base_model_name: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            description: Option<&'a str>,
+        }
+        let form_body = FormBody {
+            name: name.as_ref(),
+            base_model_name: base_model.to_string(),
+            description: description.as_ref().map(|d| d.as_ref()),
+        };
+        let client = self.get_client();
+        let request = client.post(url).json(&form_body);
+        let response = client.send(request).await?;
+        match response.status() {
+            StatusCode::CREATED => {
+                let root: AcousticModel = response
+                    .json()
+                    .await
+                    .map_err(|e| AcousticModelError::DeserializationError(e.to_string()))?;
+                Ok(root)
+            }
+            StatusCode::BAD_REQUEST => Err(AcousticModelError::BadRequest400),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Lists information about all custom acoustic models that are owned by an instance of the service. Use the `language` parameter to see all custom acoustic models for the specified language. You must use credentials for the instance of the service that owns a model to list information about it
+    ///
+    /// # Parameters
+    ///
+    /// * `language` - The identifier of the language for which custom acoustic models are to be returned (for example, en-US). Pass [`None`] to see all custom acoustic models that are owned by the requester
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let models = stt.list_acoustic_models(Some("en-US")).await?;
+    /// println!("found: {:#?} models", models.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn list_acoustic_models(
+        &self,
+        language: Option<&str>,
+    ) -> Result<Vec<AcousticModel>, AcousticModelError> {
+        let mut url = self.service_url.clone();
+        Self::set_acoustic_models_path(&mut url);
+        if let Some(language) = language {
+            url.query_pairs_mut().append_pair("language", language);
+        }
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                #[derive(Deserialize)]
+                struct Root {
+                    customizations: Vec<AcousticModel>,
+                }
+                let root: Root = response
+                    .json()
+                    .await
+                    .map_err(|e| AcousticModelError::DeserializationError(e.to_string()))?;
+                Ok(root.customizations)
+            }
+            StatusCode::BAD_REQUEST => Err(AcousticModelError::BadRequest400),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Gets information about a specified custom acoustic model. You must use credentials for the instance of the service that owns a model to list information about it
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom acoustic model. You must make the request with credentials for the instance of the service that owns the custom model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let model = stt.get_acoustic_model("cust-id").await?;
+    /// println!("{:#?}", model.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn get_acoustic_model(
+        &self,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<AcousticModel, AcousticModelError> {
+        let mut url = self.service_url.clone();
+        Self::set_acoustic_model_path(&mut url, &customisation_id);
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                let root: AcousticModel = response
+                    .json()
+                    .await
+                    .map_err(|e| AcousticModelError::DeserializationError(e.to_string()))?;
+                Ok(root)
+            }
+            StatusCode::BAD_REQUEST => Err(AcousticModelError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(AcousticModelError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::NOT_FOUND => Err(AcousticModelError::NotFound404(
+                customisation_id.as_ref().to_owned(),
+            )),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Deletes an existing custom acoustic model. The custom model cannot be deleted if another request, such as adding an audio resource to the model, is currently being processed. You must use credentials for the instance of the service that owns a model to delete it
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom acoustic model. You must make the request with credentials for the instance of the service that owns the custom model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// if stt.delete_acoustic_model("cust-id").await.is_ok() {
+    ///     println!("model deleted");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn delete_acoustic_model(
+        &self,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<(), AcousticModelError> {
+        let mut url = self.service_url.clone();
+        Self::set_acoustic_model_path(&mut url, &customisation_id);
+        let req = Request::new(Method::DELETE, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(()),
+            StatusCode::BAD_REQUEST => Err(AcousticModelError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(AcousticModelError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::NOT_FOUND => Err(AcousticModelError::NotFound404(
+                customisation_id.as_ref().to_owned(),
+            )),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Initiates the training of a custom acoustic model with new or changed audio resources. Training is asynchronous: the call returns as soon as the service accepts the request. Poll [`get_acoustic_model()`] until its [`status`] becomes [`Available`] to know when the model is trained
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom acoustic model that is to be trained
+    /// * `custom_language_model_id` - The customisation ID (GUID) of a custom [`language model`] that is to be used during training of the custom acoustic model. Specify a custom language model that has been trained with verbatim transcriptions of the audio resources or that contains words that are relevant to the contents of the audio resources. The custom language model must be based on the same version of the same base model as the custom acoustic model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// stt.train_acoustic_model("cust-id", Some("language-cust-id"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`get_acoustic_model()`]: Self::get_acoustic_model()
+    /// [`status`]: self::AcousticModel::status
+    /// [`Available`]: self::ModelStatus::Available
+    /// [`language model`]: crate::stt::customisations::LanguageModel
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn train_acoustic_model(
+        &self,
+        customisation_id: impl AsRef<str>,
+        custom_language_model_id: Option<&str>,
+    ) -> Result<(), TrainAcousticModelError> {
+        let mut url = self.service_url.clone();
+        url.set_path(&format!(
+            "v1/acoustic_customizations/{}/train",
+            customisation_id.as_ref()
+        ));
+        if let Some(language_model_id) = custom_language_model_id {
+            url.query_pairs_mut()
+                .append_pair("custom_language_model_id", language_model_id);
+        }
+        self.send_acoustic_training_request(url, customisation_id)
+            .await
+    }
+
+    /// Resets a custom acoustic model by removing all of its audio resources. The metadata of the model, such as its name and language, is preserved
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom acoustic model that is to be reset
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// stt.reset_acoustic_model("cust-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn reset_acoustic_model(
+        &self,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<(), TrainAcousticModelError> {
+        let mut url = self.service_url.clone();
+        url.set_path(&format!(
+            "v1/acoustic_customizations/{}/reset",
+            customisation_id.as_ref()
+        ));
+        self.send_acoustic_training_request(url, customisation_id)
+            .await
+    }
+
+    /// Upgrades a custom acoustic model to the latest version of its base model. Upgrading is asynchronous: the model's [`status`] is [`Upgrading`] until it completes, and it returns to [`Available`] afterwards. The model must be [`Ready`] or [`Available`], and the service returns [`Conflict409`] if it is busy, for example while it is being trained or is processing audio
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom acoustic model that is to be upgraded
+    /// * `custom_language_model_id` - The customisation ID (GUID) of a custom [`language model`] that the acoustic model was trained with. It must be upgraded to the latest version of the base model first. If the acoustic model was trained with a custom language model, it must be passed here too
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// stt.upgrade_language_model("language-cust-id").await?;
+    /// stt.upgrade_acoustic_model("cust-id", Some("language-cust-id"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`status`]: self::AcousticModel::status
+    /// [`Upgrading`]: self::ModelStatus::Upgrading
+    /// [`Ready`]: self::ModelStatus::Ready
+    /// [`Available`]: self::ModelStatus::Available
+    /// [`Conflict409`]: crate::stt::customisations::errors::TrainAcousticModelError::Conflict409
+    /// [`language model`]: crate::stt::customisations::LanguageModel
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn upgrade_acoustic_model(
+        &self,
+        customisation_id: impl AsRef<str>,
+        custom_language_model_id: Option<&str>,
+    ) -> Result<(), TrainAcousticModelError> {
+        let mut url = self.service_url.clone();
+        url.set_path(&format!(
+            "v1/acoustic_customizations/{}/upgrade_model",
+            customisation_id.as_ref()
+        ));
+        if let Some(language_model_id) = custom_language_model_id {
+            url.query_pairs_mut()
+                .append_pair("custom_language_model_id", language_model_id);
+        }
+        self.send_acoustic_training_request(url, customisation_id)
+            .await
+    }
+
+    async fn send_acoustic_training_request(
+        &self,
+        url: Url,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<(), TrainAcousticModelError> {
+        let req = Request::new(Method::POST, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => Ok(()),
+            StatusCode::BAD_REQUEST => Err(TrainAcousticModelError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(TrainAcousticModelError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::CONFLICT => Err(TrainAcousticModelError::Conflict409),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    fn set_acoustic_models_path(uri: &mut Url) {
+        uri.set_path("v1/acoustic_customizations");
+    }
+
+    pub(crate) fn set_acoustic_model_path(uri: &mut Url, customisation_id: impl AsRef<str>) {
+        uri.set_path(&format!(
+            "v1/acoustic_customizations/{}",
+            customisation_id.as_ref()
+        ));
+    }
+}
@@ -0,0 +1,371 @@
+use std::path::Path;
+
+use reqwest::{
+    header::{HeaderValue, CONTENT_TYPE},
+    Body, Method, Request, StatusCode, Url,
+};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, BufReader};
+
+use crate::{error::CommonError, stt::SpeechToText};
+
+use super::errors::AudioError;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Information about an audio resource from a custom acoustic model
+pub struct AudioResource {
+    /// The total seconds of audio in the audio resource
+    #[serde(rename = "duration", default)]
+    pub duration: f64,
+    /// The name of the audio resource. For an audio file in an archive, the name is the name of the file in the archive
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The [`details`] of the audio resource, such as its type and, for an archive, its compression
+    ///
+    /// [`details`]: self::AudioDetails
+    #[serde(rename = "details")]
+    pub details: AudioDetails,
+    /// The [`status`] of the audio resource
+    ///
+    /// [`status`]: self::AudioStatus
+    #[serde(rename = "status")]
+    pub status: AudioStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// Information about an audio resource from a custom acoustic model. For an archive, it describes the archive itself in [`container`] and each file in it in [`audio`]
+///
+/// [`container`]: Self::container
+/// [`audio`]: Self::audio
+pub struct AudioListing {
+    /// The total seconds of audio in the audio resource. Returned only for an individual audio file
+    #[serde(rename = "duration", skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+    /// The name of the audio resource. Returned only for an individual audio file
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The [`details`] of the audio resource. Returned only for an individual audio file
+    ///
+    /// [`details`]: self::AudioDetails
+    #[serde(rename = "details", skip_serializing_if = "Option::is_none")]
+    pub details: Option<AudioDetails>,
+    /// The [`status`] of the audio resource. Returned only for an individual audio file
+    ///
+    /// [`status`]: self::AudioStatus
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<AudioStatus>,
+    /// Information about the archive. Returned only for an archive
+    #[serde(rename = "container", skip_serializing_if = "Option::is_none")]
+    pub container: Option<AudioResource>,
+    /// Information about each audio file in the archive. Returned only for an archive
+    #[serde(rename = "audio", skip_serializing_if = "Option::is_none")]
+    pub audio: Option<Vec<AudioResource>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+/// Information about an audio resource
+pub struct AudioDetails {
+    /// The [`type`] of the audio resource
+    ///
+    /// [`type`]: self::AudioType
+    #[serde(rename = "type", default)]
+    pub audio_type: AudioType,
+    /// For an audio file, the codec in which the audio is encoded
+    #[serde(rename = "codec", skip_serializing_if = "Option::is_none")]
+    pub codec: Option<String>,
+    /// For an audio file, the sampling rate of the audio in Hz
+    #[serde(rename = "frequency", skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<u32>,
+    /// For an archive, the type of compression of the archive, either `zip` or `gzip`
+    #[serde(rename = "compression", skip_serializing_if = "Option::is_none")]
+    pub compression: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+/// The type of an audio resource
+pub enum AudioType {
+    /// An individual audio file
+    Audio,
+    /// An archive file (a .zip or .tar.gz file) that contains audio files
+    Archive,
+    #[default]
+    /// The service cannot determine the type, for example because the audio is invalid
+    Undetermined,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+/// The status of an audio resource
+pub enum AudioStatus {
+    /// The service successfully analysed the audio data. The data can be used to train the custom model
+    Ok,
+    /// The service is still analysing the audio data. The service cannot accept requests to add new audio resources or to train the custom model until its analysis is complete
+    BeingProcessed,
+    /// The audio data is not valid for training the custom model, possibly because it has the wrong format or sampling rate, or because it is corrupted
+    Invalid,
+}
+
+impl SpeechToText {
+    /// Adds an audio resource to a custom acoustic model. Add audio content that reflects the acoustic characteristics of the audio that you plan to transcribe. You must use credentials for the instance of the service that owns a model to add an audio resource to it. Adding audio data does not affect the custom acoustic model until you train the model
+    ///
+    /// The call returns once the service has accepted the audio; the service then analyses it asynchronously. Use [`get_audio()`] to check its status
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom acoustic model that is to be used for the request
+    /// * `audio_name` - The name of the new audio resource for the custom acoustic model. Use a localised name that matches the language of the custom model and reflects the contents of the resource
+    ///     * Include a maximum of 128 characters in the name
+    ///     * Do not use characters that need to be URL-encoded
+    ///     * Do not use the name of an audio resource that is already defined for the custom model
+    /// * `audio` - The path to the audio file or archive of audio files to add
+    /// * `content_type` - The format (MIME type) of the audio resource, for example `audio/wav`. For an archive, use `application/zip` or `application/gzip`
+    /// * `contained_content_type` - For an archive, the format (MIME type) of the audio files that it contains. If [`None`] is passed for an archive, the service assumes `audio/wav`. It is ignored for individual audio files
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let file_path = std::path::Path::new("/home/user/audio.zip");
+    /// stt.add_audio("cust-id", "audio1", &file_path, "application/zip", Some("audio/flac"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`get_audio()`]: Self::get_audio()
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn add_audio(
+        &self,
+        customisation_id: impl AsRef<str>,
+        audio_name: impl AsRef<str>,
+        audio: impl AsRef<Path>,
+        content_type: &str,
+        contained_content_type: Option<&str>,
+    ) -> Result<(), AudioError> {
+        let file = tokio::fs::OpenOptions::new()
+            .read(true)
+            .open(audio.as_ref())
+            .await
+            .map_err(|e| AudioError::FileReadError(e.to_string()))?;
+
+        let mut buf_reader = BufReader::new(file);
+        let mut buffer = Vec::new();
+        buf_reader
+            .read_to_end(&mut buffer)
+            .await
+            .map_err(|e| AudioError::FileReadError(e.to_string()))?;
+
+        let mut url = self.service_url.clone();
+        Self::set_audio_path(&mut url, &customisation_id, &audio_name);
+        let client = self.get_client();
+        let mut request = client
+            .post(url)
+            .header(CONTENT_TYPE, content_type)
+            .body(Body::from(buffer));
+        if let Some(contained_content_type) = contained_content_type {
+            if is_archive(content_type) {
+                let value = HeaderValue::from_str(contained_content_type).map_err(|_| {
+                    AudioError::InvalidContentType(contained_content_type.to_owned())
+                })?;
+                request = request.header("Contained-Content-Type", value);
+            }
+        }
+        let response = client.send(request).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => Ok(()),
+            StatusCode::BAD_REQUEST => Err(AudioError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(AudioError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::CONFLICT => Err(AudioError::Conflict409),
+            StatusCode::UNSUPPORTED_MEDIA_TYPE => Err(AudioError::UnsupportedMediaType415),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Lists information about all audio resources from a custom acoustic model. The information includes the name of the resource and information about its audio data, such as its duration
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom acoustic model that is to be used for the request
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let audio = stt.list_audio("cust-id").await?;
+    /// println!("found: {:#?} audio resources", audio.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn list_audio(
+        &self,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<Vec<AudioResource>, AudioError> {
+        let mut url = self.service_url.clone();
+        Self::set_audio_resources_path(&mut url, &customisation_id);
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                #[derive(Deserialize)]
+                struct Root {
+                    audio: Vec<AudioResource>,
+                }
+                let root: Root = response
+                    .json()
+                    .await
+                    .map_err(|e| AudioError::DeserializationError(e.to_string()))?;
+                Ok(root.audio)
+            }
+            StatusCode::BAD_REQUEST => Err(AudioError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(AudioError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Gets information about an audio resource from a custom acoustic model. For an archive, the information describes the archive and each audio file that it contains
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom acoustic model that is to be used for the request
+    /// * `audio_name` - The name of the audio resource for the custom acoustic model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let audio = stt.get_audio("cust-id", "audio1").await?;
+    /// println!("{:#?}", audio.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn get_audio(
+        &self,
+        customisation_id: impl AsRef<str>,
+        audio_name: impl AsRef<str>,
+    ) -> Result<AudioListing, AudioError> {
+        let mut url = self.service_url.clone();
+        Self::set_audio_path(&mut url, &customisation_id, &audio_name);
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                let root: AudioListing = response
+                    .json()
+                    .await
+                    .map_err(|e| AudioError::DeserializationError(e.to_string()))?;
+                Ok(root)
+            }
+            StatusCode::BAD_REQUEST => Err(AudioError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(AudioError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::NOT_FOUND => Err(AudioError::NotFound404(audio_name.as_ref().to_owned())),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Deletes an existing audio resource from a custom acoustic model. Deleting an archive deletes all of the audio files that it contains. Removing an audio resource does not affect the custom model until you train the model
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom acoustic model that is to be used for the request
+    /// * `audio_name` - The name of the audio resource for the custom acoustic model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// if stt.delete_audio("cust-id", "audio1").await.is_ok() {
+    ///     println!("audio deleted");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn delete_audio(
+        &self,
+        customisation_id: impl AsRef<str>,
+        audio_name: impl AsRef<str>,
+    ) -> Result<(), AudioError> {
+        let mut url = self.service_url.clone();
+        Self::set_audio_path(&mut url, &customisation_id, &audio_name);
+        let req = Request::new(Method::DELETE, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(()),
+            StatusCode::BAD_REQUEST => Err(AudioError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(AudioError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::NOT_FOUND => Err(AudioError::NotFound404(audio_name.as_ref().to_owned())),
+            StatusCode::CONFLICT => Err(AudioError::Conflict409),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    fn set_audio_resources_path(uri: &mut Url, customisation_id: impl AsRef<str>) {
+        Self::set_acoustic_model_path(uri, customisation_id);
+        uri.path_segments_mut().unwrap().push("audio");
+    }
+
+    fn set_audio_path(
+        uri: &mut Url,
+        customisation_id: impl AsRef<str>,
+        audio_name: impl AsRef<str>,
+    ) {
+        Self::set_audio_resources_path(uri, customisation_id);
+        uri.path_segments_mut().unwrap().push(audio_name.as_ref());
+    }
+}
+
+fn is_archive(content_type: &str) -> bool {
+    matches!(
+        content_type.split(';').next().map(str::trim),
+        Some("application/zip" | "application/gzip")
+    )
+}
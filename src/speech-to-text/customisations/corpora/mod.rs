@@ -0,0 +1,293 @@
+use std::path::Path;
+
+use reqwest::{header::CONTENT_TYPE, Body, Method, Request, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, BufReader};
+
+use crate::{error::CommonError, stt::SpeechToText};
+
+use super::errors::CorpusError;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+/// Information about a corpus of a custom language model
+pub struct Corpus {
+    /// The name of the corpus
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The total number of words in the corpus. The value is 0 while the corpus is being processed
+    #[serde(rename = "total_words", default)]
+    pub total_words: u64,
+    /// The number of OOV words that the service extracted from the corpus. The value is 0 while the corpus is being processed
+    #[serde(rename = "out_of_vocabulary_words", default)]
+    pub out_of_vocabulary_words: u64,
+    /// The [`status`] of the corpus
+    ///
+    /// [`status`]: self::CorpusStatus
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<CorpusStatus>,
+    /// If the status of the corpus is [`Undetermined`], a message that describes the error that occurred while adding it
+    ///
+    /// [`Undetermined`]: self::CorpusStatus::Undetermined
+    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+/// The status of a corpus
+pub enum CorpusStatus {
+    /// The service successfully analysed the corpus. The custom model can be trained with data from the corpus
+    #[serde(rename = "analyzed")]
+    Analysed,
+    /// The service is still analysing the corpus. The service cannot accept requests to add new resources or to train the custom model
+    BeingProcessed,
+    /// The service encountered an error while processing the corpus. The `error` field describes the failure
+    Undetermined,
+}
+
+impl SpeechToText {
+    /// Adds a single corpus text file of new training data to a custom language model. Use multiple requests to submit multiple corpus text files. You must use credentials for the instance of the service that owns a model to add a corpus to it. Adding a corpus does not affect the custom language model until you train the model for the new data
+    ///
+    /// The call returns once the service has accepted the corpus; the service then analyses it asynchronously. Use [`get_corpus()`] to check its status
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model that is to be used for the request
+    /// * `corpus_name` - The name of the new corpus for the custom language model. Use a localised name that matches the language of the custom model and reflects the contents of the corpus
+    ///     * Include a maximum of 128 characters in the name
+    ///     * Do not use characters that need to be URL-encoded
+    ///     * Do not use the name of an existing corpus or grammar that is already defined for the custom model
+    /// * `text` - The path to a plain text file that contains the training data for the corpus. Encode the file in UTF-8 if it contains non-ASCII characters
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let file_path = std::path::Path::new("/home/user/corpus.txt");
+    /// stt.add_corpus("cust-id", "corpus1", &file_path).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`get_corpus()`]: Self::get_corpus()
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn add_corpus(
+        &self,
+        customisation_id: impl AsRef<str>,
+        corpus_name: impl AsRef<str>,
+        text: impl AsRef<Path>,
+    ) -> Result<(), CorpusError> {
+        let file = tokio::fs::OpenOptions::new()
+            .read(true)
+            .open(text.as_ref())
+            .await
+            .map_err(|e| CorpusError::FileReadError(e.to_string()))?;
+
+        let mut buf_reader = BufReader::new(file);
+        let mut buffer = Vec::new();
+        buf_reader
+            .read_to_end(&mut buffer)
+            .await
+            .map_err(|e| CorpusError::FileReadError(e.to_string()))?;
+
+        let mut url = self.service_url.clone();
+        Self::set_corpus_path(&mut url, &customisation_id, &corpus_name);
+        let client = self.get_client();
+        let request = client
+            .post(url)
+            .header(CONTENT_TYPE, "text/plain")
+            .body(Body::from(buffer));
+        let response = client.send(request).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => Ok(()),
+            StatusCode::BAD_REQUEST => Err(CorpusError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(CorpusError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::CONFLICT => Err(CorpusError::Conflict409),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Lists information about all corpora from a custom language model. The information includes the name, status, and total number of words for each corpus
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model that is to be used for the request
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let corpora = stt.list_corpora("cust-id").await?;
+    /// println!("found: {:#?} corpora", corpora.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn list_corpora(
+        &self,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<Vec<Corpus>, CorpusError> {
+        let mut url = self.service_url.clone();
+        Self::set_corpora_path(&mut url, &customisation_id);
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                #[derive(Deserialize)]
+                struct Root {
+                    corpora: Vec<Corpus>,
+                }
+                let root: Root = response
+                    .json()
+                    .await
+                    .map_err(|e| CorpusError::DeserializationError(e.to_string()))?;
+                Ok(root.corpora)
+            }
+            StatusCode::BAD_REQUEST => Err(CorpusError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(CorpusError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Gets information about a corpus from a custom language model. The information includes the name, status, and total number of words for the corpus
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model that is to be used for the request
+    /// * `corpus_name` - The name of the corpus for the custom language model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let corpus = stt.get_corpus("cust-id", "corpus1").await?;
+    /// println!("{:#?}", corpus.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn get_corpus(
+        &self,
+        customisation_id: impl AsRef<str>,
+        corpus_name: impl AsRef<str>,
+    ) -> Result<Corpus, CorpusError> {
+        let mut url = self.service_url.clone();
+        Self::set_corpus_path(&mut url, &customisation_id, &corpus_name);
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                let root: Corpus = response
+                    .json()
+                    .await
+                    .map_err(|e| CorpusError::DeserializationError(e.to_string()))?;
+                Ok(root)
+            }
+            StatusCode::BAD_REQUEST => Err(CorpusError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(CorpusError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::NOT_FOUND => Err(CorpusError::NotFound404(corpus_name.as_ref().to_owned())),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Deletes an existing corpus from a custom language model. Removing a corpus does not affect the custom model until you train the model
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model that is to be used for the request
+    /// * `corpus_name` - The name of the corpus for the custom language model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// if stt.delete_corpus("cust-id", "corpus1").await.is_ok() {
+    ///     println!("corpus deleted");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn delete_corpus(
+        &self,
+        customisation_id: impl AsRef<str>,
+        corpus_name: impl AsRef<str>,
+    ) -> Result<(), CorpusError> {
+        let mut url = self.service_url.clone();
+        Self::set_corpus_path(&mut url, &customisation_id, &corpus_name);
+        let req = Request::new(Method::DELETE, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(()),
+            StatusCode::BAD_REQUEST => Err(CorpusError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(CorpusError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::NOT_FOUND => Err(CorpusError::NotFound404(corpus_name.as_ref().to_owned())),
+            StatusCode::CONFLICT => Err(CorpusError::Conflict409),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    fn set_corpora_path(uri: &mut Url, customisation_id: impl AsRef<str>) {
+        uri.set_path(&format!(
+            "v1/customizations/{}/corpora",
+            customisation_id.as_ref()
+        ));
+    }
+
+    fn set_corpus_path(
+        uri: &mut Url,
+        customisation_id: impl AsRef<str>,
+        corpus_name: impl AsRef<str>,
+    ) {
+        uri.set_path(&format!(
+            "v1/customizations/{}/corpora/{}",
+            customisation_id.as_ref(),
+            corpus_name.as_ref()
+        ));
+    }
+}
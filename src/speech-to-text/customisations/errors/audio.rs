@@ -0,0 +1,49 @@
+use thiserror::Error;
+
+use crate::error::impl_from_common_error;
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned when managing the [`audio resources`] of a custom acoustic model
+///
+/// [`audio resources`]: crate::stt::customisations::AudioResource
+pub enum AudioError {
+    /// There was an error establishing the connection
+    #[error("There was an error establishing the connection")]
+    ConnectionError(#[from] reqwest::Error),
+    #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported")]
+    /// A required input parameter is null or a specified input parameter or header value is invalid or not supported. For example, the audio is shorter than one second or the archive contains more than 100 MB of audio
+    BadRequest400,
+    #[error("The specified customisation_id {0} is invalid for the requesting credentials")]
+    /// The specified customisation_id is invalid for the requesting credentials
+    Unauthorised401(String),
+    #[error("The specified audio resource {0} was not found")]
+    /// The specified audio resource does not exist in the custom model
+    NotFound404(String),
+    #[error("The service is currently processing another request for the custom model")]
+    /// The service is currently processing another request for the custom model, such as training it or adding audio
+    Conflict409,
+    #[error("The request specified an unacceptable media type.")]
+    /// The request specified an unacceptable media type with the Content-Type header
+    UnsupportedMediaType415,
+    #[error("The service is currently unavailable")]
+    /// The service is currently unavailable
+    ServiceUnavailable503,
+    #[error("The service experienced an internal error")]
+    /// The service experienced an internal error
+    InternalServerError500,
+    /// The content type cannot be sent as a header value
+    #[error("{0} is not a valid content type")]
+    InvalidContentType(String),
+    /// There was an error reading the file
+    #[error("There was an error reading the file: {0}")]
+    FileReadError(String),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
+    /// The response code the server returnes
+    #[error("{0}")]
+    UnmappedResponse(u16),
+}
+
+impl_from_common_error!(AudioError);
@@ -0,0 +1,43 @@
+use thiserror::Error;
+
+use crate::error::impl_from_common_error;
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned when managing the [`corpora`] of a custom language model
+///
+/// [`corpora`]: crate::stt::customisations::Corpus
+pub enum CorpusError {
+    /// There was an error establishing the connection
+    #[error("There was an error establishing the connection")]
+    ConnectionError(#[from] reqwest::Error),
+    #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported")]
+    /// A required input parameter is null or a specified input parameter or header value is invalid or not supported. For example, the corpus name is already in use or the corpus file is empty
+    BadRequest400,
+    #[error("The specified customisation_id {0} is invalid for the requesting credentials")]
+    /// The specified customisation_id is invalid for the requesting credentials
+    Unauthorised401(String),
+    #[error("The specified corpus {0} was not found")]
+    /// The specified custom language model or corpus was not found
+    NotFound404(String),
+    #[error("The service is currently processing another request for the custom model")]
+    /// The service is currently processing another request for the custom model, such as training it or adding another corpus
+    Conflict409,
+    #[error("The service is currently unavailable")]
+    /// The service is currently unavailable
+    ServiceUnavailable503,
+    #[error("The service experienced an internal error")]
+    /// The service experienced an internal error
+    InternalServerError500,
+    /// There was an error reading the file
+    #[error("There was an error reading the file: {0}")]
+    FileReadError(String),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
+    /// The response code the server returnes
+    #[error("{0}")]
+    UnmappedResponse(u16),
+}
+
+impl_from_common_error!(CorpusError);
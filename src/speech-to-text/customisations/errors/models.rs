@@ -0,0 +1,64 @@
+use thiserror::Error;
+
+use crate::error::impl_from_common_error;
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned when managing custom [`language models`]
+///
+/// [`language models`]: crate::stt::customisations::LanguageModel
+pub enum LanguageModelError {
+    /// There was an error establishing the connection
+    #[error("There was an error establishing the connection")]
+    ConnectionError(#[from] reqwest::Error),
+    #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported")]
+    /// A required input parameter is null or a specified input parameter or header value is invalid or not supported
+    BadRequest400,
+    #[error("The specified customisation_id {0} is invalid for the requesting credentials")]
+    /// The specified customisation_id is invalid for the requesting credentials
+    Unauthorised401(String),
+    #[error("The specified customisation_id {0} was not found")]
+    /// The specified customisation_id was not found
+    NotFound404(String),
+    #[error("The service is currently unavailable")]
+    /// The service is currently unavailable
+    ServiceUnavailable503,
+    #[error("The service experienced an internal error")]
+    /// The service experienced an internal error
+    InternalServerError500,
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
+    /// The response code the server returnes
+    #[error("{0}")]
+    UnmappedResponse(u16),
+}
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned when training or resetting a custom language model
+pub enum TrainLanguageModelError {
+    /// There was an error establishing the connection
+    #[error("There was an error establishing the connection")]
+    ConnectionError(#[from] reqwest::Error),
+    #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported")]
+    /// A required input parameter is null or a specified input parameter or header value is invalid or not supported. For example, the custom model has no training data or contains invalid resources
+    BadRequest400,
+    #[error("The specified customisation_id {0} is invalid for the requesting credentials")]
+    /// The specified customisation_id is invalid for the requesting credentials
+    Unauthorised401(String),
+    #[error("The service is currently processing another request for the custom model")]
+    /// The service is currently processing another request for the custom model, such as training it or adding a corpus
+    Conflict409,
+    #[error("The service is currently unavailable")]
+    /// The service is currently unavailable
+    ServiceUnavailable503,
+    #[error("The service experienced an internal error")]
+    /// The service experienced an internal error
+    InternalServerError500,
+    /// The response code the server returnes
+    #[error("{0}")]
+    UnmappedResponse(u16),
+}
+
+impl_from_common_error!(LanguageModelError, TrainLanguageModelError);
@@ -0,0 +1,303 @@
+use reqwest::{header::CONTENT_TYPE, Method, Request, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+
+use crate::{error::CommonError, stt::SpeechToText};
+
+use super::errors::GrammarError;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+/// Information about a grammar of a custom language model
+pub struct Grammar {
+    /// The name of the grammar
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The number of OOV words that the service extracted from the grammar. The value is 0 while the grammar is being processed
+    #[serde(rename = "out_of_vocabulary_words", default)]
+    pub out_of_vocabulary_words: u64,
+    /// The [`status`] of the grammar
+    ///
+    /// [`status`]: self::GrammarStatus
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<GrammarStatus>,
+    /// If the status of the grammar is [`Undetermined`], a message that describes the error that occurred while adding it
+    ///
+    /// [`Undetermined`]: self::GrammarStatus::Undetermined
+    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+/// The status of a grammar
+pub enum GrammarStatus {
+    /// The service successfully analysed the grammar. The custom model can be trained with data from the grammar
+    #[serde(rename = "analyzed")]
+    Analysed,
+    /// The service is still analysing the grammar. The service cannot accept requests to add new resources or to train the custom model
+    BeingProcessed,
+    /// The service encountered an error while processing the grammar. The `error` field describes the failure
+    Undetermined,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// The format of a grammar, as defined by the W3C Speech Recognition Grammar Specification
+pub enum GrammarFormat {
+    #[default]
+    /// Augmented Backus-Naur Form (ABNF), which uses a plain-text representation
+    Abnf,
+    /// XML Form, which uses XML elements to represent the grammar
+    Xml,
+}
+
+impl GrammarFormat {
+    /// The `Content-Type` that the server expects for a particular format
+    pub fn id(&self) -> &'static str {
+        match self {
+            GrammarFormat::Abnf => "application/srgs",
+            GrammarFormat::Xml => "application/srgs+xml",
+        }
+    }
+}
+
+impl SpeechToText {
+    /// Adds a single grammar file to a custom language model. Once the model is trained, a grammar restricts recognition to the words and phrases that it recognises. You must use credentials for the instance of the service that owns a model to add a grammar to it. Adding a grammar does not affect the custom language model until you train the model for the new data
+    ///
+    /// The call returns once the service has accepted the grammar; the service then analyses it asynchronously. Use [`get_grammar()`] to check its status
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model that is to be used for the request
+    /// * `grammar_name` - The name of the new grammar for the custom language model. Use a localised name that matches the language of the custom model and reflects the contents of the grammar
+    ///     * Include a maximum of 128 characters in the name
+    ///     * Do not use characters that need to be URL-encoded
+    ///     * Do not use the name of an existing grammar or corpus that is already defined for the custom model
+    /// * `grammar` - The contents of the grammar. Encode it in UTF-8 if it contains non-ASCII characters
+    /// * `format` - The [`format`] of the grammar
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{customisations::GrammarFormat, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let grammar = "#ABNF 1.0 ISO-8859-1;\nlanguage en-US;\nmode voice;\nroot $yesno;\n$yesno = yes | no;";
+    /// stt.add_grammar("cust-id", "yesno", grammar, GrammarFormat::Abnf)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`get_grammar()`]: Self::get_grammar()
+    /// [`format`]: crate::stt::customisations::GrammarFormat
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn add_grammar(
+        &self,
+        customisation_id: impl AsRef<str>,
+        grammar_name: impl AsRef<str>,
+        grammar: impl AsRef<str>,
+        format: GrammarFormat,
+    ) -> Result<(), GrammarError> {
+        let mut url = self.service_url.clone();
+        Self::set_grammar_path(&mut url, &customisation_id, &grammar_name);
+        let client = self.get_client();
+        let request = client
+            .post(url)
+            .header(CONTENT_TYPE, format.id())
+            .body(grammar.as_ref().to_owned());
+        let response = client.send(request).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => Ok(()),
+            StatusCode::BAD_REQUEST => Err(GrammarError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(GrammarError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::CONFLICT => Err(GrammarError::Conflict409),
+            StatusCode::PAYLOAD_TOO_LARGE => Err(GrammarError::PayloadTooLarge413),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Lists information about all grammars from a custom language model. The information includes the name, status, and number of out-of-vocabulary words for each grammar
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model that is to be used for the request
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let grammars = stt.list_grammars("cust-id").await?;
+    /// println!("found: {:#?} grammars", grammars.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn list_grammars(
+        &self,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<Vec<Grammar>, GrammarError> {
+        let mut url = self.service_url.clone();
+        Self::set_grammars_path(&mut url, &customisation_id);
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                #[derive(Deserialize)]
+                struct Root {
+                    grammars: Vec<Grammar>,
+                }
+                let root: Root = response
+                    .json()
+                    .await
+                    .map_err(|e| GrammarError::DeserializationError(e.to_string()))?;
+                Ok(root.grammars)
+            }
+            StatusCode::BAD_REQUEST => Err(GrammarError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(GrammarError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Gets information about a grammar from a custom language model. The information includes the name, status, and number of out-of-vocabulary words for the grammar
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model that is to be used for the request
+    /// * `grammar_name` - The name of the grammar for the custom language model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let grammar = stt.get_grammar("cust-id", "yesno").await?;
+    /// println!("{:#?}", grammar.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn get_grammar(
+        &self,
+        customisation_id: impl AsRef<str>,
+        grammar_name: impl AsRef<str>,
+    ) -> Result<Grammar, GrammarError> {
+        let mut url = self.service_url.clone();
+        Self::set_grammar_path(&mut url, &customisation_id, &grammar_name);
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                let root: Grammar = response
+                    .json()
+                    .await
+                    .map_err(|e| GrammarError::DeserializationError(e.to_string()))?;
+                Ok(root)
+            }
+            StatusCode::BAD_REQUEST => Err(GrammarError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(GrammarError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::NOT_FOUND => {
+                Err(GrammarError::NotFound404(grammar_name.as_ref().to_owned()))
+            }
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Deletes an existing grammar from a custom language model. Removing a grammar does not affect the custom model until you train the model
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model that is to be used for the request
+    /// * `grammar_name` - The name of the grammar for the custom language model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// if stt.delete_grammar("cust-id", "yesno").await.is_ok() {
+    ///     println!("grammar deleted");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn delete_grammar(
+        &self,
+        customisation_id: impl AsRef<str>,
+        grammar_name: impl AsRef<str>,
+    ) -> Result<(), GrammarError> {
+        let mut url = self.service_url.clone();
+        Self::set_grammar_path(&mut url, &customisation_id, &grammar_name);
+        let req = Request::new(Method::DELETE, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(()),
+            StatusCode::BAD_REQUEST => Err(GrammarError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(GrammarError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::NOT_FOUND => {
+                Err(GrammarError::NotFound404(grammar_name.as_ref().to_owned()))
+            }
+            StatusCode::CONFLICT => Err(GrammarError::Conflict409),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    fn set_grammars_path(uri: &mut Url, customisation_id: impl AsRef<str>) {
+        uri.set_path(&format!(
+            "v1/customizations/{}/grammars",
+            customisation_id.as_ref()
+        ));
+    }
+
+    fn set_grammar_path(
+        uri: &mut Url,
+        customisation_id: impl AsRef<str>,
+        grammar_name: impl AsRef<str>,
+    ) {
+        uri.set_path(&format!(
+            "v1/customizations/{}/grammars/{}",
+            customisation_id.as_ref(),
+            grammar_name.as_ref()
+        ));
+    }
+}
@@ -0,0 +1,530 @@
+use reqwest::{Method, Request, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::CommonError,
+    stt::{models::ModelID, SpeechToText},
+};
+
+use super::errors::{LanguageModelError, TrainLanguageModelError};
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+/// Information about an existing custom language model
+pub struct LanguageModel {
+    /// The customisation ID (GUID) of the custom language model. The create a custom language model method returns only this field of the object; it does not return the other fields
+    #[serde(rename = "customization_id")]
+    pub customisation_id: String,
+    /// The name of the custom language model
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The language identifier of the custom language model (for example, en-US)
+    #[serde(rename = "language", skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// The name of the language model for which the custom language model was created
+    #[serde(rename = "base_model_name", skip_serializing_if = "Option::is_none")]
+    pub base_model_name: Option<String>,
+    /// The description of the custom language model
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The GUID of the credentials for the instance of the service that owns the custom language model
+    #[serde(rename = "owner", skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// The date and time in Coordinated Universal Time (UTC) at which the custom language model was created. The value is provided in full ISO 8601 format (YYYY-MM-DDThh:mm:ss.sTZD)
+    #[serde(rename = "created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// The current [`status`] of the custom language model
+    ///
+    /// [`status`]: self::ModelStatus
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<ModelStatus>,
+    /// A percentage that indicates the progress of the custom language model's current training. A value of 100 means that the model is fully trained. The progress field does not reflect the progress of the training; the field changes from 0 to 100 when training is complete
+    #[serde(rename = "progress", default)]
+    pub progress: u32,
+    /// If an error occurred while adding a grammar file to the custom language model, a message that describes an Internal Server Error and includes the string Cannot compile grammars
+    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+/// The current status of a custom model
+pub enum ModelStatus {
+    /// The model was created but is waiting either for valid training data to be added or for the service to finish analysing added data
+    Pending,
+    /// The model contains valid data and is ready to be trained. If the model contains a mix of valid and invalid resources, you need to set the `strict` parameter to false for the training to proceed
+    Ready,
+    /// The model is being trained on data
+    Training,
+    /// The model is trained and ready to use
+    Available,
+    /// The model is being upgraded
+    Upgrading,
+    /// Training of the model failed
+    Failed,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// How far along the training of a custom model is, combined from its [`status`] and progress so
+/// that it can be matched on while polling the model
+///
+/// # Example
+/// ``` no_run
+/// # use std::time::Duration;
+/// # use ibm_watson::{
+/// #     auth::IamAuthenticator,
+/// #     stt::{customisations::TrainingState, SpeechToText},
+/// # };
+/// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+/// # let auth = IamAuthenticator::new("api_key").await?;
+/// # let stt = SpeechToText::new(&auth, "service_url");
+/// stt.train_language_model("cust-id", None).await?;
+/// loop {
+///     match stt.get_language_model("cust-id").await?.training_state() {
+///         TrainingState::Available => break,
+///         TrainingState::Failed(reason) => return Err(reason.into()),
+///         TrainingState::Training(progress) => println!("{progress}%"),
+///         TrainingState::Pending => {}
+///     }
+///     tokio::time::sleep(Duration::from_secs(10)).await;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`status`]: self::ModelStatus
+pub enum TrainingState {
+    /// The model is not being trained. It is [`Pending`] or [`Ready`], or the service did not report a status
+    ///
+    /// [`Pending`]: self::ModelStatus::Pending
+    /// [`Ready`]: self::ModelStatus::Ready
+    Pending,
+    /// The model is being trained or upgraded. Contains the progress that the service reported, as a percentage. The service only changes it from 0 to 100 once training is complete, so it is no estimate of the time left
+    Training(u32),
+    /// The model is trained and ready to use
+    Available,
+    /// Training of the model failed. Contains the error that the service reported for the model, which is empty if it reported none
+    Failed(String),
+}
+
+impl TrainingState {
+    pub(crate) fn new(status: Option<&ModelStatus>, progress: u32, error: Option<&str>) -> Self {
+        match status {
+            None | Some(ModelStatus::Pending) | Some(ModelStatus::Ready) => TrainingState::Pending,
+            Some(ModelStatus::Training) | Some(ModelStatus::Upgrading) => {
+                TrainingState::Training(progress)
+            }
+            Some(ModelStatus::Available) => TrainingState::Available,
+            Some(ModelStatus::Failed) => {
+                TrainingState::Failed(error.unwrap_or_default().to_owned())
+            }
+        }
+    }
+}
+
+impl LanguageModel {
+    /// The [`state`] of the training of the model, from its [`status`], [`progress`] and [`error`]
+    ///
+    /// [`state`]: self::TrainingState
+    /// [`status`]: Self::status
+    /// [`progress`]: Self::progress
+    /// [`error`]: Self::error
+    pub fn training_state(&self) -> TrainingState {
+        TrainingState::new(self.status.as_ref(), self.progress, self.error.as_deref())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The type of words from a custom language model's words resource on which to train the model
+pub enum WordTypeToAdd {
+    /// Trains the model on all new words, regardless of whether they were extracted from corpora or grammars or were added or modified by the user
+    All,
+    /// Trains the model only on custom words that were added or modified by the user directly. The model is not trained on new words extracted from corpora or grammars
+    User,
+}
+
+impl WordTypeToAdd {
+    /// The value of the `word_type_to_add` query parameter
+    pub fn id(&self) -> &str {
+        match self {
+            WordTypeToAdd::All => "all",
+            WordTypeToAdd::User => "user",
+        }
+    }
+}
+
+impl SpeechToText {
+    /// Creates a new custom language model for a specified base model. The custom language model can be used only with the base model for which it is created. The model is owned by the instance of the service whose credentials are used to create it
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - A user-defined name for the new custom language model. Use a name that is unique among all custom language models that you own
+    /// * `base_model` - The name of the base language [`model`] that is to be customised by the new custom language model. The new custom model can be used only with the base model that it customises
+    /// * `description` - A description of the new custom language model. Use a localised description that matches the language of the custom model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{models::ModelID, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let model = stt
+    ///     .create_language_model("new model", ModelID::EnUsBroadband, Some("example"))
+    ///     .await?;
+    /// println!("model: {:#?}", model);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`model`]: crate::stt::models::ModelID
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn create_language_model(
+        &self,
+        name: impl AsRef<str>,
+        base_model: ModelID,
+        description: Option<impl AsRef<str>>,
+    ) -> Result<LanguageModel, LanguageModelError> {
+        let mut url = self.service_url.clone();
+        Self::set_language_models_path(&mut url);
+        #[derive(Serialize)]
+        struct FormBody<'a> {
+            name: &'a str,
+            base_model_name: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            description: Option<&'a str>,
+        }
+        let form_body = FormBody {
+            name: name.as_ref(),
+            base_model_name: base_model.to_string(),
+            description: description.as_ref().map(|d| d.as_ref()),
+        };
+        let client = self.get_client();
+        let request = client.post(url).json(&form_body);
+        let response = client.send(request).await?;
+        match response.status() {
+            StatusCode::CREATED => {
+                let root: LanguageModel = response
+                    .json()
+                    .await
+                    .map_err(|e| LanguageModelError::DeserializationError(e.to_string()))?;
+                Ok(root)
+            }
+            StatusCode::BAD_REQUEST => Err(LanguageModelError::BadRequest400),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Lists information about all custom language models that are owned by an instance of the service. Use the `language` parameter to see all custom language models for the specified language. You must use credentials for the instance of the service that owns a model to list information about it
+    ///
+    /// # Parameters
+    ///
+    /// * `language` - The identifier of the language for which custom language models are to be returned (for example, en-US). Pass [`None`] to see all custom language models that are owned by the requester
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let models = stt.list_language_models(Some("en-US")).await?;
+    /// println!("found: {:#?} models", models.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn list_language_models(
+        &self,
+        language: Option<&str>,
+    ) -> Result<Vec<LanguageModel>, LanguageModelError> {
+        let mut url = self.service_url.clone();
+        Self::set_language_models_path(&mut url);
+        if let Some(language) = language {
+            url.query_pairs_mut().append_pair("language", language);
+        }
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                #[derive(Deserialize)]
+                struct Root {
+                    customizations: Vec<LanguageModel>,
+                }
+                let root: Root = response
+                    .json()
+                    .await
+                    .map_err(|e| LanguageModelError::DeserializationError(e.to_string()))?;
+                Ok(root.customizations)
+            }
+            StatusCode::BAD_REQUEST => Err(LanguageModelError::BadRequest400),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Gets information about a specified custom language model. You must use credentials for the instance of the service that owns a model to list information about it
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model. You must make the request with credentials for the instance of the service that owns the custom model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let model = stt.get_language_model("cust-id").await?;
+    /// println!("{:#?}", model.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn get_language_model(
+        &self,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<LanguageModel, LanguageModelError> {
+        let mut url = self.service_url.clone();
+        Self::set_language_model_path(&mut url, &customisation_id);
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                let root: LanguageModel = response
+                    .json()
+                    .await
+                    .map_err(|e| LanguageModelError::DeserializationError(e.to_string()))?;
+                Ok(root)
+            }
+            StatusCode::BAD_REQUEST => Err(LanguageModelError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(LanguageModelError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::NOT_FOUND => Err(LanguageModelError::NotFound404(
+                customisation_id.as_ref().to_owned(),
+            )),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Deletes an existing custom language model. The custom model cannot be deleted if another request, such as adding a corpus or grammar to the model, is currently being processed. You must use credentials for the instance of the service that owns a model to delete it
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model. You must make the request with credentials for the instance of the service that owns the custom model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// if stt.delete_language_model("cust-id").await.is_ok() {
+    ///     println!("model deleted");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn delete_language_model(
+        &self,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<(), LanguageModelError> {
+        let mut url = self.service_url.clone();
+        Self::set_language_model_path(&mut url, &customisation_id);
+        let req = Request::new(Method::DELETE, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(()),
+            StatusCode::BAD_REQUEST => Err(LanguageModelError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(LanguageModelError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::NOT_FOUND => Err(LanguageModelError::NotFound404(
+                customisation_id.as_ref().to_owned(),
+            )),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Initiates the training of a custom language model with new resources such as corpora, grammars, and custom words. Training is asynchronous: the call returns as soon as the service accepts the request. Poll [`get_language_model()`] until its [`status`] becomes [`Available`] to know when the model is trained
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model that is to be trained
+    /// * `word_type_to_add` - The [`type of words`] from the custom model's words resource on which to train the model. If [`None`] is passed, the service trains on all words
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{customisations::WordTypeToAdd, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// stt.train_language_model("cust-id", Some(WordTypeToAdd::User))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`get_language_model()`]: Self::get_language_model()
+    /// [`status`]: self::LanguageModel::status
+    /// [`Available`]: self::ModelStatus::Available
+    /// [`type of words`]: self::WordTypeToAdd
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn train_language_model(
+        &self,
+        customisation_id: impl AsRef<str>,
+        word_type_to_add: Option<WordTypeToAdd>,
+    ) -> Result<(), TrainLanguageModelError> {
+        let mut url = self.service_url.clone();
+        url.set_path(&format!(
+            "v1/customizations/{}/train",
+            customisation_id.as_ref()
+        ));
+        if let Some(word_type) = word_type_to_add {
+            url.query_pairs_mut()
+                .append_pair("word_type_to_add", word_type.id());
+        }
+        self.send_training_request(url, customisation_id).await
+    }
+
+    /// Resets a custom language model by removing all of its training data: corpora, grammars, and words. The metadata of the model, such as its name and language, is preserved
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model that is to be reset
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// stt.reset_language_model("cust-id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn reset_language_model(
+        &self,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<(), TrainLanguageModelError> {
+        let mut url = self.service_url.clone();
+        url.set_path(&format!(
+            "v1/customizations/{}/reset",
+            customisation_id.as_ref()
+        ));
+        self.send_training_request(url, customisation_id).await
+    }
+
+    /// Upgrades a custom language model to the latest version of its base model. Upgrading is asynchronous: the model's [`status`] is [`Upgrading`] until it completes, and it returns to [`Available`] afterwards. The model must be [`Ready`] or [`Available`], and the service returns [`Conflict409`] if it is busy, for example while it is being trained or is processing a corpus or grammar
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom language model that is to be upgraded
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{customisations::errors::TrainLanguageModelError, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// match stt.upgrade_language_model("cust-id").await {
+    ///     Ok(()) => println!("upgrading"),
+    ///     Err(TrainLanguageModelError::Conflict409) => println!("busy, try again later"),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`status`]: self::LanguageModel::status
+    /// [`Upgrading`]: self::ModelStatus::Upgrading
+    /// [`Ready`]: self::ModelStatus::Ready
+    /// [`Available`]: self::ModelStatus::Available
+    /// [`Conflict409`]: crate::stt::customisations::errors::TrainLanguageModelError::Conflict409
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn upgrade_language_model(
+        &self,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<(), TrainLanguageModelError> {
+        let mut url = self.service_url.clone();
+        url.set_path(&format!(
+            "v1/customizations/{}/upgrade_model",
+            customisation_id.as_ref()
+        ));
+        self.send_training_request(url, customisation_id).await
+    }
+
+    async fn send_training_request(
+        &self,
+        url: Url,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<(), TrainLanguageModelError> {
+        let req = Request::new(Method::POST, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => Ok(()),
+            StatusCode::BAD_REQUEST => Err(TrainLanguageModelError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(TrainLanguageModelError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::CONFLICT => Err(TrainLanguageModelError::Conflict409),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    fn set_language_models_path(uri: &mut Url) {
+        uri.set_path("v1/customizations");
+    }
+
+    pub(crate) fn set_language_model_path(uri: &mut Url, customisation_id: impl AsRef<str>) {
+        uri.set_path(&format!("v1/customizations/{}", customisation_id.as_ref()));
+    }
+}
@@ -0,0 +1,318 @@
+use reqwest::{Method, Request, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+
+use crate::{error::CommonError, stt::SpeechToText};
+
+use super::errors::WordError;
+
+#[derive(Clone, Debug, Eq, PartialEq, Default, Serialize, Deserialize)]
+/// Defines a custom word and how it is pronounced and displayed in a custom [`language model`]
+///
+/// [`language model`]: crate::stt::customisations::LanguageModel
+pub struct Word {
+    /// The custom word that is to be added to or updated in the custom language model. Do not include spaces in the word. Use a - (dash) or _ (underscore) to connect the tokens of compound words
+    #[serde(rename = "word")]
+    pub word: String,
+    /// Pronunciations for the word. Each pronunciation is a sounds-like spelling of the word, for example `IEEE` can sound like `i triple e`. You can specify a maximum of five sounds-like pronunciations for a word. If you omit them, the service attempts to set the pronunciation from its spelling
+    #[serde(rename = "sounds_like", default, skip_serializing_if = "Vec::is_empty")]
+    pub sounds_like: Vec<String>,
+    /// An alternative spelling for the custom word when it appears in a transcript. Use it when you want the word to have a spelling that is different from its usual representation or from its spelling in corpora training data
+    #[serde(rename = "display_as", skip_serializing_if = "Option::is_none")]
+    pub display_as: Option<String>,
+}
+
+impl SpeechToText {
+    /// Adds one or more custom words to a custom language model. Adding a word that already exists in the model overwrites its existing definition. Adding words does not affect the custom language model until you train the model. You must use credentials for the instance of the service that owns a model to add words to it
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom [`language model`]. You must make the request with credentials for the instance of the service that owns the custom model
+    /// * `words` - [`Words`] that are to be added or updated for the custom model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{customisations::Word, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let word = Word {
+    ///     word: String::from("IEEE"),
+    ///     sounds_like: vec![String::from("i triple e")],
+    ///     display_as: None,
+    /// };
+    /// if stt.add_words("customisation_id", &[word]).await.is_ok() {
+    ///     println!("word(s) added!");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`language model`]: crate::stt::customisations::LanguageModel
+    /// [`Words`]: self::Word
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn add_words(
+        &self,
+        customisation_id: impl AsRef<str>,
+        words: &[Word],
+    ) -> Result<(), WordError> {
+        let mut url = self.service_url.clone();
+        Self::set_words_path(&mut url, &customisation_id);
+        #[derive(Serialize)]
+        struct FormBody<'a> {
+            words: &'a [Word],
+        }
+        let client = self.get_client();
+        let request = client.post(url).json(&FormBody { words });
+        let response = client.send(request).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => Ok(()),
+            StatusCode::BAD_REQUEST => Err(WordError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(WordError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::CONFLICT => Err(WordError::Conflict409),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Adds a single custom [`word`] to a custom language model. Adding a word that already exists in the model overwrites its existing definition. You must use credentials for the instance of the service that owns a model to add a word to it
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom [`language model`]. You must make the request with credentials for the instance of the service that owns the custom model
+    /// * `word` - The [`word`] that is to be added or updated for the custom model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{customisations::Word, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let word = Word {
+    ///     word: String::from("HHonors"),
+    ///     sounds_like: vec![String::from("hilton honors")],
+    ///     display_as: Some(String::from("HHonors")),
+    /// };
+    /// stt.add_word("customisation_id", &word).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`word`]: self::Word
+    /// [`language model`]: crate::stt::customisations::LanguageModel
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn add_word(
+        &self,
+        customisation_id: impl AsRef<str>,
+        word: &Word,
+    ) -> Result<(), WordError> {
+        let mut url = self.service_url.clone();
+        Self::set_word_path(&mut url, &customisation_id, &word.word);
+        #[derive(Serialize)]
+        struct FormBody<'a> {
+            #[serde(skip_serializing_if = "<[String]>::is_empty")]
+            sounds_like: &'a [String],
+            #[serde(skip_serializing_if = "Option::is_none")]
+            display_as: Option<&'a str>,
+        }
+        let form_body = FormBody {
+            sounds_like: &word.sounds_like,
+            display_as: word.display_as.as_deref(),
+        };
+        let client = self.get_client();
+        let request = client.request(Method::PUT, url).json(&form_body);
+        let response = client.send(request).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => Ok(()),
+            StatusCode::BAD_REQUEST => Err(WordError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(WordError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::CONFLICT => Err(WordError::Conflict409),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Lists information about the custom words from a custom language model. You must use credentials for the instance of the service that owns a model to list its words
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom [`language model`]. You must make the request with credentials for the instance of the service that owns the custom model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let words = stt.list_words("customisation_id").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`language model`]: crate::stt::customisations::LanguageModel
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn list_words(
+        &self,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<Vec<Word>, WordError> {
+        let mut url = self.service_url.clone();
+        Self::set_words_path(&mut url, &customisation_id);
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                #[derive(Deserialize)]
+                struct Root {
+                    words: Vec<Word>,
+                }
+                let root: Root = response
+                    .json()
+                    .await
+                    .map_err(|e| WordError::DeserializationError(e.to_string()))?;
+                Ok(root.words)
+            }
+            StatusCode::BAD_REQUEST => Err(WordError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(WordError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Gets information about a custom word from a custom language model. You must use credentials for the instance of the service that owns a model to list information about its words
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom [`language model`]. You must make the request with credentials for the instance of the service that owns the custom model
+    /// * `word` - The custom word that is to be read from the custom [`language model`]
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let word = stt.get_word("customisation_id", "IEEE").await?;
+    /// println!("{:#?}", word);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`language model`]: crate::stt::customisations::LanguageModel
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn get_word(
+        &self,
+        customisation_id: impl AsRef<str>,
+        word: impl AsRef<str>,
+    ) -> Result<Word, WordError> {
+        let mut url = self.service_url.clone();
+        Self::set_word_path(&mut url, &customisation_id, &word);
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                let root: Word = response
+                    .json()
+                    .await
+                    .map_err(|e| WordError::DeserializationError(e.to_string()))?;
+                Ok(root)
+            }
+            StatusCode::BAD_REQUEST => Err(WordError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(WordError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::NOT_FOUND => Err(WordError::NotFound404(word.as_ref().to_owned())),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Deletes a custom word from a custom language model. Removing a custom word does not affect the custom model until you train the model. You must use credentials for the instance of the service that owns a model to delete its words
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom [`language model`]. You must make the request with credentials for the instance of the service that owns the custom model
+    /// * `word` - The custom word that is to be deleted from the custom [`language model`]
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// if stt.delete_word("customisation_id", "IEEE").await.is_ok() {
+    ///     println!("word deleted");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`language model`]: crate::stt::customisations::LanguageModel
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn delete_word(
+        &self,
+        customisation_id: impl AsRef<str>,
+        word: impl AsRef<str>,
+    ) -> Result<(), WordError> {
+        let mut url = self.service_url.clone();
+        Self::set_word_path(&mut url, &customisation_id, &word);
+        let req = Request::new(Method::DELETE, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(()),
+            StatusCode::BAD_REQUEST => Err(WordError::BadRequest400),
+            StatusCode::UNAUTHORIZED => Err(WordError::Unauthorised401(
+                customisation_id.as_ref().to_owned(),
+            )),
+            StatusCode::NOT_FOUND => Err(WordError::NotFound404(word.as_ref().to_owned())),
+            StatusCode::CONFLICT => Err(WordError::Conflict409),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    fn set_words_path(uri: &mut Url, customisation_id: impl AsRef<str>) {
+        uri.set_path(&format!(
+            "v1/customizations/{}/words",
+            customisation_id.as_ref()
+        ));
+    }
+
+    fn set_word_path(uri: &mut Url, customisation_id: impl AsRef<str>, word: impl AsRef<str>) {
+        uri.set_path(&format!(
+            "v1/customizations/{}/words/{}",
+            customisation_id.as_ref(),
+            word.as_ref()
+        ));
+    }
+}
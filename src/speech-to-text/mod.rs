@@ -1,47 +1,275 @@
-use reqwest::{
-    header::{HeaderMap, HeaderValue, AUTHORIZATION},
-    Client, ClientBuilder,
+use reqwest::{Client, Url};
+
+use crate::{
+    auth::IamAuthenticator,
+    client::{parse_service_url, ServiceClient},
+    error::{PingError, ServiceError},
 };
 
-use crate::auth::IamAuthenticator;
+use self::models::models_url;
+
+mod builder;
+pub use builder::SpeechToTextBuilder;
 
+/// A blocking Speech To Text client
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
+/// Manage custom language and acoustic models
+pub mod customisations;
 pub mod models;
+/// Transcribe audio to text
+pub mod recognition;
+
+pub use self::recognition::verify_callback_signature;
 
-/// Creates a client used to send requests to your Text To Speech endpoint
-pub struct SpeechToText<'a> {
-    service_url: &'a str,
-    client: Client,
+/// Creates a client used to send requests to your Speech To Text endpoint. It owns its
+/// configuration and is cheap to clone, so it can be shared across tasks
+#[derive(Clone)]
+pub struct SpeechToText {
+    service_url: Url,
+    client: ServiceClient,
 }
 
-impl<'a> SpeechToText<'a> {
-    pub(crate) fn get_client(&self) -> Client {
+impl SpeechToText {
+    pub(crate) fn get_client(&self) -> ServiceClient {
         self.client.clone()
     }
 
-    pub fn new(authenticator: &'a IamAuthenticator, service_url: &'a str) -> Self {
-        let client = ClientBuilder::new();
-        let default_headers = Self::default_headers(authenticator.token_response().access_token());
-        let client = client.default_headers(default_headers);
+    /// Create a new Speech To Text instance. This instance will be used to make all the requests
+    /// to the speech to text service.
+    ///
+    /// # Parameters
+    /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
+    /// * `service_url` - The endpoint for your speech to text instance. All Speech To Text
+    ///   requests will be made to this endpoint
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// let auth = IamAuthenticator::new("api_key").await?;
+    /// let stt = SpeechToText::new(&auth, "service_url");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `service_url` is not an absolute `http` or `https` url. Use [`try_new()`] to
+    /// handle an invalid url instead
+    ///
+    /// [`IamAuthenticator`]: super::auth::IamAuthenticator
+    /// [`try_new()`]: Self::try_new()
+    pub fn new(authenticator: &IamAuthenticator, service_url: impl Into<String>) -> Self {
+        Self::try_new(authenticator, service_url).unwrap_or_else(|e| panic!("{e}"))
+    }
 
-        #[cfg(feature = "http2")]
-        let client = ClientBuilder::use_rustls_tls(client);
+    /// Create a new Speech To Text instance like [`new()`], but return [`InvalidUrl`] instead of
+    /// panicking if `service_url` is not an absolute `http` or `https` url
+    ///
+    /// # Parameters
+    /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
+    /// * `service_url` - The endpoint for your speech to text instance. All Speech To Text
+    ///   requests will be made to this endpoint
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// let auth = IamAuthenticator::new("api_key").await?;
+    /// let stt = SpeechToText::try_new(&auth, "https://api.eu-gb.speech-to-text.watson.cloud.ibm.com")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`IamAuthenticator`]: super::auth::IamAuthenticator
+    /// [`new()`]: Self::new()
+    /// [`InvalidUrl`]: crate::error::ServiceError::InvalidUrl
+    pub fn try_new(
+        authenticator: &IamAuthenticator,
+        service_url: impl Into<String>,
+    ) -> Result<Self, ServiceError> {
+        Self::builder(authenticator, service_url).try_build()
+    }
 
-        #[cfg(feature = "http2")]
-        let client = client.http2_prior_knowledge();
+    /// Configure a new Speech To Text instance, for example to set a request timeout or tune the
+    /// connection pool. See [`SpeechToTextBuilder`] for the available options
+    ///
+    /// # Parameters
+    /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
+    /// * `service_url` - The endpoint for your speech to text instance. All Speech To Text
+    ///   requests will be made to this endpoint
+    ///
+    /// [`IamAuthenticator`]: super::auth::IamAuthenticator
+    /// [`SpeechToTextBuilder`]: self::SpeechToTextBuilder
+    pub fn builder(
+        authenticator: &IamAuthenticator,
+        service_url: impl Into<String>,
+    ) -> SpeechToTextBuilder<'_> {
+        SpeechToTextBuilder::new(authenticator, service_url)
+    }
 
-        let client = client.build().unwrap();
+    /// Create a new Speech To Text instance that sends its requests with a preconfigured
+    /// [`Client`]. Use this to control timeouts, proxies or connection pooling yourself. The
+    /// authentication headers are added to every request, so the client does not need them
+    ///
+    /// # Parameters
+    /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
+    /// * `service_url` - The endpoint for your speech to text instance. All Speech To Text
+    ///   requests will be made to this endpoint
+    /// * `client` - The [`Client`] to send requests with
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// let auth = IamAuthenticator::new("api_key").await?;
+    /// let client = reqwest::Client::builder()
+    ///     .timeout(std::time::Duration::from_secs(30))
+    ///     .build()?;
+    /// let stt = SpeechToText::with_client(&auth, "service_url", client);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `service_url` is not an absolute `http` or `https` url. Use
+    /// [`try_with_client()`] to handle an invalid url instead
+    ///
+    /// [`IamAuthenticator`]: super::auth::IamAuthenticator
+    /// [`Client`]: reqwest::Client
+    /// [`try_with_client()`]: Self::try_with_client()
+    pub fn with_client(
+        authenticator: &IamAuthenticator,
+        service_url: impl Into<String>,
+        client: Client,
+    ) -> Self {
+        Self::try_with_client(authenticator, service_url, client).unwrap_or_else(|e| panic!("{e}"))
+    }
 
-        Self {
+    /// Create a new Speech To Text instance like [`with_client()`], but return [`InvalidUrl`] instead
+    /// of panicking if `service_url` is not an absolute `http` or `https` url
+    ///
+    /// # Parameters
+    /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
+    /// * `service_url` - The endpoint for your speech to text instance. All Speech To Text
+    ///   requests will be made to this endpoint
+    /// * `client` - The [`Client`] to send requests with
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// let auth = IamAuthenticator::new("api_key").await?;
+    /// let client = reqwest::Client::new();
+    /// let stt = SpeechToText::try_with_client(&auth, "https://api.eu-gb.speech-to-text.watson.cloud.ibm.com", client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`IamAuthenticator`]: super::auth::IamAuthenticator
+    /// [`Client`]: reqwest::Client
+    /// [`with_client()`]: Self::with_client()
+    /// [`InvalidUrl`]: crate::error::ServiceError::InvalidUrl
+    pub fn try_with_client(
+        authenticator: &IamAuthenticator,
+        service_url: impl Into<String>,
+        client: Client,
+    ) -> Result<Self, ServiceError> {
+        let service_url = parse_service_url(&service_url.into())?;
+        let client =
+            ServiceClient::with_client(authenticator.token_response().access_token(), client);
+        Ok(Self {
             service_url,
             client,
-        }
+        })
+    }
+
+    /// Associate a customer ID with all the data that is sent to the service from now on, by passing it in the `X-Watson-Metadata` header. The ID is percent-encoded. The service can then delete all the data that is associated with it on request
+    ///
+    /// # Parameters
+    ///
+    /// * `customer_id` - The customer ID to associate with the data
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// let mut stt = SpeechToText::new(&auth, "service_url");
+    /// stt.set_customer_id("my_customer_ID");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_customer_id(&mut self, customer_id: impl AsRef<str>) {
+        self.client.set_customer_id(customer_id.as_ref());
+    }
+
+    /// Opt out of having the data of requests made from now on used to improve the service, by sending `X-Watson-Learning-Opt-Out: true` with every request. By default the service may use the data, which matches IBM's default
+    ///
+    /// # Parameters
+    ///
+    /// * `opt_out` - Whether to opt out. Pass `false` to stop sending the header
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// let mut stt = SpeechToText::new(&auth, "service_url");
+    /// stt.set_learning_opt_out(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_learning_opt_out(&mut self, opt_out: bool) {
+        self.client.set_learning_opt_out(opt_out);
     }
 
-    fn default_headers(token: &str) -> HeaderMap<HeaderValue> {
-        let mut headers = HeaderMap::new();
-        let mut auth_value = HeaderValue::from_str(&format!("Bearer {}", token)).unwrap();
-        auth_value.set_sensitive(true);
-        headers.insert(AUTHORIZATION, auth_value);
-        headers
+    /// Checks that the service can be reached and that it accepts the credentials, for example as a health check before starting a long job. It lists the models, which is the cheapest authenticated request, and gives up if the service has not responded within 5 seconds
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     error::PingError,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// let stt = SpeechToText::new(&auth, "service_url");
+    /// match stt.ping().await {
+    ///     Ok(()) => println!("ready"),
+    ///     Err(PingError::Unauthorised401(e)) => println!("check the API key: {e}"),
+    ///     Err(e) => println!("the service cannot be reached: {e}"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn ping(&self) -> Result<(), PingError> {
+        self.client.ping(models_url(&self.service_url)).await
     }
 }
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::error::impl_from_common_error;
+
 #[derive(Error, Debug)]
 #[non_exhaustive]
 /// Errors that may be returned when listing [`Watson Models`]
@@ -7,7 +9,7 @@ use thiserror::Error;
 /// [`Watson Models`]: crate::stt::models::Model
 pub enum ListModelsError {
     #[error("The request specified an Accept header with an incompatible content type.")]
-    /// The request specified an Accept header with an incompatible content type
+    /// The request specified an Accept header with an incompatible content type. Requests ask for `application/json`, so this means that the content negotiation failed, for example because a proxy or a preconfigured client changed the header
     NotAcceptable406, //406
     #[error("The request specified an unacceptable media type.")]
     /// The request specified an unacceptable media type
@@ -22,6 +24,9 @@ pub enum ListModelsError {
     /// There was an error making the request
     ConnectionError(#[from] reqwest::Error),
 
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     #[error("{0}")]
     /// There was an error making the request
     UnmappedResponse(u16),
@@ -34,7 +39,7 @@ pub enum GetModelError {
     /// The specified `model_id` was not found
     NotFound404(String), //404
     #[error("The request specified an Accept header with an incompatible content type.")]
-    /// The request specified an Accept header with an incompatible content type
+    /// The request specified an Accept header with an incompatible content type. Requests ask for `application/json`, so this means that the content negotiation failed, for example because a proxy or a preconfigured client changed the header
     NotAcceptable406, //406
     #[error("The request specified an unacceptable media type.")]
     /// The request specified an unacceptable media type
@@ -48,7 +53,12 @@ pub enum GetModelError {
     #[error("{0}")]
     /// There was an error making the request
     ConnectionError(#[from] reqwest::Error),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     #[error("{0}")]
     /// There was an error making the request
     UnmappedResponse(u16),
 }
+
+impl_from_common_error!(ListModelsError, GetModelError);
@@ -1,14 +1,17 @@
 pub mod errors;
 
+use std::fmt::Display;
+
 use self::errors::{GetModelError, ListModelsError};
+use crate::error::CommonError;
 
 use super::SpeechToText;
 
-use reqwest::{Method, Request, StatusCode, Url, Version};
+use reqwest::{Method, Request, StatusCode, Url};
 use serde::Deserialize;
 
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ModelID {
     #[deprecated]
     ArArBroadband,
@@ -81,17 +84,19 @@ pub enum ModelID {
     ZhCnBroadband,
     ZhCnNarrowband,
     ZhCnTelephony,
+    /// Any other model, by the identifier that the service uses for it, for example `en-US_Telephony` or the name of a model that was added after this version of the crate
+    Custom(String),
 }
 
-impl ToString for ModelID {
-    fn to_string(&self) -> String {
-        match self {
+impl Display for ModelID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let id = match self {
             #[allow(deprecated)]
             ModelID::ArArBroadband => "ar-AR_BroadbandModel",
             ModelID::ArMsBroadband => "ar-MS_BroadbandModel",
             ModelID::ArMsTelephony => "ar-MS_Telephony",
             ModelID::CsCzTelephony => "cs-CZ_Telephony",
-            ModelID::DeDeBroadband => "cs-CZ_Telephony",
+            ModelID::DeDeBroadband => "de-DE_BroadbandModel",
             ModelID::DeDeMultimedia => "de-DE_Multimedia",
             ModelID::DeDeNarrowband => "de-DE_NarrowbandModel",
             ModelID::DeDeTelephony => "de-DE_Telephony",
@@ -128,7 +133,7 @@ impl ToString for ModelID {
             ModelID::FrCaBroadband => "fr-CA_BroadbandModel",
             ModelID::FrCaMultimedia => "fr-CA_Multimedia",
             ModelID::FrCaNarrowband => "fr-CA_NarrowbandModel",
-            ModelID::FrCaTelephony => "fr-CA_NarrowbandModel",
+            ModelID::FrCaTelephony => "fr-CA_Telephony",
             ModelID::FrFrBroadband => "fr-FR_BroadbandModel",
             ModelID::FrFrMultimedia => "fr-FR_Multimedia",
             ModelID::FrFrNarrowband => "fr-FR_NarrowbandModel",
@@ -157,8 +162,62 @@ impl ToString for ModelID {
             ModelID::ZhCnBroadband => "zh-CN_BroadbandModel",
             ModelID::ZhCnNarrowband => "zh-CN_NarrowbandModel",
             ModelID::ZhCnTelephony => "zh-CN_Telephony",
+            ModelID::Custom(id) => id,
+        };
+        write!(f, "{id}")
+    }
+}
+
+impl ModelID {
+    /// The features that IBM documents for the model, without asking the service. Use it to narrow down the models before fetching them, for example to those that support speaker labels. It is a best-effort answer that may fall behind the service, so use [`get_model()`] for the authoritative one
+    ///
+    /// Every model supports custom language models. Only the previous-generation models, the
+    /// `Broadband` and `Narrowband` ones, support custom acoustic models. Speaker labels are
+    /// supported by every next-generation model, the `Telephony` and `Multimedia` ones, and by a
+    /// few previous-generation ones. A [`Custom`] model reports no features, since nothing is
+    /// known about it
+    ///
+    /// # Example
+    /// ```
+    /// # use ibm_watson::stt::models::ModelID;
+    /// let features = ModelID::EnGbTelephony.default_features();
+    /// assert!(features.speaker_labels);
+    /// assert!(!features.custom_acoustic_model);
+    /// ```
+    ///
+    /// [`get_model()`]: crate::stt::SpeechToText::get_model()
+    /// [`Custom`]: Self::Custom
+    pub fn default_features(&self) -> SupportedFeatures {
+        if let ModelID::Custom(_) = self {
+            return SupportedFeatures::default();
+        }
+        let id = self.to_string();
+        let next_generation = id.ends_with("_Telephony") || id.ends_with("_Multimedia");
+        let speaker_labels = next_generation
+            || matches!(
+                self,
+                ModelID::DeDeNarrowband
+                    | ModelID::EnAuBroadband
+                    | ModelID::EnAuNarrowband
+                    | ModelID::EnGbBroadband
+                    | ModelID::EnGbNarrowband
+                    | ModelID::EnUsBroadband
+                    | ModelID::EnUsNarrowband
+                    | ModelID::EnUsShortFormNarrowband
+                    | ModelID::EsArNarrowband
+                    | ModelID::EsClNarrowband
+                    | ModelID::EsCoNarrowband
+                    | ModelID::EsEsNarrowband
+                    | ModelID::EsMxNarrowband
+                    | ModelID::EsPeNarrowband
+                    | ModelID::JaJpBroadband
+                    | ModelID::JaJpNarrowband
+            );
+        SupportedFeatures {
+            custom_language_model: true,
+            custom_acoustic_model: !next_generation,
+            speaker_labels,
         }
-        .to_string()
     }
 }
 
@@ -183,64 +242,92 @@ pub struct SupportedFeatures {
     pub speaker_labels: bool,
 }
 
-impl SpeechToText<'_> {
+impl SpeechToText {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
     pub async fn list_models(&self) -> Result<Vec<Model>, ListModelsError> {
-        let mut url = Url::parse(self.service_url).unwrap();
-
-        Self::set_models_path(&mut url);
-
-        let mut req = Request::new(Method::GET, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let req = Request::new(Method::GET, models_url(&self.service_url));
 
         let client = self.get_client();
         let response = client.execute(req).await?;
         match response.status() {
             StatusCode::OK => {
-                #[derive(Deserialize)]
-                struct Root {
-                    models: Vec<Model>,
-                }
-                let root: Root = response.json().await.unwrap();
+                let root: ModelList = response
+                    .json()
+                    .await
+                    .map_err(|e| ListModelsError::DeserializationError(e.to_string()))?;
 
                 Ok(root.models)
             }
-            StatusCode::NOT_ACCEPTABLE => Err(ListModelsError::NotAcceptable406),
-            StatusCode::UNSUPPORTED_MEDIA_TYPE => Err(ListModelsError::UnsupportedMediaType415),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(ListModelsError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(ListModelsError::ServiceUnavailable503),
-            _ => Err(ListModelsError::UnmappedResponse(response.status().into())),
+            status => Err(list_models_error(status)),
         }
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
     pub async fn get_model(&self, model_id: &ModelID) -> Result<Model, GetModelError> {
-        let mut url = Url::parse(self.service_url).unwrap();
-        url.set_path(&format!("v1/models/{}", model_id.to_string()));
-        let mut req = Request::new(Method::GET, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let mut url = self.service_url.clone();
+        url.set_path(&format!("v1/models/{}", model_id));
+        let req = Request::new(Method::GET, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
         match response.status() {
             StatusCode::OK => {
-                let root: Model = response.json().await.unwrap();
+                let root: Model = response
+                    .json()
+                    .await
+                    .map_err(|e| GetModelError::DeserializationError(e.to_string()))?;
                 Ok(root)
             }
-            StatusCode::NOT_FOUND => Err(GetModelError::NotFound404(model_id.to_string())),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(GetModelError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(GetModelError::ServiceUnavailable503),
-            StatusCode::UNSUPPORTED_MEDIA_TYPE => Err(GetModelError::UnsupportedMediaType415),
-            StatusCode::NOT_ACCEPTABLE => Err(GetModelError::NotAcceptable406),
-            _ => Err(GetModelError::UnmappedResponse(response.status().as_u16())),
+            status => Err(get_model_error(status, model_id)),
         }
     }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ModelList {
+    pub(crate) models: Vec<Model>,
+}
+
+/// The url that lists the models. Shared by the async and blocking clients
+pub(crate) fn models_url(service_url: &Url) -> Url {
+    let mut url = service_url.clone();
+    url.set_path("v1/models");
+    url
+}
+
+/// The error for an unsuccessful response when listing models
+pub(crate) fn list_models_error(status: StatusCode) -> ListModelsError {
+    match status {
+        StatusCode::NOT_ACCEPTABLE => ListModelsError::NotAcceptable406,
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => ListModelsError::UnsupportedMediaType415,
+        _ => CommonError::from_status(status).into(),
+    }
+}
+
+/// The error for an unsuccessful response when getting `model_id`
+pub(crate) fn get_model_error(status: StatusCode, model_id: &ModelID) -> GetModelError {
+    match status {
+        StatusCode::NOT_FOUND => GetModelError::NotFound404(model_id.to_string()),
+        StatusCode::NOT_ACCEPTABLE => GetModelError::NotAcceptable406,
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => GetModelError::UnsupportedMediaType415,
+        _ => CommonError::from_status(status).into(),
+    }
+}
+
+impl From<&str> for ModelID {
+    fn from(id: &str) -> Self {
+        ModelID::Custom(id.to_owned())
+    }
+}
 
-    fn set_models_path(uri: &mut Url) {
-        uri.set_path("v1/models");
+impl From<String> for ModelID {
+    fn from(id: String) -> Self {
+        ModelID::Custom(id)
     }
 }
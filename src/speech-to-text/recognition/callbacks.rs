@@ -0,0 +1,185 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hmac::{Hmac, Mac};
+use reqwest::{Method, Request, StatusCode};
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+
+use super::errors::CallbackError;
+use crate::{error::CommonError, stt::SpeechToText};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// The outcome of registering a callback URL
+pub struct RegisterStatus {
+    /// Whether the URL was newly allowlisted or already had been. It is taken from the status code
+    /// of the response
+    #[serde(rename = "status")]
+    pub status: CallbackStatus,
+    /// The callback URL that was registered
+    #[serde(rename = "url")]
+    pub url: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Whether a callback URL was allowlisted by a request to register it. Both mean that the URL can
+/// be used with [`create_recognition_job()`]
+///
+/// [`create_recognition_job()`]: crate::stt::SpeechToText::create_recognition_job()
+pub enum CallbackStatus {
+    /// The URL was allowlisted by this request (`201 Created`). The service verified it by sending a `GET` request with a `challenge_string` query parameter, which the URL echoed back
+    #[serde(rename = "created")]
+    Created,
+    /// The URL was allowlisted before this request (`200 OK`), so it was not verified again. A `user_secret` that was passed with this request is ignored, and the secret that was registered first is still used to sign notifications
+    #[serde(rename = "already created")]
+    AlreadyCreated,
+}
+
+impl SpeechToText {
+    /// Registers a callback URL with the service, so that it can be used to receive notifications about asynchronous [`recognition jobs`]. The service verifies the URL by sending it a `GET` request with a `challenge_string` query parameter, which it must echo back as plain text within 5 seconds. Registering a URL that is already allowlisted succeeds with [`AlreadyCreated`]
+    ///
+    /// # Parameters
+    ///
+    /// * `callback_url` - An HTTP or HTTPS URL to which callback notifications are to be sent
+    /// * `user_secret` - A secret with which the service signs its verification request and every notification with an `X-Callback-Signature` header. If [`None`] is passed, nothing is signed
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{recognition::CallbackStatus, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let registered = stt
+    ///     .register_callback("https://example.com/results", Some("secret"))
+    ///     .await?;
+    /// if registered.status == CallbackStatus::AlreadyCreated {
+    ///     println!("{} was already registered", registered.url);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`recognition jobs`]: Self::create_recognition_job()
+    /// [`AlreadyCreated`]: self::CallbackStatus::AlreadyCreated
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn register_callback(
+        &self,
+        callback_url: impl AsRef<str>,
+        user_secret: Option<&str>,
+    ) -> Result<RegisterStatus, CallbackError> {
+        let mut url = self.service_url.clone();
+        url.set_path("v1/register_callback");
+        url.query_pairs_mut()
+            .append_pair("callback_url", callback_url.as_ref());
+        if let Some(user_secret) = user_secret {
+            url.query_pairs_mut()
+                .append_pair("user_secret", user_secret);
+        }
+        let req = Request::new(Method::POST, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        let status = match response.status() {
+            StatusCode::CREATED => CallbackStatus::Created,
+            StatusCode::OK => CallbackStatus::AlreadyCreated,
+            status => return Err(callback_error(status)),
+        };
+        let mut registered: RegisterStatus = response
+            .json()
+            .await
+            .map_err(|e| CallbackError::DeserializationError(e.to_string()))?;
+        // the status code is what tells the two apart, whatever the body says
+        registered.status = status;
+        Ok(registered)
+    }
+
+    /// Unregisters a callback URL that was registered with [`register_callback()`], so that it can no longer be used with asynchronous recognition jobs
+    ///
+    /// # Parameters
+    ///
+    /// * `callback_url` - The callback URL that is to be unregistered
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// stt.unregister_callback("https://example.com/results").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`register_callback()`]: Self::register_callback()
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn unregister_callback(
+        &self,
+        callback_url: impl AsRef<str>,
+    ) -> Result<(), CallbackError> {
+        let mut url = self.service_url.clone();
+        url.set_path("v1/unregister_callback");
+        url.query_pairs_mut()
+            .append_pair("callback_url", callback_url.as_ref());
+        let req = Request::new(Method::POST, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => Ok(()),
+            status => Err(callback_error(status)),
+        }
+    }
+}
+
+/// The error for an unsuccessful response when registering or unregistering a callback URL
+pub(crate) fn callback_error(status: StatusCode) -> CallbackError {
+    match status {
+        StatusCode::BAD_REQUEST => CallbackError::BadRequest400,
+        StatusCode::NOT_FOUND => CallbackError::NotFound404,
+        _ => CommonError::from_status(status).into(),
+    }
+}
+
+/// Checks that a callback notification was sent by the service, by recomputing the signature of
+/// its body with the `user_secret` that the callback URL was [`registered`] with. The comparison
+/// takes the same time wherever the signatures differ, so it does not leak the expected one
+///
+/// # Parameters
+///
+/// * `secret` - The `user_secret` that the callback URL was registered with
+/// * `body` - The raw body of the notification, exactly as it was received
+/// * `signature_header` - The value of the `X-Callback-Signature` header of the notification, a base64-encoded HMAC-SHA1 of `body`
+///
+/// # Returns
+///
+/// `true` only if the signature is valid. A header that is not valid base64 is rejected
+///
+/// # Example
+/// ```
+/// # use ibm_watson::stt::verify_callback_signature;
+/// let body = br#"{"id":"job-id","event":"recognitions.started","user_token":"token"}"#;
+/// // the value of the X-Callback-Signature header
+/// let signature = "HsC3eei0mo+t35c7NI0GBS7cHX4=";
+/// assert!(verify_callback_signature(b"secret", body, signature));
+/// assert!(!verify_callback_signature(b"another secret", body, signature));
+/// ```
+///
+/// [`registered`]: crate::stt::SpeechToText::register_callback()
+pub fn verify_callback_signature(secret: &[u8], body: &[u8], signature_header: &str) -> bool {
+    let signature = match STANDARD.decode(signature_header.trim()) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    mac.verify_slice(&signature).is_ok()
+}
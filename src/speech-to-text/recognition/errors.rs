@@ -0,0 +1,107 @@
+use thiserror::Error;
+
+use crate::error::impl_from_common_error;
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned when recognising audio
+pub enum RecognizeError {
+    #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported")]
+    /// A required input parameter is null or a specified input parameter or header value is invalid or not supported. For example, the audio does not match the specified content type
+    BadRequest400,
+    #[error("The request parameters are invalid: {0}")]
+    /// The request was not sent because its parameters would be rejected by the service
+    InvalidParameter(String),
+    #[error("The specified model {0} was not found")]
+    /// The specified language model, custom language model, or custom acoustic model was not found
+    NotFound404(String),
+    #[error("The request specified an unacceptable media type.")]
+    /// The request specified an unacceptable media type with the Content-Type header
+    UnsupportedMediaType415,
+    #[error("The service experienced an internal error.")]
+    /// The service experienced an internal error
+    InternalServerError500,
+    #[error("The service is currently unavailable.")]
+    /// The service is currently unavailable
+    ServiceUnavailable503,
+    /// There was an error reading the file
+    #[error("There was an error reading the file: {0}")]
+    FileReadError(String),
+    /// There was an error in the WebSocket connection, or the service reported an error over it
+    #[error("There was an error in the WebSocket connection: {0}")]
+    WebSocketError(String),
+    /// There was an error making the request
+    #[error("There was an error establishing the connection")]
+    ConnectionError(#[from] reqwest::Error),
+    /// The request did not complete within the timeout that was given for it
+    #[error("The request timed out after {0:?}")]
+    Timeout(std::time::Duration),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
+    /// The response code the server returnes
+    #[error("{0}")]
+    UnmappedResponse(u16),
+}
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned when working with asynchronous recognition jobs
+pub enum RecognitionJobError {
+    #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported")]
+    /// A required input parameter is null or a specified input parameter or header value is invalid or not supported. For example, the callback URL has not been allowlisted
+    BadRequest400,
+    #[error("The request parameters are invalid: {0}")]
+    /// The request was not sent because its parameters would be rejected by the service
+    InvalidParameter(String),
+    #[error("The specified job {0} was not found")]
+    /// The specified job ID is invalid
+    NotFound404(String),
+    #[error("The request specified an unacceptable media type.")]
+    /// The request specified an unacceptable media type with the Content-Type header
+    UnsupportedMediaType415,
+    #[error("The service experienced an internal error.")]
+    /// The service experienced an internal error
+    InternalServerError500,
+    #[error("The service is currently unavailable.")]
+    /// The service is currently unavailable
+    ServiceUnavailable503,
+    /// There was an error making the request
+    #[error("There was an error establishing the connection")]
+    ConnectionError(#[from] reqwest::Error),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
+    /// The response code the server returnes
+    #[error("{0}")]
+    UnmappedResponse(u16),
+}
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned when registering or unregistering a callback URL
+pub enum CallbackError {
+    #[error("The callback URL could not be registered or unregistered, for example because it did not echo the challenge string")]
+    /// The request failed. For example, the URL is not a valid HTTP or HTTPS URL, or it did not echo the `challenge_string` that the service sent to verify it
+    BadRequest400,
+    #[error("The callback URL is not registered")]
+    /// The callback URL that was to be unregistered is not registered
+    NotFound404,
+    #[error("The service experienced an internal error.")]
+    /// The service experienced an internal error
+    InternalServerError500,
+    #[error("The service is currently unavailable.")]
+    /// The service is currently unavailable
+    ServiceUnavailable503,
+    /// There was an error making the request
+    #[error("There was an error establishing the connection")]
+    ConnectionError(#[from] reqwest::Error),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
+    /// The response code the server returned
+    #[error("{0}")]
+    UnmappedResponse(u16),
+}
+
+impl_from_common_error!(RecognizeError, RecognitionJobError, CallbackError);
@@ -0,0 +1,318 @@
+use reqwest::{header::CONTENT_TYPE, Body, Method, Request, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    errors::{RecognitionJobError, RecognizeError},
+    RecognitionResults, RecognizeParams,
+};
+use crate::{error::CommonError, stt::SpeechToText};
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// Information about an asynchronous recognition job
+pub struct RecognitionJob {
+    /// The ID of the asynchronous job
+    #[serde(rename = "id")]
+    pub id: String,
+    /// The current [`status`] of the job
+    ///
+    /// [`status`]: self::JobStatus
+    #[serde(rename = "status")]
+    pub status: JobStatus,
+    /// The date and time in Coordinated Universal Time (UTC) at which the job was created. The value is provided in full ISO 8601 format (YYYY-MM-DDThh:mm:ss.sTZD)
+    #[serde(rename = "created")]
+    pub created: String,
+    /// The date and time in Coordinated Universal Time (UTC) at which the job was last updated by the service
+    #[serde(rename = "updated", skip_serializing_if = "Option::is_none")]
+    pub updated: Option<String>,
+    /// The URL to use to request information about the job with [`check_recognition_job()`]. This field is returned only when the job is created
+    ///
+    /// [`check_recognition_job()`]: crate::stt::SpeechToText::check_recognition_job()
+    #[serde(rename = "url", skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user token associated with the job, if one was specified when the job was created
+    #[serde(rename = "user_token", skip_serializing_if = "Option::is_none")]
+    pub user_token: Option<String>,
+    /// If the status is [`Completed`], the results of the recognition request. This field is returned only by [`check_recognition_job()`]
+    ///
+    /// [`Completed`]: self::JobStatus::Completed
+    /// [`check_recognition_job()`]: crate::stt::SpeechToText::check_recognition_job()
+    #[serde(rename = "results", skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<RecognitionResults>>,
+    /// An array of warning messages about invalid parameters included with the request
+    #[serde(rename = "warnings", skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<String>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+/// The status of an asynchronous recognition job
+pub enum JobStatus {
+    #[default]
+    /// The service is preparing the job for processing
+    Waiting,
+    /// The service is actively processing the job
+    Processing,
+    /// The service has finished processing the job. The results are available with [`check_recognition_job()`] if the job was not created with the [`CompletedWithResults`] event
+    ///
+    /// [`check_recognition_job()`]: crate::stt::SpeechToText::check_recognition_job()
+    /// [`CompletedWithResults`]: self::JobEvent::CompletedWithResults
+    Completed,
+    /// The job failed
+    Failed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The events for which the service sends callback notifications for a job
+pub enum JobEvent {
+    /// The service has begun to process the job
+    Started,
+    /// The job is complete. Use [`check_recognition_job()`] to retrieve the results
+    ///
+    /// [`check_recognition_job()`]: crate::stt::SpeechToText::check_recognition_job()
+    Completed,
+    /// The job is complete, and the notification includes the results
+    CompletedWithResults,
+    /// The job failed
+    Failed,
+}
+
+impl JobEvent {
+    /// The value that the server expects for a particular event
+    pub fn id(&self) -> &str {
+        match self {
+            JobEvent::Started => "recognitions.started",
+            JobEvent::Completed => "recognitions.completed",
+            JobEvent::CompletedWithResults => "recognitions.completed_with_results",
+            JobEvent::Failed => "recognitions.failed",
+        }
+    }
+}
+
+impl SpeechToText {
+    /// Creates a job for a new asynchronous recognition request. The job is owned by the instance of the service whose credentials are used to create it. Use this for audio that is too large or too slow to transcribe with [`recognize()`]
+    ///
+    /// You can either pass a callback URL to be notified when the job changes status, or poll the service with [`check_recognition_job()`]. Results are available for 7 days after the job completes
+    ///
+    /// # Parameters
+    ///
+    /// * `audio` - The audio to transcribe
+    /// * `content_type` - The format (MIME type) of the audio. For example, `audio/wav`, `audio/flac` or `audio/l16;rate=16000`
+    /// * `params` - The [`parameters`] to send with the request
+    /// * `callback_url` - A URL to which callback notifications are to be sent. The URL must already be successfully allowlisted with the service. If [`None`] is passed, poll the job for its status instead
+    /// * `events` - The [`events`] for which notifications are sent to `callback_url`. If it is empty, the service sends notifications for all events except [`CompletedWithResults`]. Omit it if there is no `callback_url`
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{recognition::{JobEvent, RecognizeParams}, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let audio = std::fs::read("/home/user/audio.flac")?;
+    /// let job = stt
+    ///     .create_recognition_job(
+    ///         audio,
+    ///         "audio/flac",
+    ///         &RecognizeParams::new(),
+    ///         Some("https://example.com/results"),
+    ///         &[JobEvent::CompletedWithResults, JobEvent::Failed],
+    ///     )
+    ///     .await?;
+    /// println!("created job {}", job.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`recognize()`]: Self::recognize()
+    /// [`check_recognition_job()`]: Self::check_recognition_job()
+    /// [`parameters`]: self::RecognizeParams
+    /// [`events`]: self::JobEvent
+    /// [`CompletedWithResults`]: self::JobEvent::CompletedWithResults
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn create_recognition_job(
+        &self,
+        audio: impl Into<Body>,
+        content_type: &str,
+        params: &RecognizeParams,
+        callback_url: Option<&str>,
+        events: &[JobEvent],
+    ) -> Result<RecognitionJob, RecognitionJobError> {
+        if let Err(RecognizeError::InvalidParameter(reason)) = params.validate() {
+            return Err(RecognitionJobError::InvalidParameter(reason));
+        }
+        let mut url = self.service_url.clone();
+        url.set_path("v1/recognitions");
+        params.append_query(&mut url);
+        if let Some(callback_url) = callback_url {
+            url.query_pairs_mut()
+                .append_pair("callback_url", callback_url);
+        }
+        if !events.is_empty() {
+            let events: Vec<_> = events.iter().map(JobEvent::id).collect();
+            url.query_pairs_mut()
+                .append_pair("events", &events.join(","));
+        }
+        let client = self.get_client();
+        let request = client
+            .post(url)
+            .header(CONTENT_TYPE, content_type)
+            .body(audio);
+        let response = client.send(request).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::CREATED => {
+                let root: RecognitionJob = response
+                    .json()
+                    .await
+                    .map_err(|e| RecognitionJobError::DeserializationError(e.to_string()))?;
+                Ok(root)
+            }
+            StatusCode::BAD_REQUEST => Err(RecognitionJobError::BadRequest400),
+            StatusCode::UNSUPPORTED_MEDIA_TYPE => Err(RecognitionJobError::UnsupportedMediaType415),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Returns the ID and status of the latest 100 outstanding jobs associated with the credentials with which it is called. The method also returns the creation and update times of each job, and, if a job was created with a callback URL and a user token, the user token for the job. To obtain the results for a job whose status is [`Completed`] or not one of the latest 100 outstanding jobs, use [`check_recognition_job()`]
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let jobs = stt.check_recognition_jobs().await?;
+    /// println!("found: {:#?} jobs", jobs.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`Completed`]: self::JobStatus::Completed
+    /// [`check_recognition_job()`]: Self::check_recognition_job()
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn check_recognition_jobs(&self) -> Result<Vec<RecognitionJob>, RecognitionJobError> {
+        let mut url = self.service_url.clone();
+        url.set_path("v1/recognitions");
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                #[derive(Deserialize)]
+                struct Root {
+                    recognitions: Vec<RecognitionJob>,
+                }
+                let root: Root = response
+                    .json()
+                    .await
+                    .map_err(|e| RecognitionJobError::DeserializationError(e.to_string()))?;
+                Ok(root.recognitions)
+            }
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Returns information about the specified job. The response always includes the status of the job and its creation and update times. If the status is [`Completed`], the response includes the results of the recognition request
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The identifier of the asynchronous job that is to be used for the request
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let job = stt.check_recognition_job("job-id").await?;
+    /// println!("{:#?}", job.results);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`Completed`]: self::JobStatus::Completed
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn check_recognition_job(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<RecognitionJob, RecognitionJobError> {
+        let mut url = self.service_url.clone();
+        Self::set_recognition_job_path(&mut url, &id);
+        let req = Request::new(Method::GET, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK => {
+                let root: RecognitionJob = response
+                    .json()
+                    .await
+                    .map_err(|e| RecognitionJobError::DeserializationError(e.to_string()))?;
+                Ok(root)
+            }
+            StatusCode::NOT_FOUND => Err(RecognitionJobError::NotFound404(id.as_ref().to_owned())),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Deletes the specified job. You cannot delete a job that the service is actively processing. Once you delete a job, its results are no longer available
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The identifier of the asynchronous job that is to be used for the request
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// if stt.delete_recognition_job("job-id").await.is_ok() {
+    ///     println!("job deleted");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn delete_recognition_job(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<(), RecognitionJobError> {
+        let mut url = self.service_url.clone();
+        Self::set_recognition_job_path(&mut url, &id);
+        let req = Request::new(Method::DELETE, url);
+
+        let client = self.get_client();
+        let response = client.execute(req).await?;
+        match response.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(()),
+            StatusCode::NOT_FOUND => Err(RecognitionJobError::NotFound404(id.as_ref().to_owned())),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    fn set_recognition_job_path(uri: &mut Url, id: impl AsRef<str>) {
+        uri.set_path(&format!("v1/recognitions/{}", id.as_ref()));
+    }
+}
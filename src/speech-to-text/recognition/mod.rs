@@ -0,0 +1,550 @@
+mod callbacks;
+/// Errors that may be returned in speech recognition requests
+pub mod errors;
+mod jobs;
+mod params;
+mod websocket;
+
+use std::{collections::HashMap, path::Path, time::Duration};
+
+use bytes::{Bytes, BytesMut};
+use futures_util::{stream, Stream};
+use reqwest::{header::CONTENT_TYPE, Body, StatusCode};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt, BufReader};
+
+use self::errors::RecognizeError;
+pub use self::{
+    callbacks::{verify_callback_signature, CallbackStatus, RegisterStatus},
+    jobs::{JobEvent, JobStatus, RecognitionJob},
+    params::RecognizeParams,
+    websocket::RecognitionStream,
+};
+use crate::error::CommonError;
+
+use super::{models::ModelID, SpeechToText};
+
+/// How many bytes are read from a reader at a time when its audio is streamed to the service
+const READ_CHUNK_SIZE: usize = 16 * 1024;
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// The complete results for a speech recognition request
+pub struct RecognitionResults {
+    /// An index that indicates a change point in the results array. The service increments the index for additional results that it sends for new audio for the same request
+    #[serde(rename = "result_index", default)]
+    pub result_index: i64,
+    /// The results array consists of zero or more final results followed by zero or one interim result
+    #[serde(rename = "results", default)]
+    pub results: Vec<SpeechRecognitionResult>,
+    /// An array of warning messages associated with the request
+    #[serde(rename = "warnings", skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<String>>,
+    /// If [`speaker_labels`] was requested, the speakers that the service identified in the audio
+    ///
+    /// [`speaker_labels`]: self::RecognizeParams::speaker_labels()
+    #[serde(rename = "speaker_labels", skip_serializing_if = "Option::is_none")]
+    pub speaker_labels: Option<Vec<SpeakerLabel>>,
+    /// If [`audio_metrics`] was requested, the signal characteristics of the input audio
+    ///
+    /// [`audio_metrics`]: self::RecognizeParams::audio_metrics()
+    #[serde(rename = "audio_metrics", skip_serializing_if = "Option::is_none")]
+    pub audio_metrics: Option<Box<AudioMetrics>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// The signal characteristics of the input audio
+pub struct AudioMetrics {
+    /// The interval in seconds (typically 0.1 seconds) at which the service calculated the audio metrics
+    #[serde(rename = "sampling_interval")]
+    pub sampling_interval: f64,
+    /// The metrics for all of the audio that the service has processed so far
+    #[serde(rename = "accumulated")]
+    pub accumulated: AudioMetricsDetails,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// Detailed signal characteristics of the input audio
+pub struct AudioMetricsDetails {
+    /// If true, these are the final metrics for the audio. If false, they are preliminary and may change as more audio is processed
+    #[serde(rename = "final")]
+    pub final_metrics: bool,
+    /// The end time in seconds of the block of audio to which the metrics apply
+    #[serde(rename = "end_time")]
+    pub end_time: f64,
+    /// The signal-to-noise ratio (SNR) for the audio signal. The value indicates the ratio of speech to noise in the audio. A valid value lies in the range of 0 to 100 decibels (dB). The service omits the field if it cannot compute the SNR for the audio
+    #[serde(
+        rename = "signal_to_noise_ratio",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub signal_to_noise_ratio: Option<f64>,
+    /// The ratio of speech to non-speech segments in the audio signal, in the range of 0.0 to 1.0
+    #[serde(rename = "speech_ratio")]
+    pub speech_ratio: f64,
+    /// The probability that the audio signal is missing the upper half of its frequency content, in the range of 0.0 to 1.0. A high value usually means that narrowband audio was upsampled
+    #[serde(rename = "high_frequency_loss")]
+    pub high_frequency_loss: f64,
+    /// A histogram of the cumulative direct current (DC) component of the audio signal
+    #[serde(rename = "direct_current_offset", default)]
+    pub direct_current_offset: Vec<AudioMetricsHistogramBin>,
+    /// A histogram of the clipping rate for the audio segments. The clipping rate is the fraction of samples in the segment that reach the maximum or minimum value that is offered by the audio quantisation range
+    #[serde(rename = "clipping_rate", default)]
+    pub clipping_rate: Vec<AudioMetricsHistogramBin>,
+    /// A histogram of the signal level in segments of the audio that contain speech
+    #[serde(rename = "speech_level", default)]
+    pub speech_level: Vec<AudioMetricsHistogramBin>,
+    /// A histogram of the signal level in segments of the audio that do not contain speech
+    #[serde(rename = "non_speech_level", default)]
+    pub non_speech_level: Vec<AudioMetricsHistogramBin>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// A bin of a histogram of audio metrics
+pub struct AudioMetricsHistogramBin {
+    /// The lower boundary of the bin
+    #[serde(rename = "begin")]
+    pub begin: f64,
+    /// The upper boundary of the bin
+    #[serde(rename = "end")]
+    pub end: f64,
+    /// The number of values in the bin
+    #[serde(rename = "count")]
+    pub count: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// Identifies which participant spoke a word in a multi-person exchange
+pub struct SpeakerLabel {
+    /// The start time of a word from the transcript in seconds. The value matches the start time of the word's timestamp
+    #[serde(rename = "from")]
+    pub from: f64,
+    /// The end time of a word from the transcript in seconds. The value matches the end time of the word's timestamp
+    #[serde(rename = "to")]
+    pub to: f64,
+    /// The numeric identifier that the service assigns to a speaker from the audio. Speaker IDs begin at 0 initially but can evolve and change across interim results and between interim and final results as the service processes the audio
+    #[serde(rename = "speaker")]
+    pub speaker: i64,
+    /// A score that indicates the service's confidence in its identification of the speaker in the range of 0.0 to 1.0
+    #[serde(rename = "confidence")]
+    pub confidence: f64,
+    /// If true, the service is not expected to update the speaker label any further. If false, the label is interim and can change
+    #[serde(rename = "final")]
+    pub final_results: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// A single result of a speech recognition request
+pub struct SpeechRecognitionResult {
+    /// If true, the result for this utterance is not updated further. If false, the result is interim and can change
+    #[serde(rename = "final")]
+    pub final_results: bool,
+    /// An array of alternative transcripts. The alternatives array can include additional requested output such as word confidence or timestamps
+    #[serde(rename = "alternatives")]
+    pub alternatives: Vec<SpeechRecognitionAlternative>,
+    /// A dictionary (or associative array) whose keys are the strings specified for [`keywords`] if both that parameter and [`keywords_threshold`] are specified. The value for each key is an array of matches spotted in the audio for that keyword
+    ///
+    /// [`keywords`]: self::RecognizeParams::keywords()
+    /// [`keywords_threshold`]: self::RecognizeParams::keywords_threshold()
+    #[serde(rename = "keywords_result", skip_serializing_if = "Option::is_none")]
+    pub keywords_result: Option<HashMap<String, Vec<KeywordResult>>>,
+    /// If [`word_alternatives_threshold`] was requested, the alternative hypotheses that the service found for the words of the audio
+    ///
+    /// [`word_alternatives_threshold`]: self::RecognizeParams::word_alternatives_threshold()
+    #[serde(rename = "word_alternatives", skip_serializing_if = "Option::is_none")]
+    pub word_alternatives: Option<Vec<WordAlternativeResults>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// The alternative hypotheses for a span of the audio
+pub struct WordAlternativeResults {
+    /// The start time in seconds of the word from the input audio that corresponds to the word alternatives
+    #[serde(rename = "start_time")]
+    pub start_time: f64,
+    /// The end time in seconds of the word from the input audio that corresponds to the word alternatives
+    #[serde(rename = "end_time")]
+    pub end_time: f64,
+    /// The alternative hypotheses for the word, each with a confidence score
+    #[serde(rename = "alternatives")]
+    pub alternatives: Vec<WordAlternativeResult>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// An alternative hypothesis for a word
+pub struct WordAlternativeResult {
+    /// A confidence score for the word alternative hypothesis in the range of 0.0 to 1.0
+    #[serde(rename = "confidence")]
+    pub confidence: f64,
+    /// An alternative hypothesis for a word from the input audio
+    #[serde(rename = "word")]
+    pub word: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// Information about a match for a keyword from the speech recognition request
+pub struct KeywordResult {
+    /// A specified keyword normalised to the spoken phrase that matched in the audio input
+    #[serde(rename = "normalized_text")]
+    pub normalised_text: String,
+    /// The start time in seconds of the keyword match
+    #[serde(rename = "start_time")]
+    pub start_time: f64,
+    /// The end time in seconds of the keyword match
+    #[serde(rename = "end_time")]
+    pub end_time: f64,
+    /// A confidence score for the keyword match in the range of 0.0 to 1.0
+    #[serde(rename = "confidence")]
+    pub confidence: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+/// An alternative transcript of the recognised audio
+pub struct SpeechRecognitionAlternative {
+    /// A transcription of the audio
+    #[serde(rename = "transcript")]
+    pub transcript: String,
+    /// A score that indicates the service's confidence in the transcript in the range of 0.0 to 1.0. The field is returned only for the best alternative and only with results marked as final
+    #[serde(rename = "confidence", skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    /// If [`timestamps`] was requested, the time at which each word of the transcript is spoken
+    ///
+    /// [`timestamps`]: self::RecognizeParams::timestamps()
+    #[serde(rename = "timestamps", skip_serializing_if = "Option::is_none")]
+    pub timestamps: Option<Vec<WordTimestamp>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(from = "(String, f64, f64)", into = "(String, f64, f64)")]
+/// The time at which a word is spoken in the recognised audio. The service sends it as a `[word, start, end]` array
+pub struct WordTimestamp {
+    /// The word from the transcript
+    pub word: String,
+    /// The time in seconds at which the word starts in the audio
+    pub start: f64,
+    /// The time in seconds at which the word ends in the audio
+    pub end: f64,
+}
+
+impl From<(String, f64, f64)> for WordTimestamp {
+    fn from((word, start, end): (String, f64, f64)) -> Self {
+        Self { word, start, end }
+    }
+}
+
+impl From<WordTimestamp> for (String, f64, f64) {
+    fn from(timestamp: WordTimestamp) -> Self {
+        (timestamp.word, timestamp.start, timestamp.end)
+    }
+}
+
+impl SpeechToText {
+    /// Sends audio and returns transcription results for a recognition request. You can pass a maximum of 100 MB and a minimum of 100 bytes of audio with a request. The service automatically detects the endianness of the incoming audio and, for audio that includes multiple channels, downmixes the audio to one-channel mono during transcoding
+    ///
+    /// # Parameters
+    ///
+    /// * `audio` - The audio to transcribe
+    /// * `content_type` - The format (MIME type) of the audio. For example, `audio/wav`, `audio/flac` or `audio/l16;rate=16000`. The `audio/l16` format requires that you also specify the sampling rate
+    /// * `model` - The [`model`] to use for speech recognition. If [`None`] is passed, the service uses `en-US_BroadbandModel`
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{models::ModelID, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let audio = std::fs::read("/home/user/audio.wav")?;
+    /// let results = stt.recognize(audio, "audio/wav", Some(ModelID::EnGbBroadband)).await?;
+    /// println!("{:#?}", results);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`model`]: crate::stt::models::ModelID
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn recognize(
+        &self,
+        audio: impl Into<Body>,
+        content_type: &str,
+        model: Option<ModelID>,
+    ) -> Result<RecognitionResults, RecognizeError> {
+        let params = match model {
+            Some(model) => RecognizeParams::new().model(model),
+            None => RecognizeParams::new(),
+        };
+        self.recognize_with_params(audio, content_type, &params)
+            .await
+    }
+
+    /// Sends audio for recognition with additional [`parameters`] that control the output, such as [`speaker labels`]. See [`recognize()`] for more details
+    ///
+    /// # Parameters
+    ///
+    /// * `audio` - The audio to transcribe
+    /// * `content_type` - The format (MIME type) of the audio. For example, `audio/wav`, `audio/flac` or `audio/l16;rate=16000`. The `audio/l16` format requires that you also specify the sampling rate
+    /// * `params` - The [`parameters`] to send with the request
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{models::ModelID, recognition::RecognizeParams, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let audio = std::fs::read("/home/user/audio.wav")?;
+    /// let params = RecognizeParams::new()
+    ///     .model(ModelID::EnUsTelephony)
+    ///     .speaker_labels(true);
+    /// let results = stt.recognize_with_params(audio, "audio/wav", &params).await?;
+    /// println!("{:#?}", results.speaker_labels);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`parameters`]: self::RecognizeParams
+    /// [`speaker labels`]: self::RecognizeParams::speaker_labels()
+    /// [`recognize()`]: Self::recognize()
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn recognize_with_params(
+        &self,
+        audio: impl Into<Body>,
+        content_type: &str,
+        params: &RecognizeParams,
+    ) -> Result<RecognitionResults, RecognizeError> {
+        self.recognition_response(audio, content_type, params, None)
+            .await
+    }
+
+    /// Sends audio for recognition like [`recognize_with_params()`], but gives up if the request has not completed within `timeout`, including reading the results. This overrides any timeout that the client was built with, so that a long recording can be given more time than other requests
+    ///
+    /// # Parameters
+    ///
+    /// * `audio` - The audio to transcribe
+    /// * `content_type` - The format (MIME type) of the audio. For example, `audio/wav`, `audio/flac` or `audio/l16;rate=16000`. The `audio/l16` format requires that you also specify the sampling rate
+    /// * `params` - The [`parameters`] to send with the request
+    /// * `timeout` - How long to wait for the request to complete. If it elapses, [`Timeout`] is returned rather than [`ConnectionError`]
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use std::time::Duration;
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{recognition::RecognizeParams, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let audio = std::fs::read("/home/user/audio.wav")?;
+    /// let params = RecognizeParams::new();
+    /// let results = stt
+    ///     .recognize_with_timeout(audio, "audio/wav", &params, Duration::from_secs(300))
+    ///     .await?;
+    /// println!("{:#?}", results);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`recognize_with_params()`]: Self::recognize_with_params()
+    /// [`parameters`]: self::RecognizeParams
+    /// [`Timeout`]: self::errors::RecognizeError::Timeout
+    /// [`ConnectionError`]: self::errors::RecognizeError::ConnectionError
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn recognize_with_timeout(
+        &self,
+        audio: impl Into<Body>,
+        content_type: &str,
+        params: &RecognizeParams,
+        timeout: Duration,
+    ) -> Result<RecognitionResults, RecognizeError> {
+        self.recognition_response(audio, content_type, params, Some(timeout))
+            .await
+    }
+
+    async fn recognition_response(
+        &self,
+        audio: impl Into<Body>,
+        content_type: &str,
+        params: &RecognizeParams,
+        timeout: Option<Duration>,
+    ) -> Result<RecognitionResults, RecognizeError> {
+        params.validate()?;
+        let mut url = self.service_url.clone();
+        url.set_path("v1/recognize");
+        params.append_query(&mut url);
+        let client = self.get_client();
+        let mut request = client
+            .post(url)
+            .header(CONTENT_TYPE, content_type)
+            .body(audio);
+        if let Some(timeout) = timeout {
+            request = request.timeout(timeout);
+        }
+        let timed_out = |e: &reqwest::Error| match timeout {
+            Some(timeout) if e.is_timeout() => Some(RecognizeError::Timeout(timeout)),
+            _ => None,
+        };
+        let response = client
+            .send(request)
+            .await
+            .map_err(|e| timed_out(&e).unwrap_or(RecognizeError::ConnectionError(e)))?;
+        match response.status() {
+            StatusCode::OK => {
+                let root: RecognitionResults = response.json().await.map_err(|e| {
+                    timed_out(&e)
+                        .unwrap_or_else(|| RecognizeError::DeserializationError(e.to_string()))
+                })?;
+                Ok(root)
+            }
+            status => Err(recognize_error(status, params.get_model())),
+        }
+    }
+
+    /// Reads an audio file and sends it for recognition. The content type of the audio is inferred from the file extension. See [`recognize()`] for more details
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - The path of the audio file to transcribe. Files ending in `.wav`, `.flac`, `.ogg` and `.mp3` are sent with their respective content type. Any other file is sent as `application/octet-stream`, in which case the service attempts to detect the format itself
+    /// * `model` - The [`model`] to use for speech recognition. If [`None`] is passed, the service uses `en-US_BroadbandModel`
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{models::ModelID, SpeechToText},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let file_path = std::path::Path::new("/home/user/audio.flac");
+    /// let results = stt.recognize_file(&file_path, None).await?;
+    /// println!("{:#?}", results);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`recognize()`]: Self::recognize()
+    /// [`model`]: crate::stt::models::ModelID
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn recognize_file(
+        &self,
+        path: impl AsRef<Path>,
+        model: Option<ModelID>,
+    ) -> Result<RecognitionResults, RecognizeError> {
+        let path = path.as_ref();
+        let content_type = content_type_from_path(path);
+        let file = tokio::fs::OpenOptions::new()
+            .read(true)
+            .open(path)
+            .await
+            .map_err(|e| RecognizeError::FileReadError(e.to_string()))?;
+
+        let mut buf_reader = BufReader::new(file);
+        let mut buffer = Vec::new();
+        buf_reader
+            .read_to_end(&mut buffer)
+            .await
+            .map_err(|e| RecognizeError::FileReadError(e.to_string()))?;
+
+        self.recognize(buffer, content_type, model).await
+    }
+
+    /// Sends audio for recognition as it is read from `reader`, for example a pipe or a file that is still being written, without buffering the whole input. The audio is uploaded with chunked `Transfer-Encoding` and the request completes once `reader` reaches its end. See [`recognize()`] for more details
+    ///
+    /// Unlike [`recognize_stream()`], which uses a WebSocket, this returns nothing until all the audio has been sent, so there are no interim results. In exchange it is a plain HTTP request: it needs no WebSocket support along the way, such as in proxies, and it returns the same [`RecognitionResults`] as [`recognize()`]
+    ///
+    /// # Parameters
+    ///
+    /// * `reader` - The source of the audio to transcribe
+    /// * `content_type` - The format (MIME type) of the audio. For example, `audio/wav`, `audio/flac` or `audio/l16;rate=16000`. The `audio/l16` format requires that you also specify the sampling rate
+    /// * `model` - The [`model`] to use for speech recognition. If [`None`] is passed, the service uses `en-US_BroadbandModel`
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::SpeechToText,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let recording = tokio::fs::File::open("/home/user/recording.flac").await?;
+    /// let results = stt.recognize_reader(recording, "audio/flac", None).await?;
+    /// println!("{:#?}", results);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`recognize()`]: Self::recognize()
+    /// [`recognize_stream()`]: Self::recognize_stream()
+    /// [`RecognitionResults`]: self::RecognitionResults
+    /// [`model`]: crate::stt::models::ModelID
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn recognize_reader(
+        &self,
+        reader: impl AsyncRead + Send + Sync + 'static,
+        content_type: &str,
+        model: Option<ModelID>,
+    ) -> Result<RecognitionResults, RecognizeError> {
+        let audio = Body::wrap_stream(reader_stream(reader));
+        self.recognize(audio, content_type, model).await
+    }
+}
+
+/// The contents of `reader` as a stream of chunks of up to [`READ_CHUNK_SIZE`] bytes, which ends
+/// after the end of the reader or its first error
+fn reader_stream(
+    reader: impl AsyncRead + Send + Sync + 'static,
+) -> impl Stream<Item = std::io::Result<Bytes>> + Send + Sync + 'static {
+    stream::unfold(Some(Box::pin(reader)), |reader| async move {
+        let mut reader = reader?;
+        let mut chunk = BytesMut::with_capacity(READ_CHUNK_SIZE);
+        match reader.read_buf(&mut chunk).await {
+            Ok(0) => None,
+            Ok(_) => Some((Ok(chunk.freeze()), Some(reader))),
+            Err(e) => Some((Err(e), None)),
+        }
+    })
+}
+
+fn content_type_from_path(path: &Path) -> &'static str {
+    match path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("wav") => "audio/wav",
+        Some("flac") => "audio/flac",
+        Some("ogg") => "audio/ogg",
+        Some("mp3") => "audio/mp3",
+        _ => "application/octet-stream",
+    }
+}
+
+/// The error for an unsuccessful recognition response. `model` is the model that was requested,
+/// if any
+pub(crate) fn recognize_error(status: StatusCode, model: Option<&ModelID>) -> RecognizeError {
+    match status {
+        StatusCode::BAD_REQUEST => RecognizeError::BadRequest400,
+        StatusCode::NOT_FOUND => RecognizeError::NotFound404(match model {
+            Some(model) => model.to_string(),
+            None => String::from("en-US_BroadbandModel"),
+        }),
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => RecognizeError::UnsupportedMediaType415,
+        _ => CommonError::from_status(status).into(),
+    }
+}
@@ -0,0 +1,236 @@
+use reqwest::Url;
+
+use super::errors::RecognizeError;
+use crate::stt::models::ModelID;
+
+#[derive(Clone, Debug)]
+/// Optional parameters that control how the service recognises audio. Parameters that are not
+/// set are omitted from the request so that the service defaults apply
+///
+/// # Example
+/// ```
+/// # use ibm_watson::stt::{models::ModelID, recognition::RecognizeParams};
+/// let params = RecognizeParams::new()
+///     .model(ModelID::EnUsBroadband)
+///     .speaker_labels(true);
+/// ```
+pub struct RecognizeParams {
+    model: Option<ModelID>,
+    language_customisation_id: Option<String>,
+    grammar_name: Option<String>,
+    speaker_labels: bool,
+    timestamps: bool,
+    keywords: Vec<String>,
+    keywords_threshold: Option<f32>,
+    word_alternatives_threshold: Option<f32>,
+    profanity_filter: bool,
+    smart_formatting: bool,
+    inactivity_timeout: Option<i32>,
+    audio_metrics: bool,
+}
+
+impl Default for RecognizeParams {
+    fn default() -> Self {
+        Self {
+            model: None,
+            language_customisation_id: None,
+            grammar_name: None,
+            speaker_labels: false,
+            timestamps: false,
+            keywords: Vec::new(),
+            keywords_threshold: None,
+            word_alternatives_threshold: None,
+            profanity_filter: true,
+            smart_formatting: false,
+            inactivity_timeout: None,
+            audio_metrics: false,
+        }
+    }
+}
+
+impl RecognizeParams {
+    /// Creates a new set of parameters with all the service defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The [`model`] to use for speech recognition. If it is not set, the service uses `en-US_BroadbandModel`
+    ///
+    /// [`model`]: crate::stt::models::ModelID
+    pub fn model(mut self, model: ModelID) -> Self {
+        self.model = Some(model);
+        self
+    }
+
+    /// The customisation ID (GUID) of a custom language model to use for speech recognition. The custom model must be based on the [`model`] that is used for the request, and you must make the request with credentials for the instance of the service that owns it
+    ///
+    /// [`model`]: Self::model()
+    pub fn language_customisation_id(mut self, customisation_id: impl Into<String>) -> Self {
+        self.language_customisation_id = Some(customisation_id.into());
+        self
+    }
+
+    /// The name of a [`grammar`] of the custom language model to restrict recognition to. The service then only recognises the words and phrases that the grammar allows. A grammar requires a [`language_customisation_id()`] as well
+    ///
+    /// [`grammar`]: crate::stt::customisations::Grammar
+    /// [`language_customisation_id()`]: Self::language_customisation_id()
+    pub fn grammar_name(mut self, grammar_name: impl Into<String>) -> Self {
+        self.grammar_name = Some(grammar_name.into());
+        self
+    }
+
+    /// If true, the response includes labels that identify which words were spoken by which participants in a multi-person exchange. Setting this to true forces the service to also return timestamps. Speaker labels are only available with models whose [`speaker_labels`] feature is supported; the service rejects the request otherwise
+    ///
+    /// [`speaker_labels`]: crate::stt::models::SupportedFeatures::speaker_labels
+    pub fn speaker_labels(mut self, speaker_labels: bool) -> Self {
+        self.speaker_labels = speaker_labels;
+        self
+    }
+
+    /// If true, the response includes the start and end [`time`] of each word of the transcript
+    ///
+    /// [`time`]: crate::stt::recognition::SpeechRecognitionAlternative::timestamps
+    pub fn timestamps(mut self, timestamps: bool) -> Self {
+        self.timestamps = timestamps;
+        self
+    }
+
+    /// Keywords to spot in the audio. Each keyword string can include one or more string tokens. Keywords are spotted only in the final results, not in interim hypotheses. You can spot a maximum of 1000 keywords with a single request. If you specify any keywords, you must also specify a [`keywords_threshold()`]
+    ///
+    /// [`keywords_threshold()`]: Self::keywords_threshold()
+    pub fn keywords(mut self, keywords: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.keywords = keywords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// A confidence value that is the lower bound for spotting a keyword. A word is considered to match a keyword if its confidence is greater than or equal to the threshold. Specify a probability between 0.0 and 1.0. If you specify a threshold, you must also specify one or more [`keywords()`]
+    ///
+    /// [`keywords()`]: Self::keywords()
+    pub fn keywords_threshold(mut self, threshold: f32) -> Self {
+        self.keywords_threshold = Some(threshold);
+        self
+    }
+
+    /// A confidence value that is the lower bound for identifying a hypothesis as a possible [`word alternative`] (also known as "Confusion Networks"). An alternative word is considered if its confidence is greater than or equal to the threshold. Specify a probability between 0.0 and 1.0. By default, the service computes no alternative words
+    ///
+    /// [`word alternative`]: crate::stt::recognition::SpeechRecognitionResult::word_alternatives
+    pub fn word_alternatives_threshold(mut self, threshold: f32) -> Self {
+        self.word_alternatives_threshold = Some(threshold);
+        self
+    }
+
+    /// If true, the service filters profanity from all output except for keyword results by replacing inappropriate words with a series of asterisks. Set it to false to return results with no censoring. Defaults to true, like the service
+    pub fn profanity_filter(mut self, profanity_filter: bool) -> Self {
+        self.profanity_filter = profanity_filter;
+        self
+    }
+
+    /// If true, the service converts dates, times, series of digits and numbers, phone numbers, currency values, and internet addresses into more readable, conventional representations in the final transcript. Defaults to false
+    ///
+    /// Smart formatting is only supported for US English, Japanese and Spanish models (and US English for the next-generation models); it has no effect with other models
+    pub fn smart_formatting(mut self, smart_formatting: bool) -> Self {
+        self.smart_formatting = smart_formatting;
+        self
+    }
+
+    /// The time in seconds after which, if only silence (no speech) is detected in streaming audio, the connection is closed with a 400 error. Useful for stopping audio submission from a live microphone when a user simply walks away. Use -1 for infinity. If it is not set, the service uses 30 seconds
+    pub fn inactivity_timeout(mut self, seconds: i32) -> Self {
+        self.inactivity_timeout = Some(seconds);
+        self
+    }
+
+    /// If true, the response includes [`metrics`] about the signal characteristics of the input audio, such as its signal-to-noise ratio and how much of it is clipped. Use them to detect audio that is too poor to transcribe reliably. Defaults to false
+    ///
+    /// [`metrics`]: crate::stt::recognition::AudioMetrics
+    pub fn audio_metrics(mut self, audio_metrics: bool) -> Self {
+        self.audio_metrics = audio_metrics;
+        self
+    }
+
+    /// Checks the parameters for combinations that the service would reject
+    pub(crate) fn validate(&self) -> Result<(), RecognizeError> {
+        match self.keywords_threshold {
+            Some(threshold) if !(0.0..=1.0).contains(&threshold) => {
+                return Err(RecognizeError::InvalidParameter(format!(
+                    "keywords_threshold must be between 0.0 and 1.0, got {threshold}"
+                )))
+            }
+            Some(_) if self.keywords.is_empty() => {
+                return Err(RecognizeError::InvalidParameter(String::from(
+                    "keywords_threshold requires at least one keyword",
+                )))
+            }
+            None if !self.keywords.is_empty() => {
+                return Err(RecognizeError::InvalidParameter(String::from(
+                    "keywords require a keywords_threshold",
+                )))
+            }
+            _ => {}
+        }
+        if let Some(threshold) = self.word_alternatives_threshold {
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(RecognizeError::InvalidParameter(format!(
+                    "word_alternatives_threshold must be between 0.0 and 1.0, got {threshold}"
+                )));
+            }
+        }
+        match self.inactivity_timeout {
+            Some(seconds) if seconds < -1 => {
+                return Err(RecognizeError::InvalidParameter(format!(
+                    "inactivity_timeout must be -1 or at least 0, got {seconds}"
+                )))
+            }
+            _ => {}
+        }
+        if self.grammar_name.is_some() && self.language_customisation_id.is_none() {
+            return Err(RecognizeError::InvalidParameter(String::from(
+                "grammar_name requires a language_customisation_id",
+            )));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn get_model(&self) -> Option<&ModelID> {
+        self.model.as_ref()
+    }
+
+    pub(crate) fn append_query(&self, url: &mut Url) {
+        let mut pairs = url.query_pairs_mut();
+        if let Some(model) = &self.model {
+            pairs.append_pair("model", &model.to_string());
+        }
+        if let Some(customisation_id) = &self.language_customisation_id {
+            pairs.append_pair("language_customization_id", customisation_id);
+        }
+        if let Some(grammar_name) = &self.grammar_name {
+            pairs.append_pair("grammar_name", grammar_name);
+        }
+        if self.speaker_labels {
+            pairs.append_pair("speaker_labels", "true");
+        }
+        if self.timestamps {
+            pairs.append_pair("timestamps", "true");
+        }
+        if !self.keywords.is_empty() {
+            pairs.append_pair("keywords", &self.keywords.join(","));
+        }
+        if let Some(threshold) = self.keywords_threshold {
+            pairs.append_pair("keywords_threshold", &threshold.to_string());
+        }
+        if let Some(threshold) = self.word_alternatives_threshold {
+            pairs.append_pair("word_alternatives_threshold", &threshold.to_string());
+        }
+        if !self.profanity_filter {
+            pairs.append_pair("profanity_filter", "false");
+        }
+        if self.smart_formatting {
+            pairs.append_pair("smart_formatting", "true");
+        }
+        if let Some(seconds) = self.inactivity_timeout {
+            pairs.append_pair("inactivity_timeout", &seconds.to_string());
+        }
+        if self.audio_metrics {
+            pairs.append_pair("audio_metrics", "true");
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures_util::{
+    future::{self, Either},
+    stream, SinkExt, Stream, StreamExt,
+};
+use serde::Deserialize;
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{client::IntoClientRequest, Message},
+    MaybeTlsStream, WebSocketStream,
+};
+
+use super::{errors::RecognizeError, RecognitionResults};
+use crate::stt::{models::ModelID, SpeechToText};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// A stream of interim and final [`RecognitionResults`] received over a WebSocket connection.
+/// The stream ends once the service has sent the final results for all of the audio
+///
+/// [`RecognitionResults`]: super::RecognitionResults
+pub struct RecognitionStream {
+    inner: Pin<Box<dyn Stream<Item = Result<RecognitionResults, RecognizeError>> + Send>>,
+}
+
+impl Stream for RecognitionStream {
+    type Item = Result<RecognitionResults, RecognizeError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ServerMessage {
+    State {
+        #[allow(dead_code)]
+        state: String,
+    },
+    Error {
+        error: String,
+    },
+    Results(RecognitionResults),
+}
+
+struct Session<S> {
+    socket: Socket,
+    audio: Option<Pin<Box<S>>>,
+    listening: u8,
+    done: bool,
+}
+
+impl<S> Session<S>
+where
+    S: Stream<Item = Bytes>,
+{
+    async fn next_result(mut self) -> Option<(Result<RecognitionResults, RecognizeError>, Self)> {
+        if self.done {
+            return None;
+        }
+        loop {
+            let message = match self.audio.as_mut() {
+                Some(audio) => {
+                    let event = match future::select(audio.next(), self.socket.next()).await {
+                        Either::Left((chunk, _)) => Either::Left(chunk),
+                        Either::Right((message, _)) => Either::Right(message),
+                    };
+                    match event {
+                        Either::Left(Some(chunk)) => {
+                            if let Err(e) = self.socket.send(Message::Binary(chunk.to_vec())).await
+                            {
+                                return self.fail(e.to_string());
+                            }
+                            continue;
+                        }
+                        Either::Left(None) => {
+                            // the audio has ended, ask the service for the final results
+                            self.audio = None;
+                            let stop = serde_json::json!({ "action": "stop" }).to_string();
+                            if let Err(e) = self.socket.send(Message::Text(stop)).await {
+                                return self.fail(e.to_string());
+                            }
+                            continue;
+                        }
+                        Either::Right(message) => message,
+                    }
+                }
+                None => self.socket.next().await,
+            };
+
+            match message {
+                Some(Ok(Message::Text(text))) => match serde_json::from_str(&text) {
+                    Ok(ServerMessage::State { .. }) => {
+                        // The service reports that it is listening once after the start message
+                        // and again after all the audio has been transcribed
+                        self.listening += 1;
+                        if self.audio.is_none() && self.listening >= 2 {
+                            let _ = self.socket.close(None).await;
+                            return None;
+                        }
+                    }
+                    Ok(ServerMessage::Error { error }) => return self.fail(error),
+                    Ok(ServerMessage::Results(results)) => return Some((Ok(results), self)),
+                    Err(e) => return self.fail(e.to_string()),
+                },
+                Some(Ok(Message::Close(_))) | None => return None,
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => return self.fail(e.to_string()),
+            }
+        }
+    }
+
+    fn fail(
+        mut self,
+        reason: String,
+    ) -> Option<(Result<RecognitionResults, RecognizeError>, Self)> {
+        self.done = true;
+        Some((Err(RecognizeError::WebSocketError(reason)), self))
+    }
+}
+
+impl SpeechToText {
+    /// Opens a WebSocket connection to the service and streams audio to it, returning interim and final [`RecognitionResults`] as they become available. Use this for live transcription, such as audio captured from a microphone. The connection is closed once `audio` ends and the service has sent the final results
+    ///
+    /// # Parameters
+    ///
+    /// * `audio` - A stream of audio chunks to transcribe
+    /// * `content_type` - The format (MIME type) of the audio. For example, `audio/l16;rate=16000`
+    /// * `model` - The [`model`] to use for speech recognition. If [`None`] is passed, the service uses `en-US_BroadbandModel`
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     stt::{models::ModelID, SpeechToText},
+    /// # };
+    /// # use futures_util::StreamExt;
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let stt = SpeechToText::new(&auth, "service_url");
+    /// let audio = bytes::Bytes::from(std::fs::read("/home/user/audio.raw")?);
+    /// let chunks = futures_util::stream::iter(vec![audio]);
+    /// let mut results = stt
+    ///     .recognize_stream(chunks, "audio/l16;rate=16000", None)
+    ///     .await?;
+    /// while let Some(result) = results.next().await {
+    ///     println!("{:#?}", result?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`RecognitionResults`]: super::RecognitionResults
+    /// [`model`]: crate::stt::models::ModelID
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "speech_to_text"), err)
+    )]
+    pub async fn recognize_stream<S>(
+        &self,
+        audio: S,
+        content_type: &str,
+        model: Option<ModelID>,
+    ) -> Result<RecognitionStream, RecognizeError>
+    where
+        S: Stream<Item = Bytes> + Send + 'static,
+    {
+        let mut url = self.service_url.clone();
+        url.set_path("v1/recognize");
+        let scheme = match url.scheme() {
+            "http" => "ws",
+            _ => "wss",
+        };
+        url.set_scheme(scheme)
+            .map_err(|_| RecognizeError::WebSocketError(format!("invalid url: {url}")))?;
+        if let Some(model) = &model {
+            url.query_pairs_mut()
+                .append_pair("model", &model.to_string());
+        }
+        let mut request = url
+            .as_str()
+            .into_client_request()
+            .map_err(|e| RecognizeError::WebSocketError(e.to_string()))?;
+        request.headers_mut().extend(self.client.headers().clone());
+
+        let (mut socket, _) = connect_async(request)
+            .await
+            .map_err(|e| RecognizeError::WebSocketError(e.to_string()))?;
+
+        let start = serde_json::json!({
+            "action": "start",
+            "content-type": content_type,
+            "interim_results": true,
+        });
+        socket
+            .send(Message::Text(start.to_string()))
+            .await
+            .map_err(|e| RecognizeError::WebSocketError(e.to_string()))?;
+
+        let session = Session {
+            socket,
+            audio: Some(Box::pin(audio)),
+            listening: 0,
+            done: false,
+        };
+        let inner = stream::unfold(session, Session::next_result);
+        Ok(RecognitionStream {
+            inner: Box::pin(inner),
+        })
+    }
+}
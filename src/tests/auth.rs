@@ -21,3 +21,84 @@ async fn http1_iam_key() {
     let c = client.execute(req).await.unwrap();
     assert_eq!(c.status(), 400);
 }
+
+#[tokio::test]
+async fn iam_key_through_proxy() {
+    use crate::auth::IamAuthenticator;
+
+    use super::one_shot_server;
+
+    let (proxy, request) = one_shot_server("").await;
+    let proxy = reqwest::Proxy::all(proxy).unwrap();
+    // The proxy closes the tunnel straight away, so only the request it received is of interest
+    assert!(IamAuthenticator::with_proxy("api_key", proxy)
+        .await
+        .is_err());
+    let request = request.await.unwrap();
+    assert!(request.starts_with("CONNECT iam.cloud.ibm.com:443 "));
+}
+
+#[cfg(feature = "blocking")]
+#[tokio::test]
+async fn blocking_iam_key_through_proxy() {
+    use crate::auth::IamAuthenticator;
+
+    use super::one_shot_server;
+
+    let (proxy, request) = one_shot_server("").await;
+    let proxy = reqwest::Proxy::all(proxy).unwrap();
+    let auth = tokio::task::spawn_blocking(move || {
+        IamAuthenticator::with_proxy_blocking("api_key", proxy)
+    })
+    .await
+    .unwrap();
+    assert!(auth.is_err());
+    let request = request.await.unwrap();
+    assert!(request.starts_with("CONNECT iam.cloud.ibm.com:443 "));
+}
+
+#[test]
+fn debug_is_redacted() {
+    use crate::auth::IamAuthenticator;
+
+    let auth: IamAuthenticator = serde_json::from_str(
+        r#"{
+            "access_token": {
+                "access_token": "eyJraWQiOiJzZWNyZXQifQ",
+                "refresh_token": "not-so-secret-refresh",
+                "token_type": "Bearer",
+                "expires_in": 3600,
+                "expiration": 1700000000
+            }
+        }"#,
+    )
+    .unwrap();
+    let debug = format!("{auth:?}");
+    assert_eq!(debug, "IamAuthenticator { access_token: <redacted> }");
+    #[cfg(any(feature = "tts", feature = "stt", feature = "lt"))]
+    {
+        let debug = format!("{:#?}", auth.token_response());
+        assert!(!debug.contains("eyJraWQiOiJzZWNyZXQifQ"));
+        assert!(!debug.contains("not-so-secret-refresh"));
+        assert!(debug.contains("3600"));
+    }
+}
+
+#[test]
+fn authenticator_from_token() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use crate::auth::IamAuthenticator;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+    let auth = IamAuthenticator::from_token("cached-token".to_owned(), now + 3600);
+    assert_eq!(auth.access_token(), "cached-token");
+    assert_eq!(auth.expiration(), now + 3600);
+    assert!(!auth.is_expired());
+    // A token that is about to expire is not worth sending
+    assert!(IamAuthenticator::from_token("cached-token".to_owned(), now + 30).is_expired());
+    assert!(IamAuthenticator::from_token("cached-token".to_owned(), 0).is_expired());
+}
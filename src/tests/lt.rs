@@ -0,0 +1,87 @@
+use crate::lt::{IdentifiedLanguage, TranslateBody, TranslatedText, Translation};
+
+#[test]
+fn translate_body() {
+    let body = TranslateBody {
+        text: ["Hello, world"],
+        source: None,
+        target: "es",
+    };
+    assert_eq!(
+        serde_json::to_string(&body).unwrap(),
+        r#"{"text":["Hello, world"],"target":"es"}"#
+    );
+}
+
+#[test]
+fn deserialise_translation() {
+    let json = r#"{
+        "translations": [{ "translation": "Hola, mundo" }],
+        "word_count": 2,
+        "character_count": 12,
+        "detected_language": "en",
+        "detected_language_confidence": 0.98
+    }"#;
+    let translation: Translation = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        translation.translations,
+        [TranslatedText {
+            text: "Hola, mundo".to_owned()
+        }]
+    );
+    assert_eq!(translation.word_count, 2);
+    assert_eq!(translation.detected_language.as_deref(), Some("en"));
+
+    let language: IdentifiedLanguage =
+        serde_json::from_str(r#"{ "language": "en", "confidence": 0.9 }"#).unwrap();
+    assert_eq!(language.language, "en");
+}
+
+#[test]
+fn status_mapping() {
+    use reqwest::StatusCode;
+
+    use crate::{
+        error::WatsonError,
+        lt::{
+            errors::{IdentifyError, TranslateError},
+            identify_error, translate_error,
+        },
+    };
+
+    let body = |code: StatusCode| {
+        WatsonError::from_body(code.as_u16(), br#"{"code":404,"error":"Model not found"}"#)
+    };
+    let translate = |code| translate_error(code).map(|error| error(body(code)));
+    assert!(translate(StatusCode::OK).is_none());
+    assert!(matches!(
+        translate(StatusCode::BAD_REQUEST),
+        Some(TranslateError::BadRequest400(_))
+    ));
+    assert!(
+        matches!(translate(StatusCode::NOT_FOUND), Some(TranslateError::NotFound404(e)) if e.message == "Model not found")
+    );
+    assert!(matches!(
+        translate(StatusCode::INTERNAL_SERVER_ERROR),
+        Some(TranslateError::InternalServerError500)
+    ));
+    assert!(matches!(
+        translate(StatusCode::SERVICE_UNAVAILABLE),
+        Some(TranslateError::ServiceUnavailable503)
+    ));
+
+    let identify = |code| identify_error(code).map(|error| error(body(code)));
+    assert!(identify(StatusCode::OK).is_none());
+    assert!(matches!(
+        identify(StatusCode::BAD_REQUEST),
+        Some(IdentifyError::BadRequest400(_))
+    ));
+    assert!(matches!(
+        identify(StatusCode::INTERNAL_SERVER_ERROR),
+        Some(IdentifyError::InternalServerError500)
+    ));
+    assert!(matches!(
+        identify(StatusCode::SERVICE_UNAVAILABLE),
+        Some(IdentifyError::ServiceUnavailable503)
+    ));
+}
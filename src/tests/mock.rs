@@ -0,0 +1,301 @@
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::{
+    auth::IamAuthenticator,
+    tts::{
+        customisations::{errors::ListModelError, Language},
+        synthesis::{errors::SynthesisError, AudioFormat},
+        voices::{errors::ListVoicesError, Gender, WatsonVoice},
+        TextToSpeech,
+    },
+};
+
+const TOKEN: &str = "mock-token";
+
+/// A client that sends its requests to `server`, authenticated with [`TOKEN`]
+fn text_to_speech(server: &MockServer) -> TextToSpeech {
+    let auth = IamAuthenticator::from_token(TOKEN.to_owned(), i64::MAX);
+    TextToSpeech::new(&auth, server.uri())
+}
+
+fn error_body(code: u16, error: &str) -> serde_json::Value {
+    serde_json::json!({ "code": code, "error": error })
+}
+
+#[tokio::test]
+async fn list_voices() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/voices"))
+        .and(header("authorization", format!("Bearer {TOKEN}").as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "voices": [{
+                "url": format!("{}/v1/voices/en-GB_CharlotteV3Voice", server.uri()),
+                "gender": "female",
+                "name": "en-GB_CharlotteV3Voice",
+                "language": "en-GB",
+                "description": "Charlotte: British English female voice.",
+                "customizable": true,
+                "supported_features": {
+                    "custom_pronunciation": true,
+                    "voice_transformation": false
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let voices = text_to_speech(&server).list_voices().await.unwrap();
+    assert_eq!(voices.len(), 1);
+    assert_eq!(voices[0].name, "en-GB_CharlotteV3Voice");
+    assert_eq!(voices[0].gender, Gender::Female);
+    assert!(voices[0].customisable);
+    assert!(voices[0].supported_features.custom_pronunciation);
+}
+
+#[tokio::test]
+async fn list_voices_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/voices"))
+        .respond_with(
+            ResponseTemplate::new(503)
+                .set_body_json(error_body(503, "Service is down for maintenance")),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/voices"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("not json"))
+        .mount(&server)
+        .await;
+
+    let tts = text_to_speech(&server);
+    match tts.list_voices().await {
+        Err(ListVoicesError::ServiceUnavailable503(e)) => {
+            assert_eq!(e.code, 503);
+            assert_eq!(e.message, "Service is down for maintenance");
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+    assert!(matches!(
+        tts.list_voices().await,
+        Err(ListVoicesError::DeserializationError(_))
+    ));
+}
+
+#[tokio::test]
+async fn synthesise() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/synthesize"))
+        .and(query_param("text", "Hello & goodbye"))
+        .and(query_param("voice", "en-US_MichaelV3Voice"))
+        .and(query_param("accept", "audio/mp3;rate=22050"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "audio/mp3")
+                .set_body_bytes(b"ID3 audio".to_vec()),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut tts = text_to_speech(&server);
+    tts.set_voice(WatsonVoice::EnUsMichaelV3);
+    let audio = tts
+        .synthesise(
+            "Hello & goodbye",
+            Some(AudioFormat::AudioMp3 { sample_rate: None }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(&audio[..], b"ID3 audio");
+}
+
+#[tokio::test]
+async fn synthesise_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/synthesize"))
+        .and(query_param("customization_id", "missing"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(error_body(404, "Model 'missing' not found")),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/synthesize"))
+        .respond_with(ResponseTemplate::new(418))
+        .mount(&server)
+        .await;
+
+    let tts = text_to_speech(&server);
+    match tts.synthesise("Hello", None, Some("missing")).await {
+        Err(SynthesisError::NotFound404(e)) => assert_eq!(e.message, "Model 'missing' not found"),
+        other => panic!("unexpected result: {other:?}"),
+    }
+    assert!(matches!(
+        tts.synthesise("Hello", None, None).await,
+        Err(SynthesisError::UnmappedResponse(418))
+    ));
+}
+
+#[tokio::test]
+async fn list_custom_models() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/customizations"))
+        .and(query_param("language", "en-GB"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "customizations": [
+                {
+                    "customization_id": "0c5c8e1a-1111-4f6b-a8d5-0e1a2b3c4d5e",
+                    "name": "Second",
+                    "language": "en-GB",
+                    "owner": "owner-1",
+                    "created": "2023-01-02T00:00:00.000Z"
+                },
+                {
+                    "customization_id": "7a2b3c4d-2222-4e5f-9a8b-1c2d3e4f5a6b",
+                    "name": "First",
+                    "language": "en-GB",
+                    "owner": "owner-1",
+                    "created": "2023-01-01T00:00:00.000Z"
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let models = text_to_speech(&server)
+        .list_custom_models(Some(Language::EnGb), None)
+        .await
+        .unwrap();
+    let names: Vec<_> = models.iter().filter_map(|m| m.name.as_deref()).collect();
+    assert_eq!(names, ["Second", "First"]);
+    assert_eq!(
+        models[0].customisation_id,
+        "0c5c8e1a-1111-4f6b-a8d5-0e1a2b3c4d5e"
+    );
+}
+
+#[tokio::test]
+async fn list_custom_models_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/customizations"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .insert_header("x-global-transaction-id", "abc123")
+                .set_body_json(error_body(400, "Invalid value for 'language'")),
+        )
+        .mount(&server)
+        .await;
+
+    match text_to_speech(&server).list_custom_models(None, None).await {
+        Err(ListModelError::BadRequest400(e)) => {
+            assert_eq!(e.message, "Invalid value for 'language'");
+            assert_eq!(e.transaction_id.as_deref(), Some("abc123"));
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn custom_words() {
+    use crate::tts::customisations::{SortOrder, WordSort};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/customizations/cust-id/words"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "words": [
+                {"word": "IBM", "translation": "I B M"},
+                {"word": "NCAA", "translation": "N C double A"},
+                {"word": "NYSE", "translation": "N Y S E"}
+            ]
+        })))
+        .expect(4)
+        .mount(&server)
+        .await;
+
+    let tts = text_to_speech(&server);
+    let page = tts
+        .list_custom_words_page(
+            "cust-id",
+            Some(WordSort::Alphabetical(SortOrder::Descending)),
+            1,
+            5,
+        )
+        .await
+        .unwrap();
+    assert_eq!(page.total, 3);
+    let words: Vec<_> = page.words.iter().map(|w| w.word.as_str()).collect();
+    assert_eq!(words, ["NCAA", "IBM"]);
+
+    let page = tts
+        .list_custom_words_page(
+            "cust-id",
+            Some(WordSort::Translation(SortOrder::Ascending)),
+            0,
+            2,
+        )
+        .await
+        .unwrap();
+    let words: Vec<_> = page.words.iter().map(|w| w.word.as_str()).collect();
+    assert_eq!(words, ["IBM", "NCAA"]);
+
+    let word = tts.find_word("cust-id", "nyse").await.unwrap().unwrap();
+    assert_eq!(word.translation, "N Y S E");
+    assert_eq!(tts.find_word("cust-id", "NY").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn synthesise_audio() {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/synthesize"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "audio/ogg;codecs=opus")
+                .set_body_bytes(b"OggS audio".to_vec()),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut audio = text_to_speech(&server)
+        .synthesise_audio("Hello", None, None)
+        .await
+        .unwrap();
+    assert_eq!(audio.content_type(), "audio/ogg;codecs=opus");
+    assert!(matches!(
+        audio.format(),
+        Some(AudioFormat::AudioOggCodecsOpus { .. })
+    ));
+
+    let mut magic = [0; 4];
+    audio.read_exact(&mut magic).unwrap();
+    assert_eq!(&magic, b"OggS");
+    let mut rest = String::new();
+    audio.read_to_string(&mut rest).unwrap();
+    assert_eq!(rest, " audio");
+    audio.seek(SeekFrom::Start(0)).unwrap();
+    assert_eq!(audio.as_bytes(), b"OggS audio");
+
+    let path = std::env::temp_dir().join("ibm-watson-synthesise-audio.ogg");
+    audio.write_to(&path).unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), b"OggS audio");
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(&audio.into_bytes()[..], b"OggS audio");
+}
@@ -1 +1,36 @@
 mod auth;
+#[cfg(feature = "lt")]
+mod lt;
+#[cfg(feature = "tts")]
+mod mock;
+#[cfg(feature = "stt")]
+mod stt;
+#[cfg(feature = "tts")]
+mod tts;
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    task::JoinHandle,
+};
+
+/// Listens on a local port for a single connection, answers it with `response` and closes it.
+/// Returns the url of the listener and a handle to the head of the request that was received
+pub(crate) async fn one_shot_server(response: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut head = Vec::new();
+        let mut buf = [0; 1024];
+        while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+            match stream.read(&mut buf).await.unwrap() {
+                0 => break,
+                n => head.extend_from_slice(&buf[..n]),
+            }
+        }
+        stream.write_all(response.as_bytes()).await.unwrap();
+        String::from_utf8_lossy(&head).into_owned()
+    });
+    (url, handle)
+}
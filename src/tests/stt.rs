@@ -0,0 +1,775 @@
+use crate::stt::customisations::{AudioListing, AudioStatus, AudioType};
+use crate::stt::recognition::{
+    errors::RecognizeError, JobStatus, RecognitionJob, RecognitionResults, RecognizeParams,
+    WordTimestamp,
+};
+
+#[test]
+fn keywords_threshold_out_of_range() {
+    let params = RecognizeParams::new()
+        .keywords(["watson"])
+        .keywords_threshold(1.5);
+    assert!(matches!(
+        params.validate(),
+        Err(RecognizeError::InvalidParameter(_))
+    ));
+}
+
+#[test]
+fn keywords_need_threshold() {
+    let params = RecognizeParams::new().keywords(["watson"]);
+    assert!(params.validate().is_err());
+    let params = params.keywords_threshold(0.5);
+    assert!(params.validate().is_ok());
+}
+
+#[test]
+fn grammar_needs_custom_model() {
+    let params = RecognizeParams::new().grammar_name("yesno");
+    assert!(matches!(
+        params.validate(),
+        Err(RecognizeError::InvalidParameter(_))
+    ));
+    let params = params.language_customisation_id("cust-id");
+    assert!(params.validate().is_ok());
+    let mut url = reqwest::Url::parse("https://example.com/v1/recognize").unwrap();
+    params.append_query(&mut url);
+    assert_eq!(
+        url.query(),
+        Some("language_customization_id=cust-id&grammar_name=yesno")
+    );
+}
+
+#[test]
+fn deserialise_recognition_job() {
+    let job: RecognitionJob = serde_json::from_str(
+        r#"{
+            "id": "4bd734c0-e575-21f3-de03-f932aa0468a0",
+            "status": "completed",
+            "created": "2016-08-17T19:15:17.926Z",
+            "updated": "2016-08-17T19:15:20.095Z",
+            "results": [{
+                "result_index": 0,
+                "results": [{
+                    "final": true,
+                    "alternatives": [{"transcript": "several tornadoes touch down ", "confidence": 0.96}]
+                }]
+            }]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(job.status, JobStatus::Completed);
+    let results = job.results.unwrap();
+    assert_eq!(
+        results[0].results[0].alternatives[0].transcript,
+        "several tornadoes touch down "
+    );
+
+    let job: RecognitionJob = serde_json::from_str(
+        r#"{"id": "4bd734c0", "status": "waiting", "created": "2016-08-17T19:15:17.926Z"}"#,
+    )
+    .unwrap();
+    assert_eq!(job.status, JobStatus::Waiting);
+    assert_eq!(job.results, None);
+}
+
+#[test]
+fn deserialise_archive_audio() {
+    let audio: AudioListing = serde_json::from_str(
+        r#"{
+            "container": {
+                "duration": 131,
+                "name": "audio2",
+                "details": {"type": "archive", "compression": "zip"},
+                "status": "ok"
+            },
+            "audio": [{
+                "duration": 131,
+                "name": "audio-file1.wav",
+                "details": {"codec": "pcm_s16le", "type": "audio", "frequency": 16000},
+                "status": "ok"
+            }]
+        }"#,
+    )
+    .unwrap();
+    let container = audio.container.unwrap();
+    assert_eq!(container.details.audio_type, AudioType::Archive);
+    assert_eq!(container.details.compression.as_deref(), Some("zip"));
+    let files = audio.audio.unwrap();
+    assert_eq!(files[0].details.frequency, Some(16000));
+    assert_eq!(files[0].status, AudioStatus::Ok);
+    assert_eq!(audio.name, None);
+}
+
+#[test]
+fn deserialise_timestamps() {
+    let results: RecognitionResults = serde_json::from_str(
+        r#"{
+            "result_index": 0,
+            "results": [
+                {
+                    "alternatives": [
+                        {
+                            "timestamps": [
+                                ["several", 1.0, 1.51],
+                                ["tornadoes", 1.51, 2.15],
+                                ["touch", 2.15, 2.5],
+                                ["down", 2.5, 2.81]
+                            ],
+                            "confidence": 0.96,
+                            "transcript": "several tornadoes touch down "
+                        }
+                    ],
+                    "final": true
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let alternative = &results.results[0].alternatives[0];
+    let timestamps = alternative.timestamps.as_ref().unwrap();
+    assert_eq!(timestamps.len(), 4);
+    assert_eq!(
+        timestamps[1],
+        WordTimestamp {
+            word: String::from("tornadoes"),
+            start: 1.51,
+            end: 2.15,
+        }
+    );
+}
+
+#[test]
+fn model_ids() {
+    use crate::stt::models::ModelID;
+
+    assert_eq!(ModelID::DeDeBroadband.to_string(), "de-DE_BroadbandModel");
+    assert_eq!(ModelID::FrCaTelephony.to_string(), "fr-CA_Telephony");
+    assert_eq!(ModelID::FrCaNarrowband.to_string(), "fr-CA_NarrowbandModel");
+    assert_eq!(
+        ModelID::from("en-US_NewModel").to_string(),
+        "en-US_NewModel"
+    );
+}
+
+#[test]
+fn formatting_query_parameters() {
+    let query = |params: RecognizeParams| {
+        let mut url = reqwest::Url::parse("https://example.com/v1/recognize").unwrap();
+        params.append_query(&mut url);
+        url.query().unwrap_or_default().to_owned()
+    };
+    assert_eq!(query(RecognizeParams::new()), "");
+    assert_eq!(
+        query(
+            RecognizeParams::new()
+                .profanity_filter(false)
+                .smart_formatting(true)
+        ),
+        "profanity_filter=false&smart_formatting=true"
+    );
+}
+
+#[test]
+fn deserialise_grammar() {
+    use crate::stt::customisations::{Grammar, GrammarFormat, GrammarStatus};
+
+    let grammar: Grammar =
+        serde_json::from_str(r#"{"name":"yesno","out_of_vocabulary_words":0,"status":"analyzed"}"#)
+            .unwrap();
+    assert_eq!(grammar.name, "yesno");
+    assert_eq!(grammar.status, Some(GrammarStatus::Analysed));
+    let grammar: Grammar =
+        serde_json::from_str(r#"{"name":"yesno","status":"being_processed"}"#).unwrap();
+    assert_eq!(grammar.status, Some(GrammarStatus::BeingProcessed));
+
+    assert_eq!(GrammarFormat::Abnf.id(), "application/srgs");
+    assert_eq!(GrammarFormat::Xml.id(), "application/srgs+xml");
+}
+
+#[test]
+fn deserialise_word_alternatives() {
+    let results: RecognitionResults = serde_json::from_str(
+        r#"{
+            "result_index": 0,
+            "results": [{
+                "final": true,
+                "alternatives": [{"transcript": "hello world", "confidence": 0.9}],
+                "word_alternatives": [{
+                    "start_time": 0.1,
+                    "end_time": 0.6,
+                    "alternatives": [
+                        {"confidence": 0.8, "word": "hello"},
+                        {"confidence": 0.2, "word": "yellow"}
+                    ]
+                }]
+            }]
+        }"#,
+    )
+    .unwrap();
+    let word_alternatives = results.results[0].word_alternatives.as_ref().unwrap();
+    assert_eq!(word_alternatives[0].end_time, 0.6);
+    assert_eq!(word_alternatives[0].alternatives[1].word, "yellow");
+
+    let params = RecognizeParams::new().word_alternatives_threshold(1.2);
+    assert!(params.validate().is_err());
+    assert!(RecognizeParams::new()
+        .word_alternatives_threshold(0.1)
+        .validate()
+        .is_ok());
+}
+
+#[test]
+fn inactivity_timeout_and_audio_metrics() {
+    let params = RecognizeParams::new().inactivity_timeout(-2);
+    assert!(params.validate().is_err());
+    let params = RecognizeParams::new()
+        .inactivity_timeout(-1)
+        .audio_metrics(true);
+    assert!(params.validate().is_ok());
+    let mut url = reqwest::Url::parse("https://example.com/v1/recognize").unwrap();
+    params.append_query(&mut url);
+    assert_eq!(
+        url.query(),
+        Some("inactivity_timeout=-1&audio_metrics=true")
+    );
+
+    let results: RecognitionResults = serde_json::from_str(
+        r#"{
+            "result_index": 0,
+            "results": [],
+            "audio_metrics": {
+                "sampling_interval": 0.1,
+                "accumulated": {
+                    "final": true,
+                    "end_time": 2.5,
+                    "signal_to_noise_ratio": 21.5,
+                    "speech_ratio": 0.8,
+                    "high_frequency_loss": 0.0,
+                    "direct_current_offset": [{"begin": -1.0, "end": -0.9, "count": 0}],
+                    "clipping_rate": [{"begin": 0.0, "end": 0.01, "count": 25}],
+                    "speech_level": [],
+                    "non_speech_level": []
+                }
+            }
+        }"#,
+    )
+    .unwrap();
+    let metrics = results.audio_metrics.unwrap();
+    assert!(metrics.accumulated.final_metrics);
+    assert_eq!(metrics.accumulated.signal_to_noise_ratio, Some(21.5));
+    assert_eq!(metrics.accumulated.clipping_rate[0].count, 25);
+}
+
+#[test]
+fn model_ids_compare() {
+    use crate::stt::models::ModelID;
+
+    assert_eq!(ModelID::EnGbTelephony, ModelID::EnGbTelephony);
+    assert_ne!(ModelID::EnGbTelephony, ModelID::EnUsTelephony);
+    assert_eq!(ModelID::from("en-XX_Model"), ModelID::from("en-XX_Model"));
+}
+
+#[test]
+fn status_mapping() {
+    use reqwest::StatusCode;
+
+    use crate::stt::{
+        models::{
+            errors::{GetModelError, ListModelsError},
+            get_model_error, list_models_error, ModelID,
+        },
+        recognition::recognize_error,
+    };
+
+    assert!(matches!(
+        list_models_error(StatusCode::NOT_ACCEPTABLE),
+        ListModelsError::NotAcceptable406
+    ));
+    assert!(matches!(
+        list_models_error(StatusCode::UNSUPPORTED_MEDIA_TYPE),
+        ListModelsError::UnsupportedMediaType415
+    ));
+    assert!(matches!(
+        list_models_error(StatusCode::INTERNAL_SERVER_ERROR),
+        ListModelsError::InternalServerError500
+    ));
+    assert!(matches!(
+        list_models_error(StatusCode::SERVICE_UNAVAILABLE),
+        ListModelsError::ServiceUnavailable503
+    ));
+    assert!(matches!(
+        list_models_error(StatusCode::IM_A_TEAPOT),
+        ListModelsError::UnmappedResponse(418)
+    ));
+
+    let model = ModelID::EnGbBroadband;
+    assert!(
+        matches!(get_model_error(StatusCode::NOT_FOUND, &model), GetModelError::NotFound404(id) if id == "en-GB_BroadbandModel")
+    );
+    assert!(matches!(
+        get_model_error(StatusCode::NOT_ACCEPTABLE, &model),
+        GetModelError::NotAcceptable406
+    ));
+    assert!(matches!(
+        get_model_error(StatusCode::UNSUPPORTED_MEDIA_TYPE, &model),
+        GetModelError::UnsupportedMediaType415
+    ));
+    assert!(matches!(
+        get_model_error(StatusCode::INTERNAL_SERVER_ERROR, &model),
+        GetModelError::InternalServerError500
+    ));
+    assert!(matches!(
+        get_model_error(StatusCode::SERVICE_UNAVAILABLE, &model),
+        GetModelError::ServiceUnavailable503
+    ));
+
+    assert!(matches!(
+        recognize_error(StatusCode::BAD_REQUEST, None),
+        RecognizeError::BadRequest400
+    ));
+    assert!(
+        matches!(recognize_error(StatusCode::NOT_FOUND, None), RecognizeError::NotFound404(id) if id == "en-US_BroadbandModel")
+    );
+    assert!(
+        matches!(recognize_error(StatusCode::NOT_FOUND, Some(&model)), RecognizeError::NotFound404(id) if id == "en-GB_BroadbandModel")
+    );
+    assert!(matches!(
+        recognize_error(StatusCode::UNSUPPORTED_MEDIA_TYPE, None),
+        RecognizeError::UnsupportedMediaType415
+    ));
+    assert!(matches!(
+        recognize_error(StatusCode::INTERNAL_SERVER_ERROR, None),
+        RecognizeError::InternalServerError500
+    ));
+    assert!(matches!(
+        recognize_error(StatusCode::SERVICE_UNAVAILABLE, None),
+        RecognizeError::ServiceUnavailable503
+    ));
+}
+
+#[tokio::test]
+async fn ping() {
+    use super::one_shot_server;
+    use crate::{auth::IamAuthenticator, error::PingError, stt::SpeechToText};
+
+    let (url, request) = one_shot_server(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 13\r\nconnection: close\r\n\r\n{\"models\":[]}",
+    )
+    .await;
+    let client = reqwest::Client::new();
+    let stt = SpeechToText::with_client(&IamAuthenticator::default(), url, client);
+    assert!(stt.ping().await.is_ok());
+    assert!(request
+        .await
+        .unwrap()
+        .starts_with("GET /v1/models HTTP/1.1\r\n"));
+
+    let stt = SpeechToText::with_client(
+        &IamAuthenticator::default(),
+        "http://127.0.0.1:1",
+        reqwest::Client::new(),
+    );
+    assert!(matches!(
+        stt.ping().await,
+        Err(PingError::ConnectionError(_))
+    ));
+}
+
+#[tokio::test]
+async fn recognize_reader() {
+    use tokio::io::AsyncWriteExt;
+    use wiremock::{
+        matchers::{body_bytes, header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::{
+        auth::IamAuthenticator,
+        stt::{models::ModelID, SpeechToText},
+    };
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/recognize"))
+        .and(query_param("model", "en-GB_BroadbandModel"))
+        .and(header("content-type", "audio/l16;rate=16000"))
+        .and(header("transfer-encoding", "chunked"))
+        .and(body_bytes([1; 40000]))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "result_index": 0,
+            "results": [{
+                "final": true,
+                "alternatives": [{"transcript": "hello world ", "confidence": 0.92}]
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // the audio arrives through a pipe in parts, as it would from a recorder
+    let (mut writer, reader) = tokio::io::duplex(1024);
+    tokio::spawn(async move {
+        for _ in 0..4 {
+            writer.write_all(&[1; 10000]).await.unwrap();
+        }
+    });
+    let stt = SpeechToText::with_client(
+        &IamAuthenticator::default(),
+        server.uri(),
+        reqwest::Client::new(),
+    );
+    let results = stt
+        .recognize_reader(reader, "audio/l16;rate=16000", Some(ModelID::EnGbBroadband))
+        .await
+        .unwrap();
+    assert!(results.results[0].final_results);
+    assert_eq!(
+        results.results[0].alternatives[0].transcript,
+        "hello world "
+    );
+}
+
+#[test]
+fn model_default_features() {
+    use crate::stt::models::{ModelID, SupportedFeatures};
+
+    assert_eq!(
+        ModelID::EnUsTelephony.default_features(),
+        SupportedFeatures {
+            custom_language_model: true,
+            custom_acoustic_model: false,
+            speaker_labels: true,
+        }
+    );
+    assert_eq!(
+        ModelID::FrFrBroadband.default_features(),
+        SupportedFeatures {
+            custom_language_model: true,
+            custom_acoustic_model: true,
+            speaker_labels: false,
+        }
+    );
+    assert!(ModelID::JaJpNarrowband.default_features().speaker_labels);
+    assert_eq!(
+        ModelID::from("en-US_Telephony").default_features(),
+        SupportedFeatures::default()
+    );
+}
+
+#[tokio::test]
+async fn callbacks() {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::{
+        auth::IamAuthenticator,
+        stt::{
+            recognition::{errors::CallbackError, CallbackStatus},
+            SpeechToText,
+        },
+    };
+
+    const CALLBACK_URL: &str = "https://example.com/results";
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/register_callback"))
+        .and(query_param("callback_url", CALLBACK_URL))
+        .and(query_param("user_secret", "secret"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "status": "created",
+            "url": CALLBACK_URL
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/register_callback"))
+        .and(query_param("callback_url", CALLBACK_URL))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": "already created",
+            "url": CALLBACK_URL
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/register_callback"))
+        .respond_with(ResponseTemplate::new(400))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/unregister_callback"))
+        .and(query_param("callback_url", CALLBACK_URL))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/unregister_callback"))
+        .and(query_param("callback_url", "https://example.com/broken"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/unregister_callback"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let stt = SpeechToText::with_client(
+        &IamAuthenticator::default(),
+        server.uri(),
+        reqwest::Client::new(),
+    );
+    let registered = stt
+        .register_callback(CALLBACK_URL, Some("secret"))
+        .await
+        .unwrap();
+    assert_eq!(registered.status, CallbackStatus::Created);
+    assert_eq!(registered.url, CALLBACK_URL);
+    let registered = stt.register_callback(CALLBACK_URL, None).await.unwrap();
+    assert_eq!(registered.status, CallbackStatus::AlreadyCreated);
+    assert!(matches!(
+        stt.register_callback("not a url", None).await,
+        Err(CallbackError::BadRequest400)
+    ));
+
+    assert!(stt.unregister_callback(CALLBACK_URL).await.is_ok());
+    assert!(matches!(
+        stt.unregister_callback("https://example.com/other").await,
+        Err(CallbackError::NotFound404)
+    ));
+    assert!(matches!(
+        stt.unregister_callback("https://example.com/broken").await,
+        Err(CallbackError::InternalServerError500)
+    ));
+}
+
+#[test]
+fn callback_signature() {
+    use crate::stt::verify_callback_signature;
+
+    // computed independently for the key `secret`
+    let body = br#"{"id":"job-id","event":"recognitions.started","user_token":"token"}"#;
+    let signature = "HsC3eei0mo+t35c7NI0GBS7cHX4=";
+    assert!(verify_callback_signature(b"secret", body, signature));
+    assert!(verify_callback_signature(
+        b"secret",
+        body,
+        &format!(" {signature}\r\n")
+    ));
+    assert!(!verify_callback_signature(b"secret", b"{}", signature));
+    assert!(!verify_callback_signature(
+        b"secret",
+        body,
+        "IsC3eei0mo+t35c7NI0GBS7cHX4="
+    ));
+    assert!(!verify_callback_signature(b"secret", body, "HsC3eei0"));
+    assert!(!verify_callback_signature(b"secret", body, "not base64!"));
+    assert!(!verify_callback_signature(b"secret", body, ""));
+}
+
+#[test]
+fn training_state() {
+    use crate::stt::customisations::{AcousticModel, LanguageModel, TrainingState};
+
+    let model: LanguageModel = serde_json::from_str(
+        r#"{"customization_id": "cust-id", "status": "training", "progress": 0}"#,
+    )
+    .unwrap();
+    assert_eq!(model.training_state(), TrainingState::Training(0));
+
+    let model: LanguageModel = serde_json::from_str(
+        r#"{"customization_id": "cust-id", "status": "failed", "progress": 0, "error": "Cannot compile grammars"}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        model.training_state(),
+        TrainingState::Failed("Cannot compile grammars".to_owned())
+    );
+    assert_eq!(
+        LanguageModel::default().training_state(),
+        TrainingState::Pending
+    );
+
+    let model: AcousticModel = serde_json::from_str(
+        r#"{"customization_id": "cust-id", "status": "available", "progress": 100}"#,
+    )
+    .unwrap();
+    assert_eq!(model.training_state(), TrainingState::Available);
+    let model: AcousticModel =
+        serde_json::from_str(r#"{"customization_id": "cust-id", "status": "ready"}"#).unwrap();
+    assert_eq!(model.training_state(), TrainingState::Pending);
+}
+
+#[tokio::test]
+async fn upgrade_models() {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::{
+        auth::IamAuthenticator,
+        stt::{
+            customisations::errors::{TrainAcousticModelError, TrainLanguageModelError},
+            SpeechToText,
+        },
+    };
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/customizations/lang-id/upgrade_model"))
+        .respond_with(ResponseTemplate::new(200))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/customizations/lang-id/upgrade_model"))
+        .respond_with(ResponseTemplate::new(409))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(
+            "/v1/acoustic_customizations/acoustic-id/upgrade_model",
+        ))
+        .and(query_param("custom_language_model_id", "lang-id"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(
+            "/v1/acoustic_customizations/acoustic-id/upgrade_model",
+        ))
+        .respond_with(ResponseTemplate::new(409))
+        .mount(&server)
+        .await;
+
+    let stt = SpeechToText::with_client(
+        &IamAuthenticator::default(),
+        server.uri(),
+        reqwest::Client::new(),
+    );
+    assert!(stt.upgrade_language_model("lang-id").await.is_ok());
+    assert!(matches!(
+        stt.upgrade_language_model("lang-id").await,
+        Err(TrainLanguageModelError::Conflict409)
+    ));
+    assert!(stt
+        .upgrade_acoustic_model("acoustic-id", Some("lang-id"))
+        .await
+        .is_ok());
+    assert!(matches!(
+        stt.upgrade_acoustic_model("acoustic-id", None).await,
+        Err(TrainAcousticModelError::Conflict409)
+    ));
+}
+
+#[tokio::test]
+async fn common_errors() {
+    use reqwest::StatusCode;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::{
+        auth::IamAuthenticator,
+        error::CommonError,
+        stt::{customisations::errors::CorpusError, models::errors::ListModelsError, SpeechToText},
+    };
+
+    assert!(matches!(
+        CommonError::from_status(StatusCode::SERVICE_UNAVAILABLE),
+        CommonError::ServiceUnavailable503(e) if e.code == 503 && e.message == "Service Unavailable"
+    ));
+    assert!(matches!(
+        ListModelsError::from(CommonError::from_status(StatusCode::INTERNAL_SERVER_ERROR)),
+        ListModelsError::InternalServerError500
+    ));
+    assert!(matches!(
+        ListModelsError::from(CommonError::from_status(StatusCode::IM_A_TEAPOT)),
+        ListModelsError::UnmappedResponse(418)
+    ));
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/customizations/cust-id/corpora"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/customizations/cust-id/corpora"))
+        .respond_with(ResponseTemplate::new(502))
+        .mount(&server)
+        .await;
+
+    let stt = SpeechToText::with_client(
+        &IamAuthenticator::default(),
+        server.uri(),
+        reqwest::Client::new(),
+    );
+    assert!(matches!(
+        stt.list_corpora("cust-id").await,
+        Err(CorpusError::ServiceUnavailable503)
+    ));
+    assert!(matches!(
+        stt.list_corpora("cust-id").await,
+        Err(CorpusError::UnmappedResponse(502))
+    ));
+}
+
+#[tokio::test]
+async fn builder() {
+    use std::time::Duration;
+
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::{auth::IamAuthenticator, stt::SpeechToText};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/models"))
+        .and(header("x-watson-learning-opt-out", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"models": []})))
+        .mount(&server)
+        .await;
+
+    let stt = SpeechToText::builder(&IamAuthenticator::default(), server.uri())
+        .timeout(Duration::from_secs(10))
+        .pool_max_idle_per_host(4)
+        .pool_idle_timeout(Duration::from_secs(30))
+        .http2(false)
+        .learning_opt_out(true)
+        .build();
+    assert!(stt.list_models().await.unwrap().is_empty());
+    assert!(
+        SpeechToText::builder(&IamAuthenticator::default(), "service_url")
+            .try_build()
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn models_through_proxy() {
+    use super::one_shot_server;
+    use crate::{auth::IamAuthenticator, stt::SpeechToText};
+
+    let (proxy, request) = one_shot_server(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 13\r\nconnection: close\r\n\r\n{\"models\":[]}",
+    )
+    .await;
+    let stt = SpeechToText::builder(&IamAuthenticator::default(), "http://stt.invalid")
+        .proxy(reqwest::Proxy::all(proxy).unwrap())
+        .http2(false)
+        .build();
+    assert!(stt.list_models().await.unwrap().is_empty());
+    let request = request.await.unwrap();
+    assert!(request.starts_with("GET http://stt.invalid/v1/models HTTP/1.1\r\n"));
+}
@@ -0,0 +1,177 @@
+use bytes::Bytes;
+use reqwest::{header::ACCEPT, StatusCode, Url};
+
+use crate::{
+    auth::IamAuthenticator,
+    client::{parse_service_url, ServiceClient, JSON},
+    error::{CommonError, ServiceError, WatsonError},
+};
+
+use super::{
+    customisations::errors::ParseLanguageError,
+    synthesis::{
+        errors::SynthesisError, synthesis_error, synthesis_url, AudioFormat, SynthesisInput,
+    },
+    voices::{
+        errors::ListVoicesError, list_voices_error, voices_url, Voice, VoiceList, WatsonVoice,
+    },
+};
+
+/// A blocking client used to send requests to your Text To Speech endpoint. It mirrors the async
+/// [`TextToSpeech`], for programs that do not run an async runtime. Like every blocking client, it
+/// must not be created or used from within an async runtime
+///
+/// # Example
+/// ``` no_run
+/// # use ibm_watson::{
+/// #     auth::IamAuthenticator,
+/// #     tts::blocking::TextToSpeech,
+/// # };
+/// # fn foo()-> Result<(), Box<dyn std::error::Error>> {
+/// let auth = IamAuthenticator::new_blocking("api_key")?;
+/// let tts = TextToSpeech::new(&auth, "service_url");
+/// let synth_bytes = tts.synthesise("Hey there", None, None)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`TextToSpeech`]: crate::tts::TextToSpeech
+#[derive(Clone)]
+pub struct TextToSpeech {
+    service_url: Url,
+    voice: WatsonVoice,
+    client: reqwest::blocking::Client,
+}
+
+impl TextToSpeech {
+    /// Create a new blocking Text To Speech instance. This instance will be used to make all the
+    /// requests to the text to speech service.
+    ///
+    /// # Parameters
+    /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
+    /// * `service_url` - The endpoint for your text to speech instance. All Text To Speech
+    ///   requests will be made to this endpoint
+    ///
+    /// # Panics
+    ///
+    /// Panics if `service_url` is not an absolute `http` or `https` url. Use [`try_new()`] to
+    /// handle an invalid url instead
+    ///
+    /// [`IamAuthenticator`]: crate::auth::IamAuthenticator
+    /// [`try_new()`]: Self::try_new()
+    pub fn new(authenticator: &IamAuthenticator, service_url: impl Into<String>) -> Self {
+        Self::try_new(authenticator, service_url).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Create a new blocking Text To Speech instance like [`new()`], but return [`InvalidUrl`]
+    /// instead of panicking if `service_url` is not an absolute `http` or `https` url
+    ///
+    /// [`new()`]: Self::new()
+    /// [`InvalidUrl`]: crate::error::ServiceError::InvalidUrl
+    pub fn try_new(
+        authenticator: &IamAuthenticator,
+        service_url: impl Into<String>,
+    ) -> Result<Self, ServiceError> {
+        let service_url = parse_service_url(&service_url.into())?;
+        let client = ServiceClient::blocking(
+            authenticator.token_response().access_token(),
+            cfg!(feature = "http2"),
+        );
+        Ok(Self {
+            service_url,
+            voice: WatsonVoice::default(),
+            client,
+        })
+    }
+
+    /// Change the default voice to use for Text To Speech requests
+    ///
+    /// # Parameters
+    ///
+    /// * `voice` - Use this [`voice`] in place of the [`default`] one
+    ///
+    /// [`voice`]: crate::tts::voices::WatsonVoice
+    /// [`default`]: crate::tts::voices::WatsonVoice::EnUsMichaelV3
+    pub fn set_voice(&mut self, voice: WatsonVoice) {
+        self.voice = voice;
+    }
+
+    /// Change the default voice to the one that [`default_for_language()`] picks for a language. See [`set_voice_for_language()`] for more details
+    ///
+    /// # Parameters
+    ///
+    /// * `lang` - The language code, for example `en-US` or `en`
+    ///
+    /// [`default_for_language()`]: crate::tts::voices::WatsonVoice::default_for_language()
+    /// [`set_voice_for_language()`]: crate::tts::TextToSpeech::set_voice_for_language()
+    pub fn set_voice_for_language(&mut self, lang: &str) -> Result<(), ParseLanguageError> {
+        self.voice = WatsonVoice::default_for_language(lang)
+            .ok_or_else(|| ParseLanguageError::UnknownLanguage(lang.to_owned()))?;
+        Ok(())
+    }
+
+    /// The default voice that is used for Text To Speech requests
+    pub fn voice(&self) -> &WatsonVoice {
+        &self.voice
+    }
+
+    /// Synthesises text to audio that is spoken in the [`specified voice`]. See [`synthesise()`] for more details
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
+    /// * `format` - The requested [`AudioFormat`] (MIME type) of the audio. Defaults to [`AudioOggCodecsOpus`]
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. Omit the parameter to use the specified voice with no customisation
+    ///
+    /// [`specified voice`]: Self::set_voice()
+    /// [`synthesise()`]: crate::tts::TextToSpeech::synthesise()
+    /// [`input`]: crate::tts::synthesis::SynthesisInput
+    /// [`AudioFormat`]: crate::tts::synthesis::AudioFormat
+    /// [`AudioOggCodecsOpus`]: crate::tts::synthesis::AudioFormat::AudioOggCodecsOpus
+    /// [`model`]: crate::tts::customisations::Model
+    pub fn synthesise(
+        &self,
+        text: impl Into<SynthesisInput>,
+        format: Option<AudioFormat>,
+        customisation_id: Option<&str>,
+    ) -> Result<Bytes, SynthesisError> {
+        let url = synthesis_url(
+            &self.service_url,
+            &text.into(),
+            self.voice.id(),
+            format,
+            customisation_id,
+        )?;
+        let response = self.client.get(url).send()?;
+        let status = response.status();
+        if status == StatusCode::OK {
+            return Ok(response.bytes()?);
+        }
+        match synthesis_error(status) {
+            Some(error) => Err(error(WatsonError::from_blocking_response(response))),
+            None => Err(CommonError::from_blocking_response(response).into()),
+        }
+    }
+
+    /// Lists all voices available for use with the service. See [`list_voices()`] for more details
+    ///
+    /// [`list_voices()`]: crate::tts::TextToSpeech::list_voices()
+    pub fn list_voices(&self) -> Result<Vec<Voice>, ListVoicesError> {
+        let response = self
+            .client
+            .get(voices_url(&self.service_url))
+            .header(ACCEPT, JSON)
+            .send()?;
+        let status = response.status();
+        if status == StatusCode::OK {
+            let root: VoiceList = response
+                .json()
+                .map_err(|e| ListVoicesError::DeserializationError(e.to_string()))?;
+            return Ok(root.voices);
+        }
+        match list_voices_error(status) {
+            Some(error) => Err(error(WatsonError::from_blocking_response(response))),
+            None => Err(ListVoicesError::UnmappedResponse(status.as_u16())),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use reqwest::Proxy;
+
+use crate::{
+    auth::IamAuthenticator,
+    client::{parse_service_url, ClientOptions},
+    error::ServiceError,
+};
+
+use super::{voices::WatsonVoice, TextToSpeech};
+
+/// Configures and creates a [`TextToSpeech`] instance
+///
+/// # Example
+/// ``` no_run
+/// # use ibm_watson::{
+/// #     auth::IamAuthenticator,
+/// #     tts::{voices::WatsonVoice, TextToSpeech},
+/// # };
+/// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+/// let auth = IamAuthenticator::new("api_key").await?;
+/// let tts = TextToSpeech::builder(&auth, "service_url")
+///     .timeout(std::time::Duration::from_secs(30))
+///     .retries(3)
+///     .pool_max_idle_per_host(16)
+///     .default_voice(WatsonVoice::EnGbCharlotteV3)
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`TextToSpeech`]: super::TextToSpeech
+pub struct TextToSpeechBuilder<'a> {
+    authenticator: &'a IamAuthenticator,
+    service_url: String,
+    options: ClientOptions,
+    retries: u32,
+    learning_opt_out: bool,
+    voice: WatsonVoice,
+}
+
+impl<'a> TextToSpeechBuilder<'a> {
+    pub(crate) fn new(authenticator: &'a IamAuthenticator, service_url: impl Into<String>) -> Self {
+        Self {
+            authenticator,
+            service_url: service_url.into(),
+            options: ClientOptions::default(),
+            retries: 0,
+            learning_opt_out: false,
+            voice: WatsonVoice::default(),
+        }
+    }
+
+    /// Sets a timeout for every request, from when the request starts connecting until the
+    /// response body has finished. By default there is no timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// The most idle connections that are kept open to the service for reuse. Raise it if many
+    /// requests are made at once, so that new connections do not have to be opened for each of
+    /// them. By default there is no limit
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.options.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// How long an idle connection is kept open for reuse before it is closed. Defaults to 90
+    /// seconds
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.options.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sends every request through `proxy`, for example when all outgoing traffic has to go
+    /// through a corporate proxy. The [`IamAuthenticator`] is configured separately with
+    /// [`with_proxy()`]. By default, the proxy set by the `HTTP_PROXY` and `HTTPS_PROXY`
+    /// environment variables is used, if any
+    ///
+    /// [`IamAuthenticator`]: crate::auth::IamAuthenticator
+    /// [`with_proxy()`]: crate::auth::IamAuthenticator::with_proxy()
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.options.proxy = Some(proxy);
+        self
+    }
+
+    /// Whether to send requests as `HTTP/2.0` without negotiating it first. Defaults to true if
+    /// the `http2` feature is enabled
+    pub fn http2(mut self, http2: bool) -> Self {
+        self.options.http2 = http2;
+        self
+    }
+
+    /// Retries `GET` requests, such as [`synthesise()`] and [`list_voices()`], up to `max` times
+    /// if the service responds with a 500 or 503. The retries back off exponentially with some
+    /// jitter, and the last error is returned if all of them fail. Requests that create, update or
+    /// delete resources are never retried. Defaults to 0
+    ///
+    /// [`synthesise()`]: crate::tts::TextToSpeech::synthesise()
+    /// [`list_voices()`]: crate::tts::TextToSpeech::list_voices()
+    pub fn retries(mut self, max: u32) -> Self {
+        self.retries = max;
+        self
+    }
+
+    /// Opt out of having request data used to improve the service, by sending
+    /// `X-Watson-Learning-Opt-Out: true` with every request. Defaults to false. It can be changed
+    /// later with [`set_learning_opt_out()`]
+    ///
+    /// [`set_learning_opt_out()`]: crate::tts::TextToSpeech::set_learning_opt_out()
+    pub fn learning_opt_out(mut self, opt_out: bool) -> Self {
+        self.learning_opt_out = opt_out;
+        self
+    }
+
+    /// The [`voice`] to use for requests. Defaults to [`EnUsMichaelV3`]. It can be changed later
+    /// with [`set_voice()`]
+    ///
+    /// [`voice`]: crate::tts::voices::WatsonVoice
+    /// [`EnUsMichaelV3`]: crate::tts::voices::WatsonVoice::EnUsMichaelV3
+    /// [`set_voice()`]: crate::tts::TextToSpeech::set_voice()
+    pub fn default_voice(mut self, voice: WatsonVoice) -> Self {
+        self.voice = voice;
+        self
+    }
+
+    /// Creates the [`TextToSpeech`] instance
+    ///
+    /// # Panics
+    ///
+    /// Panics if the service url is not an absolute `http` or `https` url, or if the HTTP client
+    /// cannot be built. Use [`try_build()`] to handle these errors instead
+    ///
+    /// [`TextToSpeech`]: super::TextToSpeech
+    /// [`try_build()`]: Self::try_build()
+    pub fn build(self) -> TextToSpeech {
+        self.try_build().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Creates the [`TextToSpeech`] instance. Returns [`InvalidUrl`] if the service url is not an
+    /// absolute `http` or `https` url, or [`ClientBuild`] if the HTTP client cannot be built, for
+    /// example because the [`proxy`] cannot be set up
+    ///
+    /// [`TextToSpeech`]: super::TextToSpeech
+    /// [`InvalidUrl`]: crate::error::ServiceError::InvalidUrl
+    /// [`ClientBuild`]: crate::error::ServiceError::ClientBuild
+    /// [`proxy`]: Self::proxy()
+    pub fn try_build(self) -> Result<TextToSpeech, ServiceError> {
+        let service_url = parse_service_url(&self.service_url)?;
+        let mut client = self
+            .options
+            .build(self.authenticator.token_response().access_token())?
+            .retries(self.retries);
+        client.set_learning_opt_out(self.learning_opt_out);
+        Ok(TextToSpeech {
+            service_url,
+            voice: self.voice,
+            client,
+        })
+    }
+}
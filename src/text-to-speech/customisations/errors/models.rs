@@ -1,20 +1,25 @@
 use thiserror::Error;
 
+use crate::error::{impl_from_common_error, WatsonError};
+
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum CreateModelError {
     /// There was an error establishing the connection
     #[error("There was an error establishing the connection")]
     ConnectionError(#[from] reqwest::Error),
-    #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported")]
+    #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported: {0}")]
     /// A required input parameter is null or a specified input parameter or header value is invalid or not supported
-    BadRequest400,
+    BadRequest400(WatsonError),
     /// The service is currently unavailable
-    #[error("The service is currently unavailable")]
-    ServiceUnavailable503,
+    #[error("The service is currently unavailable: {0}")]
+    ServiceUnavailable503(WatsonError),
     /// The service experienced an internal error
-    #[error("The service experienced an internal error")]
-    InternalServerError500,
+    #[error("The service experienced an internal error: {0}")]
+    InternalServerError500(WatsonError),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response code the server returnes
     #[error("{0}")]
     UnmappedResponse(u16),
@@ -26,15 +31,18 @@ pub enum ListModelError {
     /// There was an error establishing the connection
     #[error("There was an error establishing the connection")]
     ConnectionError(#[from] reqwest::Error),
-    #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported")]
+    #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported: {0}")]
     /// A required input parameter is null or a specified input parameter or header value is invalid or not supported
-    BadRequest400,
-    #[error("The service is currently unavailable")]
+    BadRequest400(WatsonError),
+    #[error("The service is currently unavailable: {0}")]
     /// The service is currently unavailable
-    ServiceUnavailable503,
+    ServiceUnavailable503(WatsonError),
     /// The service experienced an internal error
-    #[error("The service experienced an internal error")]
-    InternalServerError500,
+    #[error("The service experienced an internal error: {0}")]
+    InternalServerError500(WatsonError),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response code the server returnes
     #[error("{0}")]
     UnmappedResponse(u16),
@@ -46,15 +54,15 @@ pub enum UpdateModelError {
     /// There was an error establishing the connection
     #[error("There was an error establishing the connection")]
     ConnectionError(#[from] reqwest::Error),
-    #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported")]
+    #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported: {0}")]
     /// A required input parameter is null or a specified input parameter or header value is invalid or not supported
-    BadRequest400,
-    #[error("The service is currently unavailable")]
+    BadRequest400(WatsonError),
+    #[error("The service is currently unavailable: {0}")]
     /// The service is currently unavailable
-    ServiceUnavailable503,
-    #[error("The service experienced an internal error")]
+    ServiceUnavailable503(WatsonError),
+    #[error("The service experienced an internal error: {0}")]
     /// The service experienced an internal error
-    InternalServerError500,
+    InternalServerError500(WatsonError),
     #[error("The specified customisation_id {0} is invalid for the requesting credentials")]
     /// The specified customisation_id is invalid for the requesting credentials
     Unauthorised401(String),
@@ -72,18 +80,21 @@ pub enum GetModelError {
     #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported")]
     /// A required input parameter is null or a specified input parameter or header value is invalid or not supported
     BadRequest400(String),
-    #[error("The service is currently unavailable")]
+    #[error("The service is currently unavailable: {0}")]
     /// The service is currently unavailable
-    ServiceUnavailable503,
-    #[error("The service experienced an internal error")]
+    ServiceUnavailable503(WatsonError),
+    #[error("The service experienced an internal error: {0}")]
     /// The service experienced an internal error
-    InternalServerError500,
+    InternalServerError500(WatsonError),
     /// "The specified customisation_id is invalid for the requesting credentials
     #[error("The specified customisation_id {0} is invalid for the requesting credentials")]
     Unauthorised401(String),
     #[error(" The requested resource has not been modified since the time specified by the If-Modified-Since header, as documented in the HTTP specification.")]
     /// The requested resource has not been modified since the time specified by the If-Modified-Since header, as documented in the HTTP specification
     NotModified304,
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response code the server returnes
     #[error("{0}")]
     UnmappedResponse(u16),
@@ -98,12 +109,12 @@ pub enum DeleteModelError {
     #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported")]
     /// A required input parameter is null or a specified input parameter or header value is invalid or not supported
     BadRequest400(String),
-    #[error("The service is currently unavailable")]
+    #[error("The service is currently unavailable: {0}")]
     /// The service is currently unavailable
-    ServiceUnavailable503,
-    #[error("The service experienced an internal error")]
+    ServiceUnavailable503(WatsonError),
+    #[error("The service experienced an internal error: {0}")]
     /// The service experienced an internal error
-    InternalServerError500,
+    InternalServerError500(WatsonError),
     #[error("The specified customisation_id {0} is invalid for the requesting credentials")]
     /// The specified customisation_id is invalid for the requesting credentials
     Unauthorised401(String),
@@ -111,3 +122,16 @@ pub enum DeleteModelError {
     #[error("{0}")]
     UnmappedResponse(u16),
 }
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+/// Errors that may be returned when parsing a [`Language`] from its id
+///
+/// [`Language`]: crate::tts::customisations::Language
+pub enum ParseLanguageError {
+    #[error("{0} is not a known language id")]
+    /// The string is not the id of any known language
+    UnknownLanguage(String),
+}
+
+impl_from_common_error!(with_body: CreateModelError, ListModelError, UpdateModelError, GetModelError, DeleteModelError);
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::error::impl_from_common_error;
+
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum ListPromptsError {
@@ -15,6 +17,9 @@ pub enum ListPromptsError {
     /// The service experienced an internal error
     #[error("The service experienced an internal error")]
     InternalServerError500,
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response that the server sends back
     #[error("{0}")]
     UnmappedResponse(u16),
@@ -46,17 +51,39 @@ pub enum AddPromptError {
     /// There was an error reading the file
     #[error("There was an error reading the file: {0}")]
     FileReadError(String),
+    /// The audio is not a PCM WAV file that is sampled at 16 kHz or more and is at most 30
+    /// seconds long, so it was not sent. Contains the reason
+    #[error("The audio cannot be used for a prompt: {0}")]
+    InvalidAudio(String),
     /// The request passed an unacceptable media type with the Content-Type header. The header must pass a value of multipart/form-data
     #[error("The request passed an unacceptable media type with the Content-Type header. The header must pass a value of multipart/form-data")]
     UnsupportedMediaType415,
     /// The specified customisation_id is invalid for the requesting credentials
     #[error("The specified customisation_id  {0} is invalid for the requesting credentials")]
     Unauthorised401(String),
+    /// The prompt was added, but the service failed to process it. Contains the error that the service reported
+    #[error("The prompt could not be processed: {0}")]
+    Failed(String),
+    /// The prompt was added, but waiting for it to be processed failed
+    #[error(transparent)]
+    Wait(WaitForPromptError),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response that the server sends back
     #[error("{0}")]
     UnmappedResponse(u16),
 }
 
+impl From<WaitForPromptError> for AddPromptError {
+    fn from(error: WaitForPromptError) -> Self {
+        match error {
+            WaitForPromptError::Failed(message) => Self::Failed(message),
+            error => Self::Wait(error),
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum GetPromptError {
@@ -75,6 +102,9 @@ pub enum GetPromptError {
     /// "The specified customisation_id is invalid for the requesting credentials
     #[error("The specified customisation_id {0} is invalid for the requesting credentials")]
     Unauthorised401(String),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response that the server sends back
     #[error("{0}")]
     UnmappedResponse(u16),
@@ -102,3 +132,25 @@ pub enum DeletePromptError {
     #[error("{0}")]
     UnmappedResponse(u16),
 }
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned while waiting for a prompt to be processed
+pub enum WaitForPromptError {
+    /// The prompt could not be retrieved
+    #[error(transparent)]
+    GetPrompt(#[from] GetPromptError),
+    /// The service's validation of the prompt failed. Contains the error that the service reported
+    #[error("The prompt could not be processed: {0}")]
+    Failed(String),
+    /// The prompt was still being processed when the timeout elapsed
+    #[error("The prompt was not processed within {0:?}")]
+    Timeout(std::time::Duration),
+}
+
+impl_from_common_error!(
+    ListPromptsError,
+    AddPromptError,
+    GetPromptError,
+    DeletePromptError
+);
@@ -1,4 +1,7 @@
 use thiserror::Error;
+
+use crate::error::impl_from_common_error;
+
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum AddWordError {
@@ -41,6 +44,9 @@ pub enum ListWordsError {
     #[error("The specified customisation_id {0} is invalid for the requesting credentials")]
     Unauthorised401(String),
 
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response that the server sends back
     #[error("{0}")]
     UnmappedResponse(u16),
@@ -62,6 +68,9 @@ pub enum GetWordError {
     #[error("The specified customisation_id {0} is invalid for the requesting credentials")]
     /// The specified customisation_id is invalid for the requesting credentials
     Unauthorised401(String),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response that the server sends back
     #[error("{0}")]
     UnmappedResponse(u16),
@@ -89,3 +98,39 @@ pub enum DeleteWordError {
     #[error("{0}")]
     UnmappedResponse(u16),
 }
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+/// Errors that may be returned when setting the part of speech of a word in a Japanese custom model
+pub enum SetPartOfSpeechError {
+    /// The part of speech is not one of the JEITA codes that the service supports
+    #[error("{0} is not a supported Japanese part of speech")]
+    InvalidPartOfSpeech(String),
+    /// Parts of speech are only supported by Japanese custom models
+    #[error(
+        "parts of speech are only supported by Japanese custom models, the model's language is {0}"
+    )]
+    UnsupportedLanguage(String),
+    /// The custom model could not be fetched
+    #[error(transparent)]
+    GetModel(#[from] super::GetModelError),
+    /// The current translation of the word could not be fetched
+    #[error(transparent)]
+    GetWord(#[from] GetWordError),
+    /// The word could not be updated
+    #[error(transparent)]
+    AddWord(#[from] AddWordError),
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+/// Errors that may be returned when parsing a [`PartOfSpeech`] from its JEITA code
+///
+/// [`PartOfSpeech`]: crate::tts::customisations::PartOfSpeech
+pub enum ParsePartOfSpeechError {
+    /// The string is not the JEITA code of a part of speech that the service supports
+    #[error("{0} is not a supported Japanese part of speech")]
+    UnknownPartOfSpeech(String),
+}
+
+impl_from_common_error!(AddWordError, ListWordsError, GetWordError, DeleteWordError);
@@ -1,12 +1,19 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, str::FromStr, time::SystemTime};
 
-use reqwest::{Method, Request, StatusCode, Url, Version};
+use reqwest::{Method, Request, StatusCode};
 use serde::{Deserialize, Serialize};
 
-use crate::tts::TextToSpeech;
+use crate::{
+    client::ServiceClient,
+    error::{CommonError, WatsonError},
+    tts::{voices::WatsonVoice, TextToSpeech},
+};
 
 use super::{
-    errors::{CreateModelError, DeleteModelError, GetModelError, ListModelError, UpdateModelError},
+    errors::{
+        CreateModelError, DeleteModelError, GetModelError, ListModelError, ParseLanguageError,
+        UpdateModelError,
+    },
     prompts::Prompt,
     words::Word,
 };
@@ -17,9 +24,9 @@ pub struct Model {
     /// the customisation id (guid) of the custom model. the create a custom model method returns only this field. it does not not return the other fields of this object.
     #[serde(rename = "customization_id")]
     pub customisation_id: String,
-    /// the name of the custom model.
-    #[serde(rename = "name")]
-    pub name: String,
+    /// the name of the custom model. the create a custom model method does not return this field.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /// the language identifier of the custom model (for example, en-us).
     #[serde(rename = "language", skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
@@ -43,8 +50,101 @@ pub struct Model {
     pub prompts: Option<Vec<Prompt>>,
 }
 
+impl Model {
+    /// The [`language`] of the custom model as a [`Language`], or [`None`] if the service did not return it or it is not one that this library knows about
+    ///
+    /// [`language`]: Self::language
+    /// [`Language`]: self::Language
+    pub fn language_enum(&self) -> Option<Language> {
+        self.language.as_deref()?.parse().ok()
+    }
+
+    /// Counts the words and prompts of the custom model. Only a model that was returned by [`get_custom_model()`] has its words and prompts, so the counts of any other model are 0
+    ///
+    /// [`get_custom_model()`]: crate::tts::TextToSpeech::get_custom_model()
+    pub fn stats(&self) -> ModelStats {
+        let words = self.words.as_deref().unwrap_or_default();
+        ModelStats {
+            word_count: words.len(),
+            prompt_count: self.prompts.as_ref().map_or(0, Vec::len),
+            words_with_part_of_speech: words
+                .iter()
+                .filter(|word| word.part_of_speech.is_some())
+                .count(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+/// A summary of the contents of a custom model. A custom model can contain no more than 20,000 entries, so [`word_count`] can be compared with that limit to see how much room is left
+///
+/// [`word_count`]: Self::word_count
+pub struct ModelStats {
+    /// The number of words in the custom model
+    pub word_count: usize,
+    /// The number of prompts in the custom model
+    pub prompt_count: usize,
+    /// The number of words in the custom model that have a part of speech, which only Japanese models have
+    pub words_with_part_of_speech: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The field by which custom models are sorted
+pub enum SortBy {
+    /// Sort by the name of the model
+    Name(SortOrder),
+    /// Sort by the time at which the model was created
+    Created(SortOrder),
+    /// Sort by the time at which the model was last modified
+    LastModified(SortOrder),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// The direction in which custom models or their words are sorted
+pub enum SortOrder {
+    #[default]
+    /// Smallest first
+    Ascending,
+    /// Largest first
+    Descending,
+}
+
+impl SortBy {
+    /// Sorts `models` in place. The sort is stable, so models that compare equal keep their order
+    pub fn sort(&self, models: &mut [Model]) {
+        models.sort_by(|a, b| {
+            let (ordering, order) = match self {
+                SortBy::Name(order) => (a.name.cmp(&b.name), order),
+                SortBy::Created(order) => (a.created.cmp(&b.created), order),
+                SortBy::LastModified(order) => (a.last_modified.cmp(&b.last_modified), order),
+            };
+            match order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+/// A window of custom models
+pub struct ModelPage {
+    /// The models in the window
+    pub models: Vec<Model>,
+    /// The total number of models, including the ones outside the window
+    pub total: usize,
+}
+
+impl ModelPage {
+    pub(crate) fn new(models: Vec<Model>, offset: usize, limit: usize) -> Self {
+        let total = models.len();
+        let models = models.into_iter().skip(offset).take(limit).collect();
+        Self { models, total }
+    }
+}
+
 #[non_exhaustive]
-#[derive(Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 /// The language of the new custom model
 pub enum Language {
     /// Arabic
@@ -88,7 +188,42 @@ pub enum Language {
     ZhCn,
 }
 
+const ALL_LANGUAGES: &[Language] = &[
+    Language::ArMs,
+    Language::CsCz,
+    Language::DeDe,
+    Language::EnAu,
+    Language::EnGb,
+    Language::EnUs,
+    Language::EsEs,
+    Language::EsLa,
+    Language::EsUs,
+    Language::FrCa,
+    Language::FrFr,
+    Language::ItIt,
+    Language::JaJp,
+    Language::KoKr,
+    Language::NlBe,
+    Language::NlNl,
+    Language::PtBr,
+    Language::SvSe,
+    Language::ZhCn,
+];
+
 impl Language {
+    /// Every language that custom models can be created in, in the order in which they are declared
+    ///
+    /// # Example
+    /// ```
+    /// # use ibm_watson::tts::customisations::Language;
+    /// for language in Language::all() {
+    ///     println!("{}", language.id());
+    /// }
+    /// ```
+    pub fn all() -> &'static [Language] {
+        ALL_LANGUAGES
+    }
+
     /// The value that the server expects for a particular language
     pub fn id(&self) -> Cow<'static, str> {
         match self {
@@ -113,9 +248,73 @@ impl Language {
             Language::ZhCn => Cow::from("zh-CN"),
         }
     }
+
+    /// The voice that [`WatsonVoice::default_for_language()`] picks for the language. For US English it is the [`default`] voice of the client
+    ///
+    /// # Example
+    /// ```
+    /// # use ibm_watson::tts::{customisations::Language, voices::WatsonVoice};
+    /// assert_eq!(Language::FrFr.default_voice(), WatsonVoice::FrFrReneeV3);
+    /// ```
+    ///
+    /// [`WatsonVoice::default_for_language()`]: crate::tts::voices::WatsonVoice::default_for_language()
+    /// [`default`]: crate::tts::voices::WatsonVoice::EnUsMichaelV3
+    pub fn default_voice(&self) -> WatsonVoice {
+        match self {
+            Language::ArMs => WatsonVoice::ArMsOmar,
+            Language::CsCz => WatsonVoice::CsCzAlena,
+            Language::DeDe => WatsonVoice::DeDeBirgitV3,
+            Language::EnAu => WatsonVoice::EnAuCraig,
+            Language::EnGb => WatsonVoice::EnGbCharlotteV3,
+            Language::EnUs => WatsonVoice::EnUsMichaelV3,
+            Language::EsEs => WatsonVoice::EsEsLauraV3,
+            Language::EsLa => WatsonVoice::EsLaSofiaV3,
+            Language::EsUs => WatsonVoice::EsUsSofiaV3,
+            Language::FrCa => WatsonVoice::FrCaLouiseV3,
+            Language::FrFr => WatsonVoice::FrFrReneeV3,
+            Language::ItIt => WatsonVoice::ItItFrancescaV3,
+            Language::JaJp => WatsonVoice::JaJpEmiV3,
+            Language::KoKr => WatsonVoice::KoKrJinV3,
+            Language::NlBe => WatsonVoice::NlBeAdele,
+            Language::NlNl => WatsonVoice::NlNlMerelV3,
+            Language::PtBr => WatsonVoice::PtBrIsabelaV3,
+            Language::SvSe => WatsonVoice::SvSeIngrid,
+            Language::ZhCn => WatsonVoice::ZhCnLiNa,
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = ParseLanguageError;
+
+    /// Parses a language from the id that the server uses for it, for example `en-US`. The case of the id is ignored, as the service reports some languages in lowercase
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ar-ms" => Ok(Language::ArMs),
+            "cs-cz" => Ok(Language::CsCz),
+            "de-de" => Ok(Language::DeDe),
+            "en-au" => Ok(Language::EnAu),
+            "en-gb" => Ok(Language::EnGb),
+            "en-us" => Ok(Language::EnUs),
+            "es-es" => Ok(Language::EsEs),
+            "es-la" => Ok(Language::EsLa),
+            "es-us" => Ok(Language::EsUs),
+            "fr-ca" => Ok(Language::FrCa),
+            "fr-fr" => Ok(Language::FrFr),
+            "it-it" => Ok(Language::ItIt),
+            "ja-jp" => Ok(Language::JaJp),
+            "ko-kr" => Ok(Language::KoKr),
+            "nl-be" => Ok(Language::NlBe),
+            "nl-nl" => Ok(Language::NlNl),
+            "pt-br" => Ok(Language::PtBr),
+            "sv-se" => Ok(Language::SvSe),
+            "zh-cn" => Ok(Language::ZhCn),
+            _ => Err(ParseLanguageError::UnknownLanguage(s.to_owned())),
+        }
+    }
 }
 
-impl TextToSpeech<'_> {
+impl TextToSpeech {
     /// Creates a new empty custom model. You must specify a name for the new custom model. You can optionally specify the language and a description for the new model. The model is owned by the instance of the service whose credentials are used to create it
     ///
     /// # Parameters
@@ -140,13 +339,17 @@ impl TextToSpeech<'_> {
     /// ```
     /// [`None`]: std::option::Option::None
     /// [`default language`]: self::Language::EnUs
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn create_custom_model(
         &self,
         name: impl AsRef<str>,
         language: Option<Language>,
         description: Option<impl AsRef<str>>,
     ) -> Result<Model, CreateModelError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path("v1/customizations");
         #[derive(Serialize, Deserialize)]
         struct FormBody<'a> {
@@ -166,27 +369,20 @@ impl TextToSpeech<'_> {
             description: &description,
         };
         let client = self.get_client();
-        let response = client
-            .post(url)
-            .json(&form_body)
-            .version(if cfg!(feature = "http2") {
-                Version::HTTP_2
-            } else {
-                Version::default()
-            })
-            .send()
-            .await?;
+        let request = client.post(url).json(&form_body);
+        let response = client.send(request).await?;
         match response.status() {
             StatusCode::OK => {
-                let root: Model = response.json().await.unwrap();
+                let root: Model = response
+                    .json()
+                    .await
+                    .map_err(|e| CreateModelError::DeserializationError(e.to_string()))?;
                 Ok(root)
             }
-            StatusCode::BAD_REQUEST => Err(CreateModelError::BadRequest400),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(CreateModelError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(CreateModelError::ServiceUnavailable503),
-            _ => Err(CreateModelError::UnmappedResponse(
-                response.status().as_u16(),
+            StatusCode::BAD_REQUEST => Err(CreateModelError::BadRequest400(
+                WatsonError::from_response(response).await,
             )),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
@@ -195,36 +391,44 @@ impl TextToSpeech<'_> {
     /// # Parameters
     ///
     /// * `language` - The language for which custom models that are owned by the requesting credentials are to be returned. Pass [`None`] to see all custom models that are owned by the requester
+    /// * `sort` - How to [`sort`] the models. The service does not guarantee any order, so the models are sorted after they are received. Pass [`None`] to keep the order in which the service returned them
     ///
     /// # Example
     /// ``` no_run
     /// # use ibm_watson::{
     /// #     auth::IamAuthenticator,
-    /// #     tts::{voices::WatsonVoice, TextToSpeech},
+    /// #     tts::{customisations::{SortBy, SortOrder}, TextToSpeech},
     /// # };
     /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
     /// # let auth = IamAuthenticator::new("api_key").await?;
     /// # let tts = TextToSpeech::new(&auth, "service_url");
-    /// let models = tts.list_custom_models(None).await?;
+    /// let models = tts
+    ///     .list_custom_models(None, Some(SortBy::Name(SortOrder::Ascending)))
+    ///     .await?;
     /// println!("found: {:#?} models", models.len());
     /// # Ok(())
     /// # }
     /// ```
     /// [`None`]: std::option::Option::None
     /// [`language`]: self::Language
+    /// [`sort`]: self::SortBy
     /// [`get_custom_model()`]: Self::get_custom_model()
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn list_custom_models(
         &self,
         language: Option<Language>,
+        sort: Option<SortBy>,
     ) -> Result<Vec<Model>, ListModelError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path("v1/customizations");
-        url.set_query(Some(&language.unwrap_or_default().id()));
-        let mut req = Request::new(Method::GET, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
+        if let Some(language) = language {
+            url.query_pairs_mut()
+                .append_pair("language", &language.id());
         }
+        let req = Request::new(Method::GET, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
@@ -234,16 +438,62 @@ impl TextToSpeech<'_> {
                 struct Root {
                     customizations: Vec<Model>,
                 }
-                let root: Root = response.json().await.unwrap();
+                let mut root: Root = response
+                    .json()
+                    .await
+                    .map_err(|e| ListModelError::DeserializationError(e.to_string()))?;
+                if let Some(sort) = sort {
+                    sort.sort(&mut root.customizations);
+                }
                 Ok(root.customizations)
             }
-            StatusCode::BAD_REQUEST => Err(ListModelError::BadRequest400),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(ListModelError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(ListModelError::ServiceUnavailable503),
-            _ => Err(ListModelError::UnmappedResponse(response.status().as_u16())),
+            StatusCode::BAD_REQUEST => Err(ListModelError::BadRequest400(
+                WatsonError::from_response(response).await,
+            )),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
+    /// Lists a window of the custom models that are owned by an instance of the service, along with the total number of models. The service does not paginate this list: every model is fetched with [`list_custom_models()`] and the window is taken afterwards. The models are ordered by the time at which they were created, oldest first, so that offsets stay stable as new models are added
+    ///
+    /// # Parameters
+    ///
+    /// * `language` - The language for which custom models that are owned by the requesting credentials are to be returned. Pass [`None`] to see all custom models that are owned by the requester
+    /// * `offset` - The number of models to skip
+    /// * `limit` - The maximum number of models to return
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let page = tts.list_custom_models_page(None, 20, 10).await?;
+    /// println!("showing {} of {} models", page.models.len(), page.total);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`list_custom_models()`]: Self::list_custom_models()
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn list_custom_models_page(
+        &self,
+        language: Option<Language>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<ModelPage, ListModelError> {
+        let models = self
+            .list_custom_models(language, Some(SortBy::Created(SortOrder::Ascending)))
+            .await?;
+        Ok(ModelPage::new(models, offset, limit))
+    }
+
     /// Updates information for the specified custom model. You can update metadata such as the
     /// name and description of the model. You can also update the words in the model and their
     /// translations. Adding a new translation for a word that already exists in a custom model
@@ -274,6 +524,10 @@ impl TextToSpeech<'_> {
     /// [`name`]: crate::tts::customisations::Model::name
     /// [`description`]: crate::tts::customisations::Model::description
     /// [`Word`]: crate::tts::customisations::Word
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn update_custom_model(
         &self,
         customisation_id: impl AsRef<str>,
@@ -281,7 +535,7 @@ impl TextToSpeech<'_> {
         description: Option<&str>,
         words: Option<&[Word]>,
     ) -> Result<(), UpdateModelError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path(&format!("v1/customizations/{}", customisation_id.as_ref()));
         #[derive(Deserialize, Serialize)]
         struct Foo<'a> {
@@ -307,27 +561,17 @@ impl TextToSpeech<'_> {
         }
         let data = Foo::new(name, description, words);
         let client = self.get_client();
-        let response = client
-            .post(url)
-            .json(&data)
-            .version(if cfg!(feature = "http2") {
-                Version::HTTP_2
-            } else {
-                Version::default()
-            })
-            .send()
-            .await?;
+        let request = client.post(url).json(&data);
+        let response = client.send(request).await?;
         match response.status() {
             StatusCode::OK => Ok(()),
-            StatusCode::BAD_REQUEST => Err(UpdateModelError::BadRequest400),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(UpdateModelError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(UpdateModelError::ServiceUnavailable503),
+            StatusCode::BAD_REQUEST => Err(UpdateModelError::BadRequest400(
+                WatsonError::from_response(response).await,
+            )),
             StatusCode::UNAUTHORIZED => Err(UpdateModelError::Unauthorised401(
                 customisation_id.as_ref().to_owned(),
             )),
-            _ => Err(UpdateModelError::UnmappedResponse(
-                response.status().as_u16(),
-            )),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
@@ -336,6 +580,7 @@ impl TextToSpeech<'_> {
     /// # Parameters
     ///
     /// * `customisation_id` - The customisation ID (GUID) of the custom model. You must make the request with credentials for the instance of the service that owns the custom model
+    /// * `if_modified_since` - If set, the service returns [`NotModified304`] instead of the model when it has not changed since that time. Use it to cheaply poll for changes
     ///
     /// # Example
     /// ``` no_run
@@ -346,45 +591,83 @@ impl TextToSpeech<'_> {
     /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
     /// # let auth = IamAuthenticator::new("api_key").await?;
     /// # let tts = TextToSpeech::new(&auth, "service_url");
-    /// let model = tts.get_custom_model("cust-id").await?;
+    /// let model = tts.get_custom_model("cust-id", None).await?;
     /// println!("{:#?}", model);
     /// # Ok(())
     /// # }
     /// ```
     /// [`language`]: self::Language
     /// [`list_custom_models()`]: Self::list_custom_models()
+    /// [`NotModified304`]: super::errors::GetModelError::NotModified304
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn get_custom_model(
         &self,
         customisation_id: impl AsRef<str>,
+        if_modified_since: Option<SystemTime>,
     ) -> Result<Model, GetModelError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path(&format!("v1/customizations/{}", customisation_id.as_ref()));
         let mut req = Request::new(Method::GET, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        ServiceClient::if_modified_since(&mut req, if_modified_since);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
         match response.status() {
             StatusCode::OK => {
-                let root: Model = response.json().await.unwrap();
+                let root: Model = response
+                    .json()
+                    .await
+                    .map_err(|e| GetModelError::DeserializationError(e.to_string()))?;
                 Ok(root)
             }
             StatusCode::BAD_REQUEST => Err(GetModelError::BadRequest400(
                 customisation_id.as_ref().to_owned(),
             )),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(GetModelError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(GetModelError::ServiceUnavailable503),
             StatusCode::NOT_MODIFIED => Err(GetModelError::NotModified304),
             StatusCode::UNAUTHORIZED => Err(GetModelError::Unauthorised401(
                 customisation_id.as_ref().to_owned(),
             )),
-            _ => Err(GetModelError::UnmappedResponse(response.status().as_u16())),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
+    /// Summarises the contents of a custom model, by fetching it with [`get_custom_model()`] and counting its words and prompts. See [`ModelStats`] for the limit on the size of a model
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom model. You must make the request with credentials for the instance of the service that owns the custom model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let stats = tts.custom_model_stats("cust-id").await?;
+    /// println!("{} words, {} prompts", stats.word_count, stats.prompt_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`get_custom_model()`]: Self::get_custom_model()
+    /// [`ModelStats`]: self::ModelStats
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn custom_model_stats(
+        &self,
+        customisation_id: impl AsRef<str>,
+    ) -> Result<ModelStats, GetModelError> {
+        let model = self.get_custom_model(customisation_id, None).await?;
+        Ok(model.stats())
+    }
+
     /// Deletes the specified custom model. You must use credentials for the instance of the service that owns a model to delete it.
     ///
     /// # Parameters
@@ -408,17 +691,17 @@ impl TextToSpeech<'_> {
     /// ```
     /// [`language`]: self::Language
     /// [`list_custom_models()`]: Self::list_custom_models()
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn delete_custom_model(
         &self,
         customisation_id: impl AsRef<str>,
     ) -> Result<(), DeleteModelError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path(&format!("v1/customizations/{}", customisation_id.as_ref()));
-        let mut req = Request::new(Method::DELETE, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let req = Request::new(Method::DELETE, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
@@ -427,14 +710,10 @@ impl TextToSpeech<'_> {
             StatusCode::BAD_REQUEST => Err(DeleteModelError::BadRequest400(
                 customisation_id.as_ref().to_owned(),
             )),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(DeleteModelError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(DeleteModelError::ServiceUnavailable503),
             StatusCode::UNAUTHORIZED => Err(DeleteModelError::Unauthorised401(
                 customisation_id.as_ref().to_owned(),
             )),
-            _ => Err(DeleteModelError::UnmappedResponse(
-                response.status().as_u16(),
-            )),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 }
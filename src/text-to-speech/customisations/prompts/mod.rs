@@ -1,19 +1,27 @@
-use std::path::Path;
+use std::{path::Path, time::Duration};
 
 use reqwest::{
     header::{HeaderValue, CONTENT_TYPE},
     multipart::{Form, Part},
-    Method, Request, StatusCode, Url, Version,
+    Method, Request, StatusCode,
 };
 use serde::{Deserialize, Serialize};
 use tokio::io::{AsyncReadExt, BufReader};
 
-use crate::tts::TextToSpeech;
+use crate::{
+    error::CommonError,
+    tts::{wav, TextToSpeech},
+};
+
+use super::errors::{
+    AddPromptError, DeletePromptError, GetPromptError, ListPromptsError, WaitForPromptError,
+};
 
-use super::errors::{AddPromptError, DeletePromptError, GetPromptError, ListPromptsError};
+/// The longest prompt audio that the service accepts
+const MAX_PROMPT_DURATION: Duration = Duration::from_secs(30);
 
 #[derive(Clone, Debug, Eq, PartialEq, Default, Serialize, Deserialize)]
-struct OuterPrompt {
+pub(crate) struct OuterPrompt {
     #[serde(rename = "prompt")]
     pub prompt: String,
     #[serde(rename = "prompt_id")]
@@ -53,19 +61,20 @@ pub enum PromptStatus {
     Available,
     /// The service's validation of the prompt failed. The status of the prompt includes an error field that describes the reason for the failure.
     Failed,
+    /// A status that this crate does not know about yet, as sent by the service
+    Unknown(String),
 }
 
 impl From<OuterPrompt> for Prompt {
     fn from(prompt: OuterPrompt) -> Self {
-        let status = match prompt.status {
-            Some(ref val) => match val.as_str() {
-                "processing" => Some(PromptStatus::Processing),
-                "available" => Some(PromptStatus::Available),
-                "failed" => Some(PromptStatus::Failed),
-                _ => unreachable!("prompt status"),
-            },
-            None => None,
-        };
+        let status = prompt
+            .status
+            .map(|status| match status.to_ascii_lowercase().as_str() {
+                "processing" => PromptStatus::Processing,
+                "available" => PromptStatus::Available,
+                "failed" => PromptStatus::Failed,
+                _ => PromptStatus::Unknown(status),
+            });
         Self {
             prompt: prompt.prompt,
             prompt_id: prompt.prompt_id,
@@ -76,7 +85,7 @@ impl From<OuterPrompt> for Prompt {
     }
 }
 
-impl TextToSpeech<'_> {
+impl TextToSpeech {
     /// Lists information about all custom prompts that are defined for a custom [`model`]. The information includes the [`prompt ID`], [`prompt text`], [`status`], and
     /// optional [`speaker ID`] for each prompt of the custom model. You must use credentials for the instance of the service that owns the custom model.
     /// The same information about all of the prompts for a custom model is also provided by [`get_custom_model()`]. That method provides complete details about a specified
@@ -112,20 +121,20 @@ impl TextToSpeech<'_> {
     /// [`owner`]: crate::tts::customisations::Model::owner
     /// [`custom words`]: crate::tts::customisations::Model::words
     ///
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn list_custom_prompts(
         &self,
         customisation_id: impl AsRef<str>,
     ) -> Result<Vec<Prompt>, ListPromptsError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path(&format!(
             "v1/customizations/{}/prompts",
             customisation_id.as_ref()
         ));
-        let mut req = Request::new(Method::GET, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let req = Request::new(Method::GET, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
@@ -133,17 +142,16 @@ impl TextToSpeech<'_> {
             StatusCode::OK => {
                 #[derive(Deserialize, Serialize)]
                 struct Root {
-                    prompts: Vec<Prompt>,
+                    prompts: Vec<OuterPrompt>,
                 }
-                let root: Root = response.json().await.unwrap();
-                Ok(root.prompts)
+                let root: Root = response
+                    .json()
+                    .await
+                    .map_err(|e| ListPromptsError::DeserializationError(e.to_string()))?;
+                Ok(root.prompts.into_iter().map(Prompt::from).collect())
             }
             StatusCode::BAD_REQUEST => Err(ListPromptsError::BadRequest400),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(ListPromptsError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(ListPromptsError::ServiceUnavailable503),
-            _ => Err(ListPromptsError::UnmappedResponse(
-                response.status().as_u16(),
-            )),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
@@ -156,6 +164,7 @@ impl TextToSpeech<'_> {
     /// * `audio_file` - An audio file that speaks the text of the prompt with intonation and prosody that matches how you would like the prompt to be spoken
     ///     * The prompt audio must be in WAV format and must have a minimum sampling rate of 16 kHz. The service accepts audio with higher sampling rates. The service transcodes all audio to 16 kHz before processing it
     ///     * The length of the prompt audio is limited to 30 seconds
+    ///     * The header of the audio is checked against these limits before it is sent, and [`InvalidAudio`] is returned if it does not meet them
     ///
     /// # Example
     /// ``` no_run
@@ -179,6 +188,7 @@ impl TextToSpeech<'_> {
     /// ```
     ///
     /// [`model`]: crate::tts::customisations::Model
+    /// [`InvalidAudio`]: crate::tts::customisations::errors::AddPromptError::InvalidAudio
     /// [`prompt ID`]: crate::tts::customisations::Prompt::prompt_id
     /// [`prompt text`]: crate::tts::customisations::Prompt::prompt
     /// [`status`]: crate::tts::customisations::Prompt::status
@@ -188,6 +198,10 @@ impl TextToSpeech<'_> {
     /// [`owner`]: crate::tts::customisations::Model::owner
     /// [`custom words`]: crate::tts::customisations::Model::words
     ///
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn add_custom_prompt(
         &self,
         customisation_id: impl AsRef<str>,
@@ -214,11 +228,69 @@ impl TextToSpeech<'_> {
             .await
             .map_err(|e| AddPromptError::FileReadError(e.to_string()))?;
 
-        let mut url = Url::parse(self.service_url).unwrap();
+        self.add_prompt_audio(customisation_id.as_ref(), prompt, buffer, file_name)
+            .await
+    }
+
+    /// Adds a custom prompt to a custom [`model`] like [`add_custom_prompt()`], from audio that is already in memory, for example audio recorded from a microphone. Nothing is written to disk
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom model. You must make the request with credentials for the instance of the service that owns the custom model
+    /// * `prompt` - The prompt that is to be added to the custom model
+    /// * `audio` - The audio of the prompt, as the bytes of a WAV file. See [`add_custom_prompt()`] for its requirements
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{TextToSpeech, customisations::Prompt},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// # let recording: Vec<u8> = Vec::new();
+    /// let prompt = Prompt {
+    ///     prompt: String::from("foo"),
+    ///     prompt_id: String::from("bar"),
+    ///     ..Default::default()
+    /// };
+    /// let _ = tts
+    ///     .add_custom_prompt_from_bytes("cust-id", &prompt, recording)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`model`]: crate::tts::customisations::Model
+    /// [`add_custom_prompt()`]: Self::add_custom_prompt()
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn add_custom_prompt_from_bytes(
+        &self,
+        customisation_id: impl AsRef<str>,
+        prompt: &Prompt,
+        audio: impl Into<Vec<u8>>,
+    ) -> Result<Prompt, AddPromptError> {
+        let file_name = format!("{}.wav", prompt.prompt_id);
+        self.add_prompt_audio(customisation_id.as_ref(), prompt, audio.into(), file_name)
+            .await
+    }
+
+    async fn add_prompt_audio(
+        &self,
+        customisation_id: &str,
+        prompt: &Prompt,
+        buffer: Vec<u8>,
+        file_name: String,
+    ) -> Result<Prompt, AddPromptError> {
+        wav::validate(&buffer, MAX_PROMPT_DURATION).map_err(AddPromptError::InvalidAudio)?;
+        let mut url = self.service_url.clone();
         url.set_path(&format!(
             "v1/customizations/{}/prompts/{}",
-            customisation_id.as_ref(),
-            prompt.prompt_id
+            customisation_id, prompt.prompt_id
         ));
         let forms;
         let form = Form::new()
@@ -231,34 +303,103 @@ impl TextToSpeech<'_> {
             forms = form;
         };
         let client = self.get_client();
-        let response = client
+        let request = client
             .post(url)
             .header(
                 CONTENT_TYPE,
                 HeaderValue::from_static("multipart/form-data"),
             )
-            .version(if cfg!(feature = "http2") {
-                Version::HTTP_2
-            } else {
-                Version::default()
-            })
-            .multipart(forms)
-            .send()
-            .await
-            .unwrap();
+            .multipart(forms);
+        let response = client.send(request).await?;
         match response.status() {
-            StatusCode::CREATED => Ok(response.json().await.unwrap()),
+            StatusCode::CREATED => {
+                let root: OuterPrompt = response
+                    .json()
+                    .await
+                    .map_err(|e| AddPromptError::DeserializationError(e.to_string()))?;
+                Ok(Prompt::from(root))
+            }
             StatusCode::BAD_REQUEST => Err(AddPromptError::BadRequest400),
             StatusCode::UNAUTHORIZED => Err(AddPromptError::Unauthorised401(
-                customisation_id.as_ref().to_string(),
+                customisation_id.to_string(),
             )),
             StatusCode::UNSUPPORTED_MEDIA_TYPE => Err(AddPromptError::UnsupportedMediaType415),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(AddPromptError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(AddPromptError::ServiceUnavailable503),
-            _ => Err(AddPromptError::UnmappedResponse(response.status().as_u16())),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
+    /// Adds a custom prompt to a custom [`model`] like [`add_custom_prompt()`], then waits for the service to finish processing it with [`wait_for_prompt()`]. The returned prompt's [`status`] is always [`Available`]; if the service fails to process the prompt, the error it reported is returned as [`Failed`]
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom model. You must make the request with credentials for the instance of the service that owns the custom model
+    /// * `prompt` - The prompt that is to be added to the custom model
+    /// * `audio_file` - An audio file that speaks the text of the prompt. See [`add_custom_prompt()`] for its requirements
+    /// * `poll_interval` - How long to wait between requests for the prompt's status
+    /// * `timeout` - How long to wait for the prompt to be processed before giving up
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use std::time::Duration;
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{voices::WatsonVoice, TextToSpeech,
+    /// #     customisations::Prompt},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let file_path = std::path::Path::new("/home/user/audio.wav");
+    /// let prompt = Prompt {
+    ///     prompt: String::from("foo"),
+    ///     prompt_id: String::from("bar"),
+    ///     ..Default::default()
+    /// };
+    /// let prompt = tts
+    ///     .add_custom_prompt_and_wait(
+    ///         "cust-id",
+    ///         &prompt,
+    ///         &file_path,
+    ///         Duration::from_secs(2),
+    ///         Duration::from_secs(60),
+    ///     )
+    ///     .await?;
+    /// println!("{:#?}", prompt.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`model`]: crate::tts::customisations::Model
+    /// [`add_custom_prompt()`]: Self::add_custom_prompt()
+    /// [`wait_for_prompt()`]: Self::wait_for_prompt()
+    /// [`status`]: crate::tts::customisations::Prompt::status
+    /// [`Available`]: crate::tts::customisations::PromptStatus::Available
+    /// [`Failed`]: crate::tts::customisations::errors::AddPromptError::Failed
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn add_custom_prompt_and_wait(
+        &self,
+        customisation_id: impl AsRef<str>,
+        prompt: &Prompt,
+        audio_file: impl AsRef<Path>,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Prompt, AddPromptError> {
+        let added = self
+            .add_custom_prompt(customisation_id.as_ref(), prompt, audio_file)
+            .await?;
+        self.wait_for_prompt(
+            customisation_id.as_ref(),
+            &added.prompt_id,
+            poll_interval,
+            timeout,
+        )
+        .await
+        .map_err(AddPromptError::from)
+    }
+
     /// Gets information about a specified custom prompt for a specified custom model. The information includes the prompt ID, prompt text, status, and optional speaker ID for each prompt of the custom model. You must use credentials for the instance of the service that owns the custom model. Custom prompts are supported only for use with US English custom models and voices
     ///
     /// # Parameters
@@ -281,39 +422,107 @@ impl TextToSpeech<'_> {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn get_custom_prompt(
         &self,
         customisation_id: impl AsRef<str>,
         prompt_id: impl AsRef<str>,
     ) -> Result<Prompt, GetPromptError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path(&format!(
             "v1/customizations/{}/prompts/{}",
             customisation_id.as_ref(),
             prompt_id.as_ref()
         ));
-        let mut req = Request::new(Method::GET, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let req = Request::new(Method::GET, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
         match response.status() {
             StatusCode::OK => {
-                let root: Prompt = response.json().await.unwrap();
-                Ok(root)
+                let root: OuterPrompt = response
+                    .json()
+                    .await
+                    .map_err(|e| GetPromptError::DeserializationError(e.to_string()))?;
+                Ok(Prompt::from(root))
             }
             StatusCode::BAD_REQUEST => Err(GetPromptError::BadRequest400(
                 customisation_id.as_ref().to_owned(),
             )),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(GetPromptError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(GetPromptError::ServiceUnavailable503),
             StatusCode::UNAUTHORIZED => Err(GetPromptError::Unauthorised401(
                 customisation_id.as_ref().to_owned(),
             )),
-            _ => Err(GetPromptError::UnmappedResponse(response.status().as_u16())),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Waits for a custom prompt to be processed, polling [`get_custom_prompt()`] until its [`status`] is [`Available`] or [`Failed`]. A prompt is [`Processing`] for a while after it has been added with [`add_custom_prompt()`]
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom model. You must make the request with credentials for the instance of the service that owns the custom model
+    /// * `prompt_id` - The identifier (name) of the prompt
+    /// * `poll_interval` - How long to wait between requests
+    /// * `timeout` - How long to wait in total before giving up with a [`Timeout`] error
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use std::time::Duration;
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{voices::WatsonVoice, TextToSpeech},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let prompt = tts
+    ///     .wait_for_prompt(
+    ///         "cust-id",
+    ///         "prompt_id",
+    ///         Duration::from_secs(2),
+    ///         Duration::from_secs(60),
+    ///     )
+    ///     .await?;
+    /// println!("{:#?}", prompt);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`get_custom_prompt()`]: Self::get_custom_prompt()
+    /// [`add_custom_prompt()`]: Self::add_custom_prompt()
+    /// [`status`]: crate::tts::customisations::Prompt::status
+    /// [`Available`]: crate::tts::customisations::PromptStatus::Available
+    /// [`Failed`]: crate::tts::customisations::PromptStatus::Failed
+    /// [`Processing`]: crate::tts::customisations::PromptStatus::Processing
+    /// [`Timeout`]: crate::tts::customisations::errors::WaitForPromptError::Timeout
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn wait_for_prompt(
+        &self,
+        customisation_id: impl AsRef<str>,
+        prompt_id: impl AsRef<str>,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Prompt, WaitForPromptError> {
+        let start = tokio::time::Instant::now();
+        loop {
+            let prompt = self
+                .get_custom_prompt(customisation_id.as_ref(), prompt_id.as_ref())
+                .await?;
+            match prompt.status {
+                Some(PromptStatus::Available) => return Ok(prompt),
+                Some(PromptStatus::Failed) => {
+                    return Err(WaitForPromptError::Failed(prompt.error.unwrap_or_default()))
+                }
+                _ if start.elapsed() + poll_interval > timeout => {
+                    return Err(WaitForPromptError::Timeout(timeout))
+                }
+                _ => tokio::time::sleep(poll_interval).await,
+            }
         }
     }
 
@@ -321,8 +530,8 @@ impl TextToSpeech<'_> {
     ///
     /// # Parameters
     ///
-    /// `customisation_id` - The customisation ID (GUID) of the custom model. You must make the request with credentials for the instance of the service that owns the custom model
-    /// `prompt_id` - The identifier (name) of the prompt that is to be deleted
+    /// * `customisation_id` - The customisation ID (GUID) of the custom model. You must make the request with credentials for the instance of the service that owns the custom model
+    /// * `prompt_id` - The identifier (name) of the prompt that is to be deleted
     ///
     /// # Example
     /// ``` no_run
@@ -340,22 +549,22 @@ impl TextToSpeech<'_> {
     /// # }
     /// ```
     /// [`model`]: crate::tts::customisations::Model
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn delete_custom_prompt(
         &self,
         customisation_id: impl AsRef<str>,
         prompt_id: impl AsRef<str>,
     ) -> Result<(), DeletePromptError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path(&format!(
             "v1/customizations/{}/prompts/{}",
             customisation_id.as_ref(),
             prompt_id.as_ref()
         ));
-        let mut req = Request::new(Method::DELETE, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let req = Request::new(Method::DELETE, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
@@ -364,15 +573,11 @@ impl TextToSpeech<'_> {
             StatusCode::BAD_REQUEST => Err(DeletePromptError::BadRequest400(
                 customisation_id.as_ref().to_owned(),
             )),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(DeletePromptError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(DeletePromptError::ServiceUnavailable503),
             StatusCode::UNAUTHORIZED => Err(DeletePromptError::Unauthorised401(
                 customisation_id.as_ref().to_owned(),
                 prompt_id.as_ref().to_string(),
             )),
-            _ => Err(DeletePromptError::UnmappedResponse(
-                response.status().as_u16(),
-            )),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 }
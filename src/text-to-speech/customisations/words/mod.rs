@@ -1,12 +1,18 @@
 use reqwest::{
     header::{HeaderValue, CONTENT_TYPE},
-    Body, Method, Request, StatusCode, Url, Version,
+    Body, Method, Request, StatusCode, Url,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::tts::TextToSpeech;
+use crate::{error::CommonError, tts::TextToSpeech};
 
-use super::errors::{AddWordError, DeleteWordError, GetWordError, ListWordsError};
+use super::{
+    errors::{
+        AddWordError, DeleteWordError, GetWordError, ListWordsError, ParsePartOfSpeechError,
+        SetPartOfSpeechError,
+    },
+    Language, SortOrder,
+};
 
 #[derive(Clone, Debug, Eq, PartialEq, Default, Serialize, Deserialize)]
 /// Defines words and their translations to be used in custom [`models`]
@@ -14,17 +20,206 @@ use super::errors::{AddWordError, DeleteWordError, GetWordError, ListWordsError}
 /// [`models`]: crate::tts::customisations::Model
 pub struct Word {
     /// the word for the custom model. the maximum length of a word is 49 characters.
-    #[serde(rename = "word")]
+    #[serde(rename = "word", default)]
     pub word: String,
     /// the phonetic or sounds-like translation for the word. a phonetic translation is based on the ssml format for representing the phonetic string of a word either as an ipa or ibm spr translation. the arabic, chinese, dutch, australian english, and korean languages support only ipa. a sounds-like translation consists of one or more words that, when combined, sound like the word. the maximum length of a translation is 499 characters.
     #[serde(rename = "translation")]
     pub translation: String,
     /// japanese only. the part of speech for the word. the service uses the value to produce the correct intonation for the word. you can create only a single entry, with or without a single part of speech, for any word; you cannot create multiple entries with different parts of speech for the same word.
     #[serde(rename = "part_of_speech", skip_serializing_if = "Option::is_none")]
-    pub part_of_speech: Option<String>,
+    pub part_of_speech: Option<PartOfSpeech>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+/// The parts of speech that Japanese custom models support, by their JEITA code. Convert a code
+/// with [`TryFrom`], which rejects codes that the service does not support. A code that the service
+/// returns but this crate does not know about is kept as [`Unknown`]
+///
+/// # Example
+/// ```
+/// # use ibm_watson::tts::customisations::PartOfSpeech;
+/// let noun = PartOfSpeech::try_from("Mesi")?;
+/// assert_eq!(noun, PartOfSpeech::Noun);
+/// assert_eq!(noun.id(), "Mesi");
+/// assert!(PartOfSpeech::try_from("Noun").is_err());
+/// # Ok::<(), ibm_watson::tts::customisations::errors::ParsePartOfSpeechError>(())
+/// ```
+///
+/// [`Unknown`]: Self::Unknown
+pub enum PartOfSpeech {
+    /// `Dosi`, a verb
+    Verb,
+    /// `Fuku`, an adverb
+    Adverb,
+    /// `Gobi`, an inflectional ending
+    InflectionalEnding,
+    /// `Hoka`, any other part of speech
+    Other,
+    /// `Jodo`, an auxiliary verb
+    AuxiliaryVerb,
+    /// `Josi`, a particle
+    Particle,
+    /// `Kato`, an interjection
+    Interjection,
+    /// `Kedo`, an adjectival noun
+    AdjectivalNoun,
+    /// `Keyo`, an adjective
+    Adjective,
+    /// `Kigo`, a symbol
+    Symbol,
+    /// `Koyu`, a proper noun
+    ProperNoun,
+    /// `Mesi`, a noun
+    Noun,
+    /// `Reta`, a prenominal adjective
+    PrenominalAdjective,
+    /// `Stbi`, a suffix
+    Suffix,
+    /// `Stto`, a prefix
+    Prefix,
+    /// `Stzo`, a conjunction
+    Conjunction,
+    /// `Suji`, a number
+    Number,
+    /// A part of speech that this crate does not know about yet, as sent by the service
+    Unknown(String),
+}
+
+impl PartOfSpeech {
+    /// Every part of speech that the service supports, in the alphabetical order of their codes
+    pub const ALL: [PartOfSpeech; 17] = [
+        PartOfSpeech::Verb,
+        PartOfSpeech::Adverb,
+        PartOfSpeech::InflectionalEnding,
+        PartOfSpeech::Other,
+        PartOfSpeech::AuxiliaryVerb,
+        PartOfSpeech::Particle,
+        PartOfSpeech::Interjection,
+        PartOfSpeech::AdjectivalNoun,
+        PartOfSpeech::Adjective,
+        PartOfSpeech::Symbol,
+        PartOfSpeech::ProperNoun,
+        PartOfSpeech::Noun,
+        PartOfSpeech::PrenominalAdjective,
+        PartOfSpeech::Suffix,
+        PartOfSpeech::Prefix,
+        PartOfSpeech::Conjunction,
+        PartOfSpeech::Number,
+    ];
+
+    /// The JEITA code that the server uses for the part of speech
+    pub fn id(&self) -> &str {
+        match self {
+            PartOfSpeech::Verb => "Dosi",
+            PartOfSpeech::Adverb => "Fuku",
+            PartOfSpeech::InflectionalEnding => "Gobi",
+            PartOfSpeech::Other => "Hoka",
+            PartOfSpeech::AuxiliaryVerb => "Jodo",
+            PartOfSpeech::Particle => "Josi",
+            PartOfSpeech::Interjection => "Kato",
+            PartOfSpeech::AdjectivalNoun => "Kedo",
+            PartOfSpeech::Adjective => "Keyo",
+            PartOfSpeech::Symbol => "Kigo",
+            PartOfSpeech::ProperNoun => "Koyu",
+            PartOfSpeech::Noun => "Mesi",
+            PartOfSpeech::PrenominalAdjective => "Reta",
+            PartOfSpeech::Suffix => "Stbi",
+            PartOfSpeech::Prefix => "Stto",
+            PartOfSpeech::Conjunction => "Stzo",
+            PartOfSpeech::Number => "Suji",
+            PartOfSpeech::Unknown(code) => code,
+        }
+    }
+}
+
+impl std::fmt::Display for PartOfSpeech {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+impl From<PartOfSpeech> for String {
+    fn from(part_of_speech: PartOfSpeech) -> Self {
+        match part_of_speech {
+            PartOfSpeech::Unknown(code) => code,
+            part_of_speech => part_of_speech.id().to_owned(),
+        }
+    }
+}
+
+impl Serialize for PartOfSpeech {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for PartOfSpeech {
+    /// Unlike [`TryFrom`], codes that this crate does not know about are kept as
+    /// [`Unknown`](Self::Unknown), so that one new code does not fail a whole listing
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Ok(PartOfSpeech::try_from(code.as_str()).unwrap_or(PartOfSpeech::Unknown(code)))
+    }
 }
 
-impl TextToSpeech<'_> {
+impl TryFrom<&str> for PartOfSpeech {
+    type Error = ParsePartOfSpeechError;
+
+    /// Parses a part of speech from its JEITA code, for example `Mesi`. The code is case sensitive
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        PartOfSpeech::ALL
+            .into_iter()
+            .find(|part_of_speech| part_of_speech.id() == code)
+            .ok_or_else(|| ParsePartOfSpeechError::UnknownPartOfSpeech(code.to_owned()))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The field by which the words of a custom model are sorted. Unlike the words of a Speech To Text
+/// custom model, the service keeps no count of how often a word occurs, so they cannot be sorted by
+/// count; they can be sorted by their translation instead
+pub enum WordSort {
+    /// Sort by the word itself. This is the order in which the service returns them
+    Alphabetical(SortOrder),
+    /// Sort by the translation of the word
+    Translation(SortOrder),
+}
+
+impl WordSort {
+    /// Sorts `words` in place. The sort is stable, so words that compare equal keep their order
+    pub fn sort(&self, words: &mut [Word]) {
+        words.sort_by(|a, b| {
+            let (ordering, order) = match self {
+                WordSort::Alphabetical(order) => (a.word.cmp(&b.word), order),
+                WordSort::Translation(order) => (a.translation.cmp(&b.translation), order),
+            };
+            match order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+/// A window of the words of a custom model
+pub struct WordPage {
+    /// The words in the window
+    pub words: Vec<Word>,
+    /// The total number of words in the model, including the ones outside the window
+    pub total: usize,
+}
+
+impl WordPage {
+    pub(crate) fn new(words: Vec<Word>, offset: usize, limit: usize) -> Self {
+        let total = words.len();
+        let words = words.into_iter().skip(offset).take(limit).collect();
+        Self { words, total }
+    }
+}
+
+impl TextToSpeech {
     /// Adds one or more words and their translations to the specified custom [`model`]. Adding a new translation for a word that already exists in a custom model overwrites the word's existing translation. A custom model can contain no more than 20,000 entries. You must use credentials for the instance of the service that owns a model to add words to it.
     ///
     /// # Parameters
@@ -59,12 +254,16 @@ impl TextToSpeech<'_> {
     /// [`model`]: crate::tts::customisations::Model
     /// [`Words`]: self::Word
     /// [`AddWordError`]: super::errors::AddWordError
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn add_custom_words(
         &self,
         customisation_id: impl AsRef<str>,
         words: &[Word],
     ) -> Result<(), AddWordError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         Self::set_words_path(&mut url, &customisation_id);
         #[derive(Serialize, Deserialize)]
         struct FormBody {
@@ -83,10 +282,6 @@ impl TextToSpeech<'_> {
             .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
         *req.body_mut() = Some(Body::from(body));
 
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
-
         let client = self.get_client();
         let response = client.execute(req).await?;
         match response.status() {
@@ -95,9 +290,7 @@ impl TextToSpeech<'_> {
             StatusCode::UNAUTHORIZED => Err(AddWordError::Unauthorised401(
                 customisation_id.as_ref().to_owned(),
             )),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(AddWordError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(AddWordError::ServiceUnavailable503),
-            _ => Err(AddWordError::UnmappedResponse(response.status().as_u16())),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
@@ -125,17 +318,17 @@ impl TextToSpeech<'_> {
     /// [`model`]: crate::tts::customisations::Model
     /// [`Words`]: self::Word
     /// [`Error`]: super::errors::ListWordsError
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn list_custom_words(
         &self,
         customisation_id: impl AsRef<str>,
     ) -> Result<Vec<Word>, ListWordsError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         Self::set_words_path(&mut url, &customisation_id);
-        let mut req = Request::new(Method::GET, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let req = Request::new(Method::GET, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
@@ -145,19 +338,114 @@ impl TextToSpeech<'_> {
                 struct Root {
                     words: Vec<Word>,
                 }
-                let root: Root = response.json().await.unwrap();
+                let root: Root = response
+                    .json()
+                    .await
+                    .map_err(|e| ListWordsError::DeserializationError(e.to_string()))?;
                 Ok(root.words)
             }
             StatusCode::BAD_REQUEST => Err(ListWordsError::BadRequest400),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(ListWordsError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(ListWordsError::ServiceUnavailable503),
             StatusCode::UNAUTHORIZED => Err(ListWordsError::Unauthorised401(
                 customisation_id.as_ref().to_owned(),
             )),
-            _ => Err(ListWordsError::UnmappedResponse(response.status().as_u16())),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
+    /// Lists a window of the words of the specified custom model, along with the total number of words, optionally in a different order. The service does not paginate or sort this list on request: every word is fetched with [`list_custom_words()`] in one response, and the sort and window are applied afterwards
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom [`model`]. You must make the request with credentials for the instance of the service that owns the custom model
+    /// * `sort` - How the words are to be [`sorted`] before the window is taken. If [`None`] is passed, they keep the alphabetical order of the service
+    /// * `offset` - The number of words to skip
+    /// * `limit` - The maximum number of words to return
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{customisations::{SortOrder, WordSort}, TextToSpeech},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let sort = WordSort::Alphabetical(SortOrder::Descending);
+    /// let page = tts
+    ///     .list_custom_words_page("customisation_id", Some(sort), 0, 50)
+    ///     .await?;
+    /// println!("showing {} of {} words", page.words.len(), page.total);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`list_custom_words()`]: Self::list_custom_words()
+    /// [`model`]: crate::tts::customisations::Model
+    /// [`sorted`]: self::WordSort
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn list_custom_words_page(
+        &self,
+        customisation_id: impl AsRef<str>,
+        sort: Option<WordSort>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<WordPage, ListWordsError> {
+        let mut words = self.list_custom_words(customisation_id).await?;
+        if let Some(sort) = sort {
+            sort.sort(&mut words);
+        }
+        Ok(WordPage::new(words, offset, limit))
+    }
+
+    /// Finds a word in the specified custom model, ignoring case, so that `ibm` finds `IBM`. Unlike [`get_custom_word()`], every word is fetched with [`list_custom_words()`] and searched afterwards, since the service only looks words up by their exact spelling
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom [`model`]. You must make the request with credentials for the instance of the service that owns the custom model
+    /// * `needle` - The word to look for
+    ///
+    /// # Returns
+    ///
+    /// The first word that matches `needle` in the alphabetical order of the service, or [`None`] if there is none
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// if let Some(word) = tts.find_word("customisation_id", "ibm").await? {
+    ///     println!("{} is read as {}", word.word, word.translation);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`get_custom_word()`]: Self::get_custom_word()
+    /// [`list_custom_words()`]: Self::list_custom_words()
+    /// [`model`]: crate::tts::customisations::Model
+    /// [`None`]: std::option::Option::None
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn find_word(
+        &self,
+        customisation_id: impl AsRef<str>,
+        needle: impl AsRef<str>,
+    ) -> Result<Option<Word>, ListWordsError> {
+        let needle = needle.as_ref().to_lowercase();
+        let words = self.list_custom_words(customisation_id).await?;
+        Ok(words
+            .into_iter()
+            .find(|word| word.word.to_lowercase() == needle))
+    }
+
     /// Adds a single [`word`] and its translation to the specified custom model. Adding a new translation for a word that already exists in a custom model overwrites the word's existing translation. A custom model can contain no more than 20,000 entries. You must use credentials for the instance of the service that owns a model to add a word to it
     ///
     /// # Parameters
@@ -187,12 +475,16 @@ impl TextToSpeech<'_> {
     /// [`Words`]: self::Word
     /// [`word`]: self::Word
     /// [`model`]: crate::tts::customisations::Model
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn add_custom_word(
         &self,
         customisation_id: impl AsRef<str>,
         word: &Word,
     ) -> Result<(), AddWordError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path(&format!(
             "v1/customizations/{}/words/{}",
             customisation_id.as_ref(),
@@ -205,7 +497,7 @@ impl TextToSpeech<'_> {
             translation: String,
             /// japanese only. the part of speech for the word. the service uses the value to produce the correct intonation for the word. you can create only a single entry, with or without a single part of speech, for any word; you cannot create multiple entries with different parts of speech for the same word.
             #[serde(rename = "part_of_speech", skip_serializing_if = "Option::is_none")]
-            part_of_speech: Option<String>,
+            part_of_speech: Option<PartOfSpeech>,
         }
         impl FormBody {
             fn new(words: &Word) -> Self {
@@ -221,10 +513,6 @@ impl TextToSpeech<'_> {
             .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
         *req.body_mut() = Some(Body::from(body));
 
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
-
         let client = self.get_client();
         let response = client.execute(req).await?;
         match response.status() {
@@ -233,9 +521,7 @@ impl TextToSpeech<'_> {
             StatusCode::UNAUTHORIZED => Err(AddWordError::Unauthorised401(
                 customisation_id.as_ref().to_owned(),
             )),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(AddWordError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(AddWordError::ServiceUnavailable503),
-            _ => Err(AddWordError::UnmappedResponse(response.status().as_u16())),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
@@ -264,37 +550,38 @@ impl TextToSpeech<'_> {
     /// [`Words`]: self::Word
     /// [`word`]: self::Word
     /// [`model`]: crate::tts::customisations::Model
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn get_custom_word(
         &self,
         customisation_id: impl AsRef<str>,
         word: impl AsRef<str>,
     ) -> Result<Word, GetWordError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path(&format!(
             "v1/customizations/{}/words/{}",
             customisation_id.as_ref(),
             word.as_ref()
         ));
-        let mut req = Request::new(Method::GET, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let req = Request::new(Method::GET, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
         match response.status() {
             StatusCode::OK => {
-                let root: Word = response.json().await.unwrap();
+                let root: Word = response
+                    .json()
+                    .await
+                    .map_err(|e| GetWordError::DeserializationError(e.to_string()))?;
                 Ok(root)
             }
             StatusCode::BAD_REQUEST => Err(GetWordError::BadRequest400),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(GetWordError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(GetWordError::ServiceUnavailable503),
             StatusCode::UNAUTHORIZED => Err(GetWordError::Unauthorised401(
                 customisation_id.as_ref().to_owned(),
             )),
-            _ => Err(GetWordError::UnmappedResponse(response.status().as_u16())),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
@@ -323,22 +610,22 @@ impl TextToSpeech<'_> {
     /// [`Words`]: self::Word
     /// [`word`]: self::Word
     /// [`model`]: crate::tts::customisations::Model
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn delete_custom_word(
         &self,
         customisation_id: impl AsRef<str>,
         word: impl AsRef<str>,
     ) -> Result<(), DeleteWordError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path(&format!(
             "v1/customizations/{}/words/{}",
             customisation_id.as_ref(),
             word.as_ref()
         ));
-        let mut req = Request::new(Method::DELETE, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let req = Request::new(Method::DELETE, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
@@ -347,15 +634,61 @@ impl TextToSpeech<'_> {
             StatusCode::BAD_REQUEST => Err(DeleteWordError::BadRequest400(
                 customisation_id.as_ref().to_owned(),
             )),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(DeleteWordError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(DeleteWordError::ServiceUnavailable503),
             StatusCode::UNAUTHORIZED => Err(DeleteWordError::Unauthorised401(
                 customisation_id.as_ref().to_owned(),
             )),
-            _ => Err(DeleteWordError::UnmappedResponse(
-                response.status().as_u16(),
-            )),
+            _ => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+
+    /// Changes the part of speech of a word that already exists in a Japanese custom model, keeping its current translation. The word is fetched with [`get_custom_word()`] and stored again with the new part of speech
+    ///
+    /// # Parameters
+    ///
+    /// * `customisation_id` - The customisation ID (GUID) of the custom [`model`]. The model must be a Japanese model
+    /// * `word` - The word whose part of speech is to be changed
+    /// * `pos` - The new part of speech. It must be the JEITA code of a [`PartOfSpeech`], for example `Mesi`
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// tts.set_word_part_of_speech("customisation_id", "IBM", "Mesi")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`get_custom_word()`]: Self::get_custom_word()
+    /// [`model`]: crate::tts::customisations::Model
+    /// [`PartOfSpeech`]: self::PartOfSpeech
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn set_word_part_of_speech(
+        &self,
+        customisation_id: impl AsRef<str>,
+        word: impl AsRef<str>,
+        pos: &str,
+    ) -> Result<(), SetPartOfSpeechError> {
+        let pos = PartOfSpeech::try_from(pos)
+            .map_err(|_| SetPartOfSpeechError::InvalidPartOfSpeech(pos.to_owned()))?;
+        let model = self.get_custom_model(&customisation_id, None).await?;
+        let language = model.language.unwrap_or_default();
+        if language != Language::JaJp.id() {
+            return Err(SetPartOfSpeechError::UnsupportedLanguage(language));
         }
+        let mut current = self.get_custom_word(&customisation_id, &word).await?;
+        // the service does not echo the word itself back
+        current.word = word.as_ref().to_owned();
+        current.part_of_speech = Some(pos);
+        self.add_custom_word(&customisation_id, &current).await?;
+        Ok(())
     }
 
     fn set_words_path(uri: &mut Url, customisation_id: impl AsRef<str>) {
@@ -1,12 +1,23 @@
-use reqwest::{
-    header::{HeaderMap, HeaderValue, AUTHORIZATION},
-    Client, ClientBuilder,
+use reqwest::{Client, Url};
+
+use crate::{
+    auth::IamAuthenticator,
+    client::{parse_service_url, ServiceClient},
+    error::{PingError, ServiceError},
 };
 
-use crate::auth::IamAuthenticator;
+use self::{
+    customisations::errors::ParseLanguageError,
+    voices::{voices_url, WatsonVoice},
+};
 
-use self::voices::WatsonVoice;
+mod builder;
+pub use builder::TextToSpeechBuilder;
 
+/// A blocking Text To Speech client
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
 /// Manage custom Prompts, Words, Models
 pub mod customisations;
 /// Manage word pronunciation
@@ -14,6 +25,8 @@ pub mod pronunciation;
 #[path = "speaker-models/mod.rs"]
 /// Manage speaker models
 pub mod speaker_models;
+/// Build and escape SSML input for synthesis
+pub mod ssml;
 /// Synthesise text to speech
 pub mod synthesis;
 #[path = "user-data/mod.rs"]
@@ -21,22 +34,25 @@ pub mod synthesis;
 pub mod user_data;
 /// View information about Watson voices
 pub mod voices;
+pub(crate) mod wav;
 
-/// Creates a client used to send requests to your Text To Speech endpoint
-pub struct TextToSpeech<'a> {
-    service_url: &'a str,
+/// Creates a client used to send requests to your Text To Speech endpoint. It owns its
+/// configuration and is cheap to clone, so it can be shared across tasks
+#[derive(Clone)]
+pub struct TextToSpeech {
+    service_url: Url,
     voice: WatsonVoice,
-    client: Client,
+    client: ServiceClient,
 }
 
-impl<'a> TextToSpeech<'a> {
+impl TextToSpeech {
     /// Create a new Text To Speech instance. This instance will be used to make all the requests
     /// to the text to speech service.
     ///
     /// # Parameters
     /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
     /// * `service_url` - The endpoint for your text to speech instance. All Text To Speech
-    /// requests will be made to this endpoint
+    ///   requests will be made to this endpoint
     ///
     /// # Examples
     /// ``` no_run
@@ -47,30 +63,153 @@ impl<'a> TextToSpeech<'a> {
     /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
     /// let auth = IamAuthenticator::new("api_key").await?;
     /// let tts = TextToSpeech::new(&auth, "service_url");
-    /// let voice = tts.get_voice(WatsonVoice::EnGbCharlotteV3, None).await?;
+    /// let voice = tts.get_voice(WatsonVoice::EnGbCharlotteV3, None, None).await?;
     /// # Ok(())
     /// # }
     /// ```
     ///
+    /// # Panics
+    ///
+    /// Panics if `service_url` is not an absolute `http` or `https` url. Use [`try_new()`] to
+    /// handle an invalid url instead
+    ///
     /// [`IamAuthenticator`]: super::auth::IamAuthenticator
-    pub fn new(authenticator: &'a IamAuthenticator, service_url: &'a str) -> Self {
-        let client = ClientBuilder::new();
-        let default_headers = Self::default_headers(authenticator.token_response().access_token());
-        let client = client.default_headers(default_headers);
+    /// [`try_new()`]: Self::try_new()
+    pub fn new(authenticator: &IamAuthenticator, service_url: impl Into<String>) -> Self {
+        Self::builder(authenticator, service_url).build()
+    }
 
-        #[cfg(feature = "http2")]
-        let client = ClientBuilder::use_rustls_tls(client);
+    /// Create a new Text To Speech instance like [`new()`], but return [`InvalidUrl`] instead of
+    /// panicking if `service_url` is not an absolute `http` or `https` url
+    ///
+    /// # Parameters
+    /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
+    /// * `service_url` - The endpoint for your text to speech instance. All Text To Speech
+    ///   requests will be made to this endpoint
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// let auth = IamAuthenticator::new("api_key").await?;
+    /// let tts = TextToSpeech::try_new(&auth, "https://api.eu-gb.text-to-speech.watson.cloud.ibm.com")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`IamAuthenticator`]: super::auth::IamAuthenticator
+    /// [`new()`]: Self::new()
+    /// [`InvalidUrl`]: crate::error::ServiceError::InvalidUrl
+    pub fn try_new(
+        authenticator: &IamAuthenticator,
+        service_url: impl Into<String>,
+    ) -> Result<Self, ServiceError> {
+        Self::builder(authenticator, service_url).try_build()
+    }
 
-        #[cfg(feature = "http2")]
-        let client = client.http2_prior_knowledge();
+    /// Configure a new Text To Speech instance, for example to set a request timeout, tune the
+    /// connection pool or choose a default voice. See [`TextToSpeechBuilder`] for the available
+    /// options
+    ///
+    /// # Parameters
+    /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
+    /// * `service_url` - The endpoint for your text to speech instance. All Text To Speech
+    ///   requests will be made to this endpoint
+    ///
+    /// [`IamAuthenticator`]: super::auth::IamAuthenticator
+    /// [`TextToSpeechBuilder`]: self::TextToSpeechBuilder
+    pub fn builder(
+        authenticator: &IamAuthenticator,
+        service_url: impl Into<String>,
+    ) -> TextToSpeechBuilder<'_> {
+        TextToSpeechBuilder::new(authenticator, service_url)
+    }
 
-        let client = client.build().unwrap();
+    /// Create a new Text To Speech instance that sends its requests with a preconfigured
+    /// [`Client`]. Use this to control timeouts, proxies or connection pooling yourself. The
+    /// authentication headers are added to every request, so the client does not need them
+    ///
+    /// # Parameters
+    /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
+    /// * `service_url` - The endpoint for your text to speech instance. All Text To Speech
+    ///   requests will be made to this endpoint
+    /// * `client` - The [`Client`] to send requests with
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// let auth = IamAuthenticator::new("api_key").await?;
+    /// let client = reqwest::Client::builder()
+    ///     .timeout(std::time::Duration::from_secs(30))
+    ///     .build()?;
+    /// let tts = TextToSpeech::with_client(&auth, "service_url", client);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `service_url` is not an absolute `http` or `https` url. Use
+    /// [`try_with_client()`] to handle an invalid url instead
+    ///
+    /// [`IamAuthenticator`]: super::auth::IamAuthenticator
+    /// [`Client`]: reqwest::Client
+    /// [`try_with_client()`]: Self::try_with_client()
+    pub fn with_client(
+        authenticator: &IamAuthenticator,
+        service_url: impl Into<String>,
+        client: Client,
+    ) -> Self {
+        Self::try_with_client(authenticator, service_url, client).unwrap_or_else(|e| panic!("{e}"))
+    }
 
-        Self {
+    /// Create a new Text To Speech instance like [`with_client()`], but return [`InvalidUrl`] instead
+    /// of panicking if `service_url` is not an absolute `http` or `https` url
+    ///
+    /// # Parameters
+    /// * `authenticator` - The [`IamAuthenticator`] containing your IAM Access Token
+    /// * `service_url` - The endpoint for your text to speech instance. All Text To Speech
+    ///   requests will be made to this endpoint
+    /// * `client` - The [`Client`] to send requests with
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// let auth = IamAuthenticator::new("api_key").await?;
+    /// let client = reqwest::Client::new();
+    /// let tts = TextToSpeech::try_with_client(&auth, "https://api.eu-gb.text-to-speech.watson.cloud.ibm.com", client)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`IamAuthenticator`]: super::auth::IamAuthenticator
+    /// [`Client`]: reqwest::Client
+    /// [`with_client()`]: Self::with_client()
+    /// [`InvalidUrl`]: crate::error::ServiceError::InvalidUrl
+    pub fn try_with_client(
+        authenticator: &IamAuthenticator,
+        service_url: impl Into<String>,
+        client: Client,
+    ) -> Result<Self, ServiceError> {
+        let service_url = parse_service_url(&service_url.into())?;
+        let client =
+            ServiceClient::with_client(authenticator.token_response().access_token(), client);
+        Ok(Self {
             service_url,
             voice: WatsonVoice::default(),
             client,
-        }
+        })
     }
 
     /// Change the default voice to use for Text To Speech requests
@@ -99,15 +238,139 @@ impl<'a> TextToSpeech<'a> {
         self.voice = voice;
     }
 
-    pub(crate) fn get_client(&self) -> Client {
-        self.client.clone()
+    /// Change the default voice to the one that [`default_for_language()`] picks for a language
+    ///
+    /// # Parameters
+    ///
+    /// * `lang` - The language code, for example `en-US` or `en`
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// let mut tts = TextToSpeech::new(&auth, "service_url");
+    /// // for example, the language that the Language Translator identified
+    /// tts.set_voice_for_language("fr")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnknownLanguage`] and leaves the voice as it was if the service has no voices for `lang`
+    ///
+    /// [`default_for_language()`]: self::voices::WatsonVoice::default_for_language()
+    /// [`UnknownLanguage`]: self::customisations::errors::ParseLanguageError::UnknownLanguage
+    pub fn set_voice_for_language(&mut self, lang: &str) -> Result<(), ParseLanguageError> {
+        self.voice = WatsonVoice::default_for_language(lang)
+            .ok_or_else(|| ParseLanguageError::UnknownLanguage(lang.to_owned()))?;
+        Ok(())
+    }
+
+    /// The default voice that is used for Text To Speech requests, as set by [`set_voice()`] or the [`builder`]
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{voices::WatsonVoice, TextToSpeech},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// let mut tts = TextToSpeech::new(&auth, "service_url");
+    /// tts.set_voice(WatsonVoice::EnGbCharlotteV3);
+    /// assert_eq!(tts.voice().id(), "en-GB_CharlotteV3Voice");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`set_voice()`]: Self::set_voice()
+    /// [`builder`]: Self::builder()
+    pub fn voice(&self) -> &WatsonVoice {
+        &self.voice
+    }
+
+    /// Associate a customer ID with all the data that is sent to the service from now on, by passing it in the `X-Watson-Metadata` header. The ID is percent-encoded. Use [`delete_labeled_data()`] to delete all the data that is associated with it
+    ///
+    /// # Parameters
+    ///
+    /// * `customer_id` - The customer ID to associate with the data
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// let mut tts = TextToSpeech::new(&auth, "service_url");
+    /// tts.set_customer_id("my_customer_ID");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`delete_labeled_data()`]: Self::delete_labeled_data()
+    pub fn set_customer_id(&mut self, customer_id: impl AsRef<str>) {
+        self.client.set_customer_id(customer_id.as_ref());
+    }
+
+    /// Opt out of having the data of requests made from now on used to improve the service, by sending `X-Watson-Learning-Opt-Out: true` with every request. By default the service may use the data, which matches IBM's default
+    ///
+    /// # Parameters
+    ///
+    /// * `opt_out` - Whether to opt out. Pass `false` to stop sending the header
+    ///
+    /// # Examples
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// let mut tts = TextToSpeech::new(&auth, "service_url");
+    /// tts.set_learning_opt_out(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_learning_opt_out(&mut self, opt_out: bool) {
+        self.client.set_learning_opt_out(opt_out);
     }
 
-    fn default_headers(token: &str) -> HeaderMap<HeaderValue> {
-        let mut headers = HeaderMap::new();
-        let mut auth_value = HeaderValue::from_str(&format!("Bearer {}", token)).unwrap();
-        auth_value.set_sensitive(true);
-        headers.insert(AUTHORIZATION, auth_value);
-        headers
+    /// Checks that the service can be reached and that it accepts the credentials, for example as a health check before starting a long job. It lists the voices, which is the cheapest authenticated request, and gives up if the service has not responded within 5 seconds
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     error::PingError,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// let tts = TextToSpeech::new(&auth, "service_url");
+    /// match tts.ping().await {
+    ///     Ok(()) => println!("ready"),
+    ///     Err(PingError::Unauthorised401(e)) => println!("check the API key: {e}"),
+    ///     Err(e) => println!("the service cannot be reached: {e}"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn ping(&self) -> Result<(), PingError> {
+        self.client.ping(voices_url(&self.service_url)).await
+    }
+
+    pub(crate) fn get_client(&self) -> ServiceClient {
+        self.client.clone()
     }
 }
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::error::impl_from_common_error;
+
 #[derive(Error, Debug)]
 #[non_exhaustive]
 /// Errors that may be returned in pronunciation requests
@@ -8,7 +10,7 @@ pub enum PronunciationError {
     #[error("The requested resource has not been modified since the time specified by the If-Modified-Since header, as documented in the HTTP specification")]
     NotModified304,
     #[error("The request specified an Accept header with an incompatible content type.")]
-    /// The request specified an Accept header with an incompatible content type
+    /// The request specified an Accept header with an incompatible content type. Requests ask for `application/json`, so this means that the content negotiation failed, for example because a proxy or a preconfigured client changed the header
     NotAcceptable406,
     #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported")]
     /// A required input parameter is null or a specified input parameter or header value is invalid or not supported
@@ -30,7 +32,12 @@ pub enum PronunciationError {
     /// There was an error making the request
     #[error("There was an error establishing the connection")]
     ConnectionError(#[from] reqwest::Error),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response code the server returnes
     #[error("{0}")]
     UnmappedResponse(u16),
 }
+
+impl_from_common_error!(PronunciationError);
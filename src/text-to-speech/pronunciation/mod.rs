@@ -1,10 +1,18 @@
-use reqwest::{Method, Request, StatusCode, Url, Version};
+use futures_util::{stream, StreamExt, TryStreamExt};
+use reqwest::{Method, Request, StatusCode};
 use serde::{Deserialize, Serialize};
 pub mod errors;
 
 use self::errors::PronunciationError;
+use crate::error::CommonError;
 
 use super::{voices::WatsonVoice, TextToSpeech};
+
+/// How many pronunciation requests [`get_pronunciations()`] has in flight at once
+///
+/// [`get_pronunciations()`]: crate::tts::TextToSpeech::get_pronunciations()
+const CONCURRENT_PRONUNCIATIONS: usize = 4;
+
 #[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
 /// Holds the pronunciation of some text
 pub struct Pronunciation {
@@ -15,9 +23,84 @@ pub struct Pronunciation {
     /// [`format`]: self::PhonemeFormat
     /// [`model`]: crate::tts::customisations::Model
     pub pronunciation: String,
+    #[serde(skip)]
+    pub(crate) format: PhonemeFormat,
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+impl Pronunciation {
+    /// The [`format`] that the pronunciation is in, which is the one that was requested
+    ///
+    /// [`format`]: self::PhonemeFormat
+    pub fn format(&self) -> PhonemeFormat {
+        self.format
+    }
+
+    /// Splits the pronunciation into its symbols, dropping syllable boundaries and spaces
+    ///
+    /// * [`IPA`] is split into phones, each with any diacritics or length marks that follow it, and stress marks
+    /// * [`IBM`] SPR is split into syllables, each with its stress digit
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{pronunciation::PhonemeFormat, TextToSpeech},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let pronunciation = tts
+    ///     .get_pronunciation("world", None, Some(PhonemeFormat::IPA), None::<&str>)
+    ///     .await?;
+    /// // for example ["ˈ", "w", "ɝː", "l", "d"]
+    /// println!("{:?}", pronunciation.symbols());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`IPA`]: self::PhonemeFormat::IPA
+    /// [`IBM`]: self::PhonemeFormat::IBM
+    pub fn symbols(&self) -> Vec<String> {
+        let boundary = |c: char| c == '.' || c.is_whitespace();
+        match self.format {
+            PhonemeFormat::IBM => self
+                .pronunciation
+                .split(boundary)
+                .filter(|syllable| !syllable.is_empty())
+                .map(str::to_owned)
+                .collect(),
+            PhonemeFormat::IPA => {
+                let mut symbols: Vec<String> = Vec::new();
+                let mut tied = false;
+                for c in self.pronunciation.chars() {
+                    if boundary(c) {
+                        tied = false;
+                        continue;
+                    }
+                    let modifies = tied || is_ipa_modifier(c);
+                    tied = c == '\u{361}' || c == '\u{35c}';
+                    match symbols.last_mut() {
+                        Some(last) if modifies && !is_stress(last) => last.push(c),
+                        _ => symbols.push(c.to_string()),
+                    }
+                }
+                symbols
+            }
+        }
+    }
+}
+
+fn is_stress(symbol: &str) -> bool {
+    matches!(symbol, "ˈ" | "ˌ")
+}
+
+/// Whether `c` belongs to the phone before it, such as a combining diacritic or a length mark
+fn is_ipa_modifier(c: char) -> bool {
+    !matches!(c, 'ˈ' | 'ˌ')
+        && matches!(c, '\u{2b0}'..='\u{2ff}' | '\u{300}'..='\u{36f}' | '\u{1dc0}'..='\u{1dff}')
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 /// The phoneme format in which to return the pronunciation. The Arabic, Chinese, Dutch, Australian English, and Korean languages support only IPA
 pub enum PhonemeFormat {
     /// ibm
@@ -39,7 +122,7 @@ impl PhonemeFormat {
     }
 }
 
-impl TextToSpeech<'_> {
+impl TextToSpeech {
     /// Gets the phonetic [`Pronunciation`] for the specified word. You can request the pronunciation for a specific [`format`]. You can also request the pronunciation for a specific [`voice`] to see the default translation for the language of that voice or for a specific custom [`model`] to see the translation for that model.
     ///
     /// # Parameters
@@ -72,6 +155,10 @@ impl TextToSpeech<'_> {
     /// [`format`]: self::PhonemeFormat
     /// [`model`]: crate::tts::customisations::Model
     /// [`Pronunciation`]: self::Pronunciation
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn get_pronunciation(
         &self,
         text: impl AsRef<str>,
@@ -79,7 +166,7 @@ impl TextToSpeech<'_> {
         format: Option<PhonemeFormat>,
         customisation_id: Option<impl AsRef<str>>,
     ) -> Result<Pronunciation, PronunciationError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path("v1/pronunciation");
 
         url.query_pairs_mut()
@@ -96,17 +183,17 @@ impl TextToSpeech<'_> {
             url.query_pairs_mut()
                 .append_pair("customization_id", c_id.as_ref());
         }
-        let mut req = Request::new(Method::GET, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let req = Request::new(Method::GET, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
         match response.status() {
             StatusCode::OK => {
-                let root: Pronunciation = response.json().await.unwrap();
+                let mut root: Pronunciation = response
+                    .json()
+                    .await
+                    .map_err(|e| PronunciationError::DeserializationError(e.to_string()))?;
+                root.format = format.unwrap_or_default();
                 Ok(root)
             }
             StatusCode::NOT_ACCEPTABLE => Err(PronunciationError::NotAcceptable406),
@@ -114,12 +201,83 @@ impl TextToSpeech<'_> {
                 customisation_id.unwrap().as_ref().to_string(),
             )),
             StatusCode::NOT_FOUND => Err(PronunciationError::NotFound404),
-            StatusCode::SERVICE_UNAVAILABLE => Err(PronunciationError::ServiceUnavailable503),
             StatusCode::BAD_REQUEST => Err(PronunciationError::BadRequest400),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(PronunciationError::InternalServerError500),
-            _ => Err(PronunciationError::UnmappedResponse(
-                response.status().as_u16(),
-            )),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
+
+    /// Gets the phonetic [`Pronunciation`] of every word in a phrase, like calling [`get_pronunciation()`] for each of them. The text is split on whitespace and punctuation is stripped from the start and end of each word, so `"Hello, world!"` is looked up as `Hello` and `world`. Words made up only of punctuation are skipped
+    ///
+    /// This makes one request per word, with up to four of them in flight at once. If any of them fails, its error is returned and the other results are discarded
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The phrase for which the pronunciations are requested
+    /// * `voice` - A [`voice`] that specifies the language in which the pronunciations are to be returned. If [`None`], the voice you [`set`] for the service will be used. If none has been set, the [`default`] will be used
+    /// * `format` - The [`PhonemeFormat`] in which to return the pronunciations. Omit the parameter to obtain them in the default format
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] for which the pronunciations are to be returned. Omit the parameter to see the translations for the specified voice with no customisation
+    ///
+    /// # Returns
+    ///
+    /// Each word with its pronunciation, in the order in which the words appear in `text`
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{pronunciation::PhonemeFormat, TextToSpeech},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let pronunciations = tts
+    ///     .get_pronunciations("Hello, world!", None, Some(PhonemeFormat::IPA), None::<&str>)
+    ///     .await?;
+    /// for (word, pronunciation) in pronunciations {
+    ///     println!("{word}: {}", pronunciation.pronunciation);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`get_pronunciation()`]: Self::get_pronunciation()
+    /// [`None`]: std::option::Option::None
+    /// [`set`]: Self::set_voice()
+    /// [`voice`]: super::voices::WatsonVoice
+    /// [`default`]: super::voices::WatsonVoice::EnUsMichaelV3
+    /// [`PhonemeFormat`]: self::PhonemeFormat
+    /// [`model`]: crate::tts::customisations::Model
+    /// [`Pronunciation`]: self::Pronunciation
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn get_pronunciations(
+        &self,
+        text: impl AsRef<str>,
+        voice: Option<WatsonVoice>,
+        format: Option<PhonemeFormat>,
+        customisation_id: Option<impl AsRef<str>>,
+    ) -> Result<Vec<(String, Pronunciation)>, PronunciationError> {
+        let customisation_id = customisation_id.as_ref().map(AsRef::as_ref);
+        let voice = voice.as_ref();
+        stream::iter(words(text.as_ref()))
+            .map(|word| async move {
+                let pronunciation = self
+                    .get_pronunciation(word, voice.cloned(), format, customisation_id)
+                    .await?;
+                Ok((word.to_owned(), pronunciation))
+            })
+            .buffered(CONCURRENT_PRONUNCIATIONS)
+            .try_collect()
+            .await
+    }
+}
+
+/// The words of `text` with the punctuation around them stripped, in order. Punctuation within a
+/// word, such as the apostrophe in `don't`, is kept
+pub(crate) fn words(text: &str) -> Vec<&str> {
+    text.split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .collect()
 }
@@ -1,4 +1,7 @@
 use thiserror::Error;
+
+use crate::error::impl_from_common_error;
+
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum ListSpeakersError {
@@ -14,6 +17,9 @@ pub enum ListSpeakersError {
     /// The request failed. Possible failure causes include. Invalid service credentials were passed with the request
     #[error("The request failed. Possible failure causes include. Invalid service credentials were passed with the request")]
     BadRequest400,
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response code the server returnes
     #[error("{0}")]
     UnmappedResponse(u16),
@@ -22,6 +28,9 @@ pub enum ListSpeakersError {
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum CreateSpeakerError {
+    /// There was an error establishing the connection
+    #[error("There was an error establishing the connection")]
+    ConnectionError(#[from] reqwest::Error),
     /// The request failed. Possible failure causes include: The audio has a media type other than
     /// audio/wav or a sampling rate of less than 16 kHz, The audio is longer than 1 minute, The
     /// speaker name exceeds the 49-character limit or includes characters that are not
@@ -39,6 +48,10 @@ pub enum CreateSpeakerError {
     /// There was an error reading the file
     #[error("There was an error reading the file: {0}")]
     FileReadError(String),
+    /// The audio is not a PCM WAV file that is sampled at 16 kHz or more and is at most 1 minute
+    /// long, so it was not sent. Contains the reason
+    #[error("The audio cannot be used to create a speaker model: {0}")]
+    InvalidAudio(String),
     /// The request passed an unacceptable media type with the Content-Type header. The header must pass a value of multipart/form-data
     #[error("The request passed an unacceptable media type with the Content-Type header. The header must pass a value of multipart/form-data")]
     UnsupportedMediaType415,
@@ -70,6 +83,9 @@ pub enum GetSpeakerError {
     /// The requested resource has not been modified since the time specified by the If-Modified-Since header, as documented in the HTTP specification
     #[error("The requested resource has not been modified since the time specified by the If-Modified-Since header, as documented in the HTTP specification")]
     NotModified304,
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response code the server returnes
     #[error("{0}")]
     UnmappedResponse(u16),
@@ -97,3 +113,10 @@ pub enum DeleteSpeakerError {
     #[error("{0}")]
     UnmappedResponse(u16),
 }
+
+impl_from_common_error!(
+    ListSpeakersError,
+    CreateSpeakerError,
+    GetSpeakerError,
+    DeleteSpeakerError
+);
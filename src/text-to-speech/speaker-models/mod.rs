@@ -1,15 +1,21 @@
-use std::path::Path;
+use std::{path::Path, time::Duration};
 
 use reqwest::{
     header::{HeaderValue, CONTENT_TYPE},
-    Body, Method, Request, StatusCode, Url, Version,
+    Body, Method, Request, StatusCode, Url,
 };
 use serde::{Deserialize, Serialize};
 use tokio::io::{AsyncReadExt, BufReader};
 
 pub mod errors;
-use super::{customisations::Prompt, TextToSpeech};
+use crate::error::CommonError;
+
+use super::{customisations::Prompt, wav, TextToSpeech};
 use errors::*;
+
+/// The longest enrollment audio that the service accepts
+const MAX_ENROLLMENT_DURATION: Duration = Duration::from_secs(60);
+
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 /// Information about all speaker models for the service instance
 pub struct Speaker {
@@ -27,7 +33,7 @@ pub struct SpeakerCustomModel {
     pub prompts: Vec<Prompt>,
 }
 
-impl TextToSpeech<'_> {
+impl TextToSpeech {
     /// Lists information about all speaker models that are defined for a service instance. The information includes the speaker ID and speaker name of each defined speaker. You must use credentials for the instance of a service to list its speakers. Speaker models and the custom prompts with which they are used are supported only for use with US English custom models and voices.
     ///
     /// # Example
@@ -45,14 +51,14 @@ impl TextToSpeech<'_> {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn list_speaker_models(&self) -> Result<Vec<Speaker>, ListSpeakersError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         Self::set_speakers_path(&mut url);
-        let mut req = Request::new(Method::GET, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let req = Request::new(Method::GET, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
@@ -62,16 +68,15 @@ impl TextToSpeech<'_> {
                 struct Root {
                     voices: Vec<Speaker>,
                 }
-                let root: Root = response.json().await.unwrap();
+                let root: Root = response
+                    .json()
+                    .await
+                    .map_err(|e| ListSpeakersError::DeserializationError(e.to_string()))?;
 
                 Ok(root.voices)
             }
             StatusCode::BAD_REQUEST => Err(ListSpeakersError::BadRequest400),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(ListSpeakersError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(ListSpeakersError::ServiceUnavailable503),
-            _ => Err(ListSpeakersError::UnmappedResponse(
-                response.status().as_u16(),
-            )),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
@@ -79,6 +84,14 @@ impl TextToSpeech<'_> {
         uri.set_path("v1/speakers");
     }
 
+    pub(crate) fn create_speaker_url(service_url: &Url, speaker_name: &str) -> Url {
+        let mut url = service_url.clone();
+        Self::set_speakers_path(&mut url);
+        url.query_pairs_mut()
+            .append_pair("speaker_name", speaker_name);
+        url
+    }
+
     /// Creates a new speaker model, which is an optional enrollment token for users who are to add prompts to custom models. A speaker model contains information about a user's voice. The service extracts this information from a WAV audio sample that you pass as the body of the request. Associating a speaker model with a prompt is optional, but the information that is extracted from the speaker model helps the service learn about the speaker's voice
     ///
     /// # Parameters
@@ -90,10 +103,11 @@ impl TextToSpeech<'_> {
     /// * `audio_file` - An enrollment audio file that contains a sample of the speaker’s voice
     ///     * The enrollment audio must be in WAV format and must have a minimum sampling rate of 16 kHz. The service accepts audio with higher sampling rates. It transcodes all audio to 16 kHz before processing it
     ///     * The length of the enrollment audio is limited to 1 minute. Speaking one or two paragraphs of text that include five to ten sentences is recommended
+    ///     * The header of the audio is checked against these limits before it is sent, and [`InvalidAudio`] is returned if it does not meet them
     ///
     /// # Returns
     ///
-    /// The [`speaker_id`] for the newly created speaker
+    /// The newly created [`Speaker`]. The service only returns the [`speaker_id`], so the [`name`] is the `speaker_name` that was passed in
     ///
     /// # Example
     /// ``` no_run
@@ -106,17 +120,24 @@ impl TextToSpeech<'_> {
     /// # let auth = IamAuthenticator::new("api_key").await?;
     /// # let tts = TextToSpeech::new(&auth, "service_url");
     /// let file_path = std::path::Path::new("/home/user/audio.wav");
-    /// let speaker_id = tts.create_speaker_model("speaker_one", &file_path).await?;
-    /// println!("created speaker: {}", speaker_id);
+    /// let speaker = tts.create_speaker_model("speaker_one", &file_path).await?;
+    /// println!("created speaker: {}", speaker.speaker_id);
     /// # Ok(())
     /// # }
     /// ```
+    /// [`Speaker`]: crate::tts::speaker_models::Speaker
+    /// [`InvalidAudio`]: crate::tts::speaker_models::errors::CreateSpeakerError::InvalidAudio
     /// [`speaker_id`]: crate::tts::speaker_models::Speaker::speaker_id
+    /// [`name`]: crate::tts::speaker_models::Speaker::name
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn create_speaker_model(
         &self,
         speaker_name: impl AsRef<str>,
         audio_file: impl AsRef<Path>,
-    ) -> Result<String, CreateSpeakerError> {
+    ) -> Result<Speaker, CreateSpeakerError> {
         let wav_file = audio_file.as_ref();
         let file = tokio::fs::OpenOptions::new()
             .read(true)
@@ -131,39 +152,68 @@ impl TextToSpeech<'_> {
             .await
             .map_err(|e| CreateSpeakerError::FileReadError(e.to_string()))?;
 
-        let mut url = Url::parse(self.service_url).unwrap();
-        Self::set_speakers_path(&mut url);
-        url.set_query(Some(&format!("speaker_name={}", speaker_name.as_ref())));
-        let body = Body::from(buffer);
+        self.create_speaker_model_from_bytes(speaker_name, buffer)
+            .await
+    }
+
+    /// Creates a new speaker model like [`create_speaker_model()`], from enrollment audio that is already in memory, for example audio recorded from a microphone. Nothing is written to disk
+    ///
+    /// # Parameters
+    /// * `speaker_name` - The name of the speaker that is to be added to the service instance. See [`create_speaker_model()`] for its requirements
+    /// * `audio` - The enrollment audio, as the bytes of a WAV file. See [`create_speaker_model()`] for its requirements
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// # let recording: Vec<u8> = Vec::new();
+    /// let speaker = tts
+    ///     .create_speaker_model_from_bytes("speaker_one", recording)
+    ///     .await?;
+    /// println!("created speaker: {}", speaker.speaker_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`create_speaker_model()`]: Self::create_speaker_model()
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn create_speaker_model_from_bytes(
+        &self,
+        speaker_name: impl AsRef<str>,
+        audio: impl Into<Vec<u8>>,
+    ) -> Result<Speaker, CreateSpeakerError> {
+        let audio = audio.into();
+        wav::validate(&audio, MAX_ENROLLMENT_DURATION).map_err(CreateSpeakerError::InvalidAudio)?;
+        let url = Self::create_speaker_url(&self.service_url, speaker_name.as_ref());
+        let body = Body::from(audio);
         let client = self.get_client();
-        let response = client
+        let request = client
             .post(url)
             .header(CONTENT_TYPE, HeaderValue::from_static("audio/wav"))
-            .version(if cfg!(feature = "http2") {
-                Version::HTTP_2
-            } else {
-                Version::default()
-            })
-            .body(body)
-            .send()
-            .await
-            .unwrap();
+            .body(body);
+        let response = client.send(request).await?;
         match response.status() {
             StatusCode::CREATED => {
                 #[derive(Deserialize)]
-                struct Foo {
+                struct Created {
                     speaker_id: String,
                 }
-                let response: Foo = response.json().await.unwrap();
-                Ok(response.speaker_id)
+                let created: Created = response.json().await?;
+                Ok(Speaker {
+                    speaker_id: created.speaker_id,
+                    name: speaker_name.as_ref().to_owned(),
+                })
             }
             StatusCode::BAD_REQUEST => Err(CreateSpeakerError::BadRequest400),
             StatusCode::UNSUPPORTED_MEDIA_TYPE => Err(CreateSpeakerError::UnsupportedMediaType415),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(CreateSpeakerError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(CreateSpeakerError::ServiceUnavailable503),
-            _ => Err(CreateSpeakerError::UnmappedResponse(
-                response.status().as_u16(),
-            )),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
@@ -187,24 +237,26 @@ impl TextToSpeech<'_> {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn get_speaker_model(
         &self,
         speaker_id: impl AsRef<str>,
     ) -> Result<SpeakerCustomModel, GetSpeakerError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path(&format!("v1/speakers/{}", speaker_id.as_ref()));
-        let mut req = Request::new(Method::GET, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let req = Request::new(Method::GET, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
-        assert_eq!(response.status(), 200);
         match response.status() {
             StatusCode::OK => {
-                let root: SpeakerCustomModel = response.json().await.unwrap();
+                let root: SpeakerCustomModel = response
+                    .json()
+                    .await
+                    .map_err(|e| GetSpeakerError::DeserializationError(e.to_string()))?;
 
                 Ok(root)
             }
@@ -213,11 +265,7 @@ impl TextToSpeech<'_> {
                 speaker_id.as_ref().to_owned(),
             )),
             StatusCode::NOT_MODIFIED => Err(GetSpeakerError::NotModified304),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(GetSpeakerError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(GetSpeakerError::ServiceUnavailable503),
-            _ => Err(GetSpeakerError::UnmappedResponse(
-                response.status().as_u16(),
-            )),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 
@@ -242,17 +290,17 @@ impl TextToSpeech<'_> {
     /// # }
     /// ```
     /// [`model`]: crate::tts::customisations::Model
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn delete_speaker_model(
         &self,
         speaker_id: impl AsRef<str>,
     ) -> Result<(), DeleteSpeakerError> {
-        let mut url = Url::parse(self.service_url).unwrap();
+        let mut url = self.service_url.clone();
         url.set_path(&format!("v1/speakers/{}", speaker_id.as_ref()));
-        let mut req = Request::new(Method::DELETE, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let req = Request::new(Method::DELETE, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
@@ -261,15 +309,11 @@ impl TextToSpeech<'_> {
             StatusCode::BAD_REQUEST => Err(DeleteSpeakerError::BadRequest400(
                 speaker_id.as_ref().to_owned(),
             )),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(DeleteSpeakerError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(DeleteSpeakerError::ServiceUnavailable503),
             StatusCode::UNAUTHORIZED => Err(DeleteSpeakerError::Unauthorised401(
                 speaker_id.as_ref().to_owned(),
             )),
 
-            _ => Err(DeleteSpeakerError::UnmappedResponse(
-                response.status().as_u16(),
-            )),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 }
@@ -0,0 +1,137 @@
+use std::fmt::Write;
+
+use crate::tts::{pronunciation::PhonemeFormat, synthesis::SynthesisInput};
+
+use super::escape;
+
+/// Builds well-formed SSML for synthesis, one element at a time. All text is escaped, so the
+/// markup cannot be broken by the content that is spoken
+///
+/// # Example
+/// ```
+/// # use ibm_watson::tts::{pronunciation::PhonemeFormat, ssml};
+/// let ssml = ssml::Builder::new()
+///     .text("Your total is")
+///     .say_as("cardinal", "42")
+///     .break_ms(500)
+///     .prosody_rate("slow")
+///     .text("Thank you & goodbye")
+///     .build();
+/// assert_eq!(
+///     ssml,
+///     r#"<speak>Your total is<say-as interpret-as="cardinal">42</say-as><break time="500ms"/><prosody rate="slow">Thank you &amp; goodbye</prosody></speak>"#
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Builder {
+    ssml: String,
+    rate: Option<String>,
+    pitch: Option<String>,
+    prosody_open: bool,
+}
+
+impl Builder {
+    /// Starts an empty `<speak>` document
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds plain text, escaping any characters that have a special meaning in SSML
+    pub fn text(mut self, text: impl AsRef<str>) -> Self {
+        self.open_prosody();
+        self.ssml.push_str(&escape(text.as_ref()));
+        self
+    }
+
+    /// Adds a pause of `ms` milliseconds
+    pub fn break_ms(mut self, ms: u32) -> Self {
+        self.open_prosody();
+        let _ = write!(self.ssml, r#"<break time="{ms}ms"/>"#);
+        self
+    }
+
+    /// Speaks the content that is added from now on at `rate`, either a keyword such as `slow` or
+    /// `x-fast`, or a relative change such as `+10%`. It applies along with any pitch that has
+    /// been set, until it is changed
+    pub fn prosody_rate(mut self, rate: impl AsRef<str>) -> Self {
+        self.close_prosody();
+        self.rate = Some(escape(rate.as_ref()).into_owned());
+        self
+    }
+
+    /// Speaks the content that is added from now on at `pitch`, either a keyword such as `low` or
+    /// `x-high`, or a relative change such as `-5%`. It applies along with any rate that has
+    /// been set, until it is changed
+    pub fn prosody_pitch(mut self, pitch: impl AsRef<str>) -> Self {
+        self.close_prosody();
+        self.pitch = Some(escape(pitch.as_ref()).into_owned());
+        self
+    }
+
+    /// Adds `text` with a hint on how it is to be read, such as `letters`, `digits`, `cardinal`
+    /// or `date`
+    pub fn say_as(mut self, interpret_as: impl AsRef<str>, text: impl AsRef<str>) -> Self {
+        self.open_prosody();
+        let _ = write!(
+            self.ssml,
+            r#"<say-as interpret-as="{}">{}</say-as>"#,
+            escape(interpret_as.as_ref()),
+            escape(text.as_ref())
+        );
+        self
+    }
+
+    /// Adds `text`, pronounced as the phonetic spelling `ph` in the given [`alphabet`]
+    ///
+    /// [`alphabet`]: crate::tts::pronunciation::PhonemeFormat
+    pub fn phoneme(
+        mut self,
+        alphabet: PhonemeFormat,
+        ph: impl AsRef<str>,
+        text: impl AsRef<str>,
+    ) -> Self {
+        self.open_prosody();
+        let _ = write!(
+            self.ssml,
+            r#"<phoneme alphabet="{}" ph="{}">{}</phoneme>"#,
+            alphabet.id(),
+            escape(ph.as_ref()),
+            escape(text.as_ref())
+        );
+        self
+    }
+
+    /// The finished SSML document
+    pub fn build(mut self) -> String {
+        self.close_prosody();
+        format!("<speak>{}</speak>", self.ssml)
+    }
+
+    fn open_prosody(&mut self) {
+        if self.prosody_open || (self.rate.is_none() && self.pitch.is_none()) {
+            return;
+        }
+        self.ssml.push_str("<prosody");
+        if let Some(rate) = &self.rate {
+            let _ = write!(self.ssml, r#" rate="{rate}""#);
+        }
+        if let Some(pitch) = &self.pitch {
+            let _ = write!(self.ssml, r#" pitch="{pitch}""#);
+        }
+        self.ssml.push('>');
+        self.prosody_open = true;
+    }
+
+    fn close_prosody(&mut self) {
+        if self.prosody_open {
+            self.ssml.push_str("</prosody>");
+            self.prosody_open = false;
+        }
+    }
+}
+
+impl From<Builder> for SynthesisInput {
+    fn from(builder: Builder) -> Self {
+        SynthesisInput::Ssml(builder.build())
+    }
+}
@@ -0,0 +1,31 @@
+use std::borrow::Cow;
+
+mod builder;
+pub use builder::Builder;
+
+/// Escapes the characters that have a special meaning in SSML (`&`, `<`, `>`, `"` and `'`) so that
+/// `text` can be embedded in markup as plain text. Text that has nothing to escape is returned as is
+///
+/// # Example
+/// ```
+/// # use ibm_watson::tts::ssml;
+/// let ssml = format!("<speak>{}</speak>", ssml::escape("Fish & chips"));
+/// assert_eq!(ssml, "<speak>Fish &amp; chips</speak>");
+/// ```
+pub fn escape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(text);
+    }
+    let mut escaped = String::with_capacity(text.len() + 8);
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
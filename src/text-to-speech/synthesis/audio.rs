@@ -0,0 +1,106 @@
+use std::{
+    io::{self, Cursor, Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use bytes::Bytes;
+
+use super::{AudioFormat, SynthesisOutput};
+
+#[derive(Clone, Debug)]
+/// Synthesised audio that can be read like a file. It implements [`Read`] and [`Seek`], so it can
+/// be handed as is to audio libraries such as `rodio` or `symphonia`, or copied with
+/// [`std::io::copy()`]
+///
+/// # Example
+/// ```
+/// # use std::io::Read;
+/// # use ibm_watson::tts::synthesis::{SynthesisOutput, SynthesizedAudio};
+/// let mut audio = SynthesizedAudio::from(SynthesisOutput {
+///     bytes: "OggS".into(),
+///     content_type: "audio/ogg;codecs=opus".to_owned(),
+/// });
+/// let mut magic = [0; 4];
+/// audio.read_exact(&mut magic)?;
+/// assert_eq!(&magic, b"OggS");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// [`Read`]: std::io::Read
+/// [`Seek`]: std::io::Seek
+/// [`std::io::copy()`]: std::io::copy()
+pub struct SynthesizedAudio {
+    cursor: Cursor<Bytes>,
+    content_type: String,
+}
+
+impl SynthesizedAudio {
+    /// All of the audio, whatever has already been read
+    pub fn as_bytes(&self) -> &[u8] {
+        self.cursor.get_ref()
+    }
+
+    /// Consumes the audio and returns all of it, whatever has already been read
+    pub fn into_bytes(self) -> Bytes {
+        self.cursor.into_inner()
+    }
+
+    /// The `Content-Type` header of the response, for example `audio/ogg;codecs=opus`. Empty if the service did not send one
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    /// The [`AudioFormat`] that the service returned the audio in, parsed from [`content_type()`], or [`None`] if it is missing or not a format that this library knows about
+    ///
+    /// [`AudioFormat`]: super::AudioFormat
+    /// [`content_type()`]: Self::content_type()
+    /// [`None`]: std::option::Option::None
+    pub fn format(&self) -> Option<AudioFormat> {
+        AudioFormat::from_mime(&self.content_type)
+    }
+
+    /// Writes all of the audio to `path`, replacing the file if it exists. The position that the audio is read from is left as it is
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - The file that the audio is written to
+    pub fn write_to(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.as_bytes())
+    }
+}
+
+impl From<SynthesisOutput> for SynthesizedAudio {
+    fn from(output: SynthesisOutput) -> Self {
+        Self {
+            cursor: Cursor::new(output.bytes),
+            content_type: output.content_type,
+        }
+    }
+}
+
+impl From<SynthesizedAudio> for SynthesisOutput {
+    fn from(audio: SynthesizedAudio) -> Self {
+        Self {
+            bytes: audio.cursor.into_inner(),
+            content_type: audio.content_type,
+        }
+    }
+}
+
+impl AsRef<[u8]> for SynthesizedAudio {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Read for SynthesizedAudio {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.cursor.read(buf)
+    }
+}
+
+impl Seek for SynthesizedAudio {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.cursor.seek(pos)
+    }
+}
@@ -1,31 +1,78 @@
 use thiserror::Error;
 
+use crate::error::{impl_from_common_error, WatsonError};
+
 #[derive(Error, Debug)]
 #[non_exhaustive]
 /// Errors that may be returned in speech synethesis
 pub enum SynthesisError {
-    #[error("A required input parameter is null or a specified input parameter or header value is invalid")]
+    #[error("A required input parameter is null or a specified input parameter or header value is invalid: {0}")]
     ///  A required input parameter is null or a specified input parameter or header value is invalid. For example, prompt_id '{prompt_id}' not found in custom model '{customisation_id}' if you attempt to use a nonexistent or deleted custom prompt
-    BadRequest400,
-    #[error("The specified voice does not exist")]
+    BadRequest400(WatsonError),
+    #[error("The specified voice does not exist: {0}")]
     /// The specified voice does not exist or, for IBM Cloud Pak for Data, the voice parameter was not specified but the default voice is not installed. The message is Model '{voice}' not found
-    NotFound404,
-    #[error("The request specified an incompatible content type or failed to specify a required sampling rate")]
+    NotFound404(WatsonError),
+    #[error("The request specified an incompatible content type or failed to specify a required sampling rate: {0}")]
     /// The request specified an incompatible content type or failed to specify a required sampling rate
-    NotAcceptable406,
-    #[error("The request specified an unacceptable media type.")]
+    NotAcceptable406(WatsonError),
+    #[error("The request specified an unacceptable media type: {0}")]
     /// The request specified an unacceptable media type
-    UnsupportedMediaType415,
-    #[error("The service experienced an internal error.")]
+    UnsupportedMediaType415(WatsonError),
+    #[error("The service experienced an internal error: {0}")]
     /// The service experienced an internal error
-    InternalServerError500,
-    #[error("The service is currently unavailable.")]
+    InternalServerError500(WatsonError),
+    #[error("The service is currently unavailable: {0}")]
     /// The service is currently unavailable
-    ServiceUnavailable500,
+    ServiceUnavailable503(WatsonError),
+    /// The request was not sent because its parameters would be rejected by the service
+    #[error("The request parameters are invalid: {0}")]
+    InvalidParameter(String),
+    /// The requested audio format was not sent because the service would reject it
+    #[error("The audio format is invalid: {0}")]
+    InvalidFormat(#[from] AudioFormatError),
+    /// The audio that the service returned could not be processed. Contains the reason
+    #[error("The audio is invalid: {0}")]
+    InvalidAudio(String),
+    /// The voice does not support a feature that the request uses. Contains the name of the voice and of the feature
+    #[error("The voice {0} does not support {1}")]
+    UnsupportedFeature(String, String),
+    /// There was an error writing the audio to a file
+    #[error("There was an error writing the file: {0}")]
+    FileWriteError(String),
+    /// There was an error in the WebSocket connection, or the service reported an error over it
+    #[error("There was an error in the WebSocket connection: {0}")]
+    WebSocketError(String),
     /// There was an error making the request
     #[error("There was an error establishing the connection")]
     ConnectionError(#[from] reqwest::Error),
+    /// The request did not complete within the timeout that was given for it
+    #[error("The request timed out after {0:?}")]
+    Timeout(std::time::Duration),
     /// The response that the server sends back
     #[error("{0}")]
     UnmappedResponse(u16),
 }
+
+impl_from_common_error!(with_body: SynthesisError);
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// Errors that may be returned when validating an [`AudioFormat`]
+///
+/// [`AudioFormat`]: super::AudioFormat
+pub enum AudioFormatError {
+    /// The sample rate is not supported by the codec of the format
+    #[error("{format} does not support a sample rate of {rate} Hz, expected {expected}")]
+    UnsupportedSampleRate {
+        /// The MIME type of the format
+        format: &'static str,
+        /// The requested sample rate
+        rate: u16,
+        /// A description of the supported sample rates
+        expected: &'static str,
+    },
+    /// Audio in the format cannot be joined by appending it, so it cannot be synthesised in
+    /// chunks. Contains the file extension of the format
+    #[error("{0} audio cannot be joined, use wav, l16, alaw or mulaw audio")]
+    CannotConcatenate(String),
+}
@@ -0,0 +1,170 @@
+use bytes::Bytes;
+use url::form_urlencoded::byte_serialize;
+
+use super::{
+    errors::{AudioFormatError, SynthesisError},
+    AudioFormat,
+};
+use crate::tts::{wav, TextToSpeech};
+
+/// The most text that is sent in a single request, in bytes once it has been percent-encoded.
+/// The service accepts 5 KB of text, and the url of a `GET` request must stay under 8 KB
+const MAX_CHUNK_BYTES: usize = 5000;
+
+impl TextToSpeech {
+    /// Synthesises text that is too long for a single request, by splitting it into chunks on sentence boundaries, synthesising each of them in turn and joining the audio. The service rejects more than 5 KB of text in one request with a `400 Bad Request`
+    ///
+    /// This makes one request per chunk. Sentences that are longer than a chunk are split between words, and words that are longer still are split where they have to be
+    ///
+    /// Only audio that can be joined by appending it is supported: raw [`AudioL16`], [`AudioAlaw`] and [`AudioMulaw`] audio, and [`AudioWav`], whose header is rewritten to cover all of the samples. Other formats, such as MP3 or Ogg, wrap their audio in frames or pages that cannot simply be appended, so they are rejected with [`InvalidFormat`] before any request is made
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The plain text to synthesise. SSML cannot be split safely, so it is not accepted
+    /// * `format` - The requested [`AudioFormat`] of the audio, which must be one of the formats above
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. Omit the parameter to use the specified voice with no customisation
+    ///
+    /// [`AudioL16`]: crate::tts::synthesis::AudioFormat::AudioL16
+    /// [`AudioAlaw`]: crate::tts::synthesis::AudioFormat::AudioAlaw
+    /// [`AudioMulaw`]: crate::tts::synthesis::AudioFormat::AudioMulaw
+    /// [`AudioWav`]: crate::tts::synthesis::AudioFormat::AudioWav
+    /// [`InvalidFormat`]: crate::tts::synthesis::errors::SynthesisError::InvalidFormat
+    /// [`AudioFormat`]: crate::tts::synthesis::AudioFormat
+    /// [`model`]: crate::tts::customisations::Model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{synthesis::AudioFormat, TextToSpeech},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let chapter = std::fs::read_to_string("chapter.txt")?;
+    /// let format = AudioFormat::AudioWav { sample_rate: None };
+    /// let wav = tts.synthesise_long(&chapter, format, None).await?;
+    /// std::fs::write("chapter.wav", wav)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn synthesise_long(
+        &self,
+        text: impl AsRef<str>,
+        format: AudioFormat,
+        customisation_id: Option<&str>,
+    ) -> Result<Bytes, SynthesisError> {
+        let is_wav = match format {
+            AudioFormat::AudioL16 { .. }
+            | AudioFormat::AudioAlaw { .. }
+            | AudioFormat::AudioMulaw { .. } => false,
+            AudioFormat::AudioWav { .. } => true,
+            _ => {
+                return Err(
+                    AudioFormatError::CannotConcatenate(format.extension().to_owned()).into(),
+                )
+            }
+        };
+        format.validate()?;
+        let chunks = split_text(text.as_ref(), MAX_CHUNK_BYTES);
+        if chunks.is_empty() {
+            return Err(SynthesisError::InvalidParameter(
+                "There is no text to synthesise".to_owned(),
+            ));
+        }
+        let mut parts = Vec::with_capacity(chunks.len());
+        for chunk in chunks {
+            parts.push(
+                self.synthesise(chunk, Some(format), customisation_id)
+                    .await?,
+            );
+        }
+        if is_wav {
+            let joined = wav::concatenate(&parts).map_err(SynthesisError::InvalidAudio)?;
+            Ok(Bytes::from(joined))
+        } else {
+            Ok(Bytes::from(parts.concat()))
+        }
+    }
+}
+
+/// Splits `text` into chunks of at most `max` bytes once percent-encoded, breaking between
+/// sentences where possible, then between words, and only then within a word
+pub(crate) fn split_text(text: &str, max: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut chunk = String::new();
+    for sentence in sentences(text) {
+        if encoded_len(sentence) > max {
+            for word in sentence.split_whitespace() {
+                for piece in split_word(word, max) {
+                    push(&mut chunks, &mut chunk, piece, max);
+                }
+            }
+        } else {
+            push(&mut chunks, &mut chunk, sentence, max);
+        }
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+    chunks
+}
+
+/// Appends `text` to `chunk` with a space, first moving `chunk` to `chunks` if `text` would not
+/// fit in it
+fn push(chunks: &mut Vec<String>, chunk: &mut String, text: &str, max: usize) {
+    if !chunk.is_empty() && encoded_len(chunk) + 1 + encoded_len(text) > max {
+        chunks.push(std::mem::take(chunk));
+    }
+    if !chunk.is_empty() {
+        chunk.push(' ');
+    }
+    chunk.push_str(text);
+}
+
+/// The sentences of `text`, trimmed. A sentence ends with `.`, `!` or `?` followed by whitespace,
+/// or with a line break
+fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let ends = c == '\n'
+            || (matches!(c, '.' | '!' | '?')
+                && chars.peek().is_none_or(|(_, next)| next.is_whitespace()));
+        if ends {
+            sentences.push(&text[start..i + c.len_utf8()]);
+            start = i + c.len_utf8();
+        }
+    }
+    sentences.push(&text[start..]);
+    sentences
+        .into_iter()
+        .map(str::trim)
+        .filter(|sentence| !sentence.is_empty())
+        .collect()
+}
+
+/// `word` split on character boundaries into pieces of at most `max` bytes once percent-encoded
+fn split_word(word: &str, max: usize) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let (mut start, mut len) = (0, 0);
+    for (i, c) in word.char_indices() {
+        let c_len = encoded_len(&word[i..i + c.len_utf8()]);
+        if len + c_len > max && i > start {
+            pieces.push(&word[start..i]);
+            (start, len) = (i, 0);
+        }
+        len += c_len;
+    }
+    pieces.push(&word[start..]);
+    pieces
+}
+
+fn encoded_len(text: &str) -> usize {
+    byte_serialize(text.as_bytes()).map(str::len).sum()
+}
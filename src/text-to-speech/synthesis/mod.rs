@@ -1,16 +1,33 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, path::Path, time::Duration};
+mod audio;
 /// Errors that may be returned in speech synthesis requests
 pub mod errors;
+pub(crate) mod long;
+mod params;
+mod websocket;
 
-use reqwest::{Method, Request, StatusCode, Url, Version};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bytes::Bytes;
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+use reqwest::{
+    header::{HeaderValue, ACCEPT, CONTENT_TYPE},
+    Method, Request, Response, StatusCode, Url,
+};
+use tokio::io::{AsyncWriteExt, BufWriter};
 use url::form_urlencoded::byte_serialize;
 
-use self::errors::SynthesisError;
+use self::errors::{AudioFormatError, SynthesisError};
+pub use self::{
+    audio::SynthesizedAudio,
+    params::SynthesisParams,
+    websocket::{Mark, SynthesisEvent, SynthesisStream, WordTiming},
+};
+use crate::error::{CommonError, WatsonError};
 
-use super::TextToSpeech;
+use super::{voices::Voice, TextToSpeech};
 
 /// The service can return audio in the following formats (MIME types):
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub enum AudioFormat {
     /// You must specify the rate of the audio.
     AudioAlaw { sample_rate: u16 },
@@ -45,6 +62,13 @@ pub enum AudioFormat {
     AudioWebmCodecsVorbis { sample_rate: Option<u16> },
 }
 
+/// The format of [`synthesise_low_latency()`]: Opus at the rate it encodes natively
+///
+/// [`synthesise_low_latency()`]: super::TextToSpeech::synthesise_low_latency()
+pub(crate) const LOW_LATENCY_FORMAT: AudioFormat = AudioFormat::AudioOggCodecsOpus {
+    sample_rate: Some(48000),
+};
+
 impl Default for AudioFormat {
     /// The default audio format: [`AudioOggCodecsOpus`]
     ///
@@ -59,79 +83,231 @@ impl Default for AudioFormat {
 impl AudioFormat {
     /// The value that the server expects for a particular format
     pub fn id(&self) -> Cow<'static, str> {
+        serialise_bytes(&self.mime())
+    }
+
+    /// The plain MIME type of the format, before it is percent-encoded by [`id()`]
+    ///
+    /// [`id()`]: Self::id()
+    pub(crate) fn mime(&self) -> String {
         match &self {
-            AudioFormat::AudioAlaw { sample_rate } => {
-                let url = format!("audio/alaw;rate={sample_rate}");
-                serialise_bytes(&url)
-            }
-            AudioFormat::AudioBasic => Cow::from("audio/basic"),
+            AudioFormat::AudioAlaw { sample_rate } => format!("audio/alaw;rate={sample_rate}"),
+            AudioFormat::AudioBasic => "audio/basic".to_owned(),
             AudioFormat::AudioFlac { sample_rate } => {
-                let url = format!("audio/flac;rate={}", sample_rate.unwrap_or(22050));
-                serialise_bytes(&url)
+                format!("audio/flac;rate={}", sample_rate.unwrap_or(22050))
             }
             AudioFormat::AudioL16 {
                 sample_rate,
                 endianess: endianness,
-            } => {
-                let url = match endianness {
-                    Some(endianness) => {
-                        format!(
-                            "audio/flac;rate={sample_rate};endianness={}",
-                            endianness.id()
-                        )
-                    }
-                    None => {
-                        format!("audio/flac;rate={sample_rate}")
-                    }
-                };
-                serialise_bytes(&url)
-            }
+            } => match endianness {
+                Some(endianness) => {
+                    format!(
+                        "audio/l16;rate={sample_rate};endianness={}",
+                        endianness.id()
+                    )
+                }
+                None => {
+                    format!("audio/l16;rate={sample_rate}")
+                }
+            },
             AudioFormat::AudioOgg { sample_rate } => {
-                let url = format!("audio/ogg;rate={}", sample_rate.unwrap_or(22050));
-                serialise_bytes(&url)
-            }
-            AudioFormat::AudioOggCodecsOpus { sample_rate } => {
-                let url = format!(
-                    "audio/ogg;codecs=opus;rate={}",
-                    match sample_rate {
-                        Some(rate) => *rate,
-                        None => 48000,
-                    }
-                );
-                serialise_bytes(&url)
-            }
-            AudioFormat::AudioOggCodecsVorbis { sample_rate } => {
-                let url = format!(
-                    "audio/ogg;codecs=vorbis;rate={}",
-                    sample_rate.unwrap_or(22050)
-                );
-                serialise_bytes(&url)
+                format!("audio/ogg;rate={}", sample_rate.unwrap_or(22050))
             }
+            AudioFormat::AudioOggCodecsOpus { sample_rate } => format!(
+                "audio/ogg;codecs=opus;rate={}",
+                match sample_rate {
+                    Some(rate) => *rate,
+                    None => 48000,
+                }
+            ),
+            AudioFormat::AudioOggCodecsVorbis { sample_rate } => format!(
+                "audio/ogg;codecs=vorbis;rate={}",
+                sample_rate.unwrap_or(22050)
+            ),
             AudioFormat::AudioMp3 { sample_rate } => {
-                let url = format!("audio/mp3;rate={}", sample_rate.unwrap_or(22050));
-                serialise_bytes(&url)
+                format!("audio/mp3;rate={}", sample_rate.unwrap_or(22050))
             }
             AudioFormat::AudioMpeg { sample_rate } => {
-                let url = format!("audio/mpeg;rate={}", sample_rate.unwrap_or(22050));
-                serialise_bytes(&url)
-            }
-            AudioFormat::AudioMulaw { sample_rate } => {
-                let url = format!("audio/mulaw;rate={}", sample_rate);
-                serialise_bytes(&url)
+                format!("audio/mpeg;rate={}", sample_rate.unwrap_or(22050))
             }
+            AudioFormat::AudioMulaw { sample_rate } => format!("audio/mulaw;rate={}", sample_rate),
             AudioFormat::AudioWav { sample_rate } => {
-                let url = format!("audio/wav;rate={}", sample_rate.unwrap_or(22050));
-                serialise_bytes(&url)
+                format!("audio/wav;rate={}", sample_rate.unwrap_or(22050))
+            }
+            AudioFormat::AudioWebm => "audio/webm".to_owned(),
+            AudioFormat::AudioWebmCodecsOpus => "audio/webm;codecs=opus".to_owned(),
+            AudioFormat::AudioWebmCodecsVorbis { sample_rate } => format!(
+                "audio/webm;codecs=vorbis;rate={}",
+                sample_rate.unwrap_or(22050)
+            ),
+        }
+    }
+
+    /// Parses a MIME type such as `audio/ogg;codecs=opus;rate=48000`, for example the `Content-Type` that the service returned, into the format with its sample rate and endianness. This is the inverse of [`id()`] once it has been percent-decoded. The type and parameter names are matched ignoring case and unknown parameters are ignored. Returns [`None`] for types that the service does not produce, or whose required sample rate is missing or is not a number
+    ///
+    /// # Example
+    /// ```
+    /// # use ibm_watson::tts::synthesis::AudioFormat;
+    /// let format = AudioFormat::from_mime("audio/ogg;codecs=opus;rate=48000").unwrap();
+    /// assert!(matches!(
+    ///     format,
+    ///     AudioFormat::AudioOggCodecsOpus {
+    ///         sample_rate: Some(48000)
+    ///     }
+    /// ));
+    /// ```
+    /// [`id()`]: Self::id()
+    /// [`None`]: std::option::Option::None
+    pub fn from_mime(mime: &str) -> Option<AudioFormat> {
+        let mut parts = mime.split(';').map(str::trim);
+        let essence = parts.next()?.to_ascii_lowercase();
+        let (mut codecs, mut rate, mut endianness) = (None, None, None);
+        for parameter in parts {
+            let (name, value) = match parameter.split_once('=') {
+                Some((name, value)) => (name.trim(), value.trim().trim_matches('"')),
+                None => continue,
+            };
+            if name.eq_ignore_ascii_case("codecs") {
+                codecs = Some(value.to_ascii_lowercase());
+            } else if name.eq_ignore_ascii_case("rate") {
+                rate = Some(value.parse::<u16>().ok()?);
+            } else if name.eq_ignore_ascii_case("endianness") {
+                endianness = Some(match value.to_ascii_lowercase().as_str() {
+                    "big-endian" => AudioEndianness::BigEndian,
+                    "little-endian" => AudioEndianness::LittleEndian,
+                    _ => return None,
+                });
+            }
+        }
+        let format = match (essence.as_str(), codecs.as_deref()) {
+            ("audio/alaw", None) => AudioFormat::AudioAlaw { sample_rate: rate? },
+            ("audio/basic", None) => AudioFormat::AudioBasic,
+            ("audio/flac", None) => AudioFormat::AudioFlac { sample_rate: rate },
+            ("audio/l16", None) => AudioFormat::AudioL16 {
+                sample_rate: rate?,
+                endianess: endianness,
+            },
+            ("audio/ogg", None) => AudioFormat::AudioOgg { sample_rate: rate },
+            ("audio/ogg", Some("opus")) => AudioFormat::AudioOggCodecsOpus { sample_rate: rate },
+            ("audio/ogg", Some("vorbis")) => {
+                AudioFormat::AudioOggCodecsVorbis { sample_rate: rate }
+            }
+            ("audio/mp3", None) => AudioFormat::AudioMp3 { sample_rate: rate },
+            ("audio/mpeg", None) => AudioFormat::AudioMpeg { sample_rate: rate },
+            ("audio/mulaw", None) => AudioFormat::AudioMulaw { sample_rate: rate? },
+            ("audio/wav", None) => AudioFormat::AudioWav { sample_rate: rate },
+            ("audio/webm", None) => AudioFormat::AudioWebm,
+            ("audio/webm", Some("opus")) => AudioFormat::AudioWebmCodecsOpus,
+            ("audio/webm", Some("vorbis")) => {
+                AudioFormat::AudioWebmCodecsVorbis { sample_rate: rate }
             }
-            AudioFormat::AudioWebm => serialise_bytes("audio/webm"),
-            AudioFormat::AudioWebmCodecsOpus => serialise_bytes("audio/webm;codecs=opus"),
+            _ => return None,
+        };
+        Some(format)
+    }
+
+    /// Picks a format from a file extension such as `mp3` or `.ogg`, ignoring case. Sample rates that are optional are left to the service default. Returns [`None`] for extensions that do not map to a single format or whose format requires a sample rate, such as raw `l16` audio
+    ///
+    /// # Example
+    /// ```
+    /// # use ibm_watson::tts::synthesis::AudioFormat;
+    /// let format = AudioFormat::from_extension("mp3").unwrap();
+    /// assert_eq!(format.extension(), "mp3");
+    /// ```
+    /// [`None`]: std::option::Option::None
+    pub fn from_extension(extension: &str) -> Option<AudioFormat> {
+        let extension = extension.trim_start_matches('.').to_ascii_lowercase();
+        let format = match extension.as_str() {
+            "au" | "snd" => AudioFormat::AudioBasic,
+            "flac" => AudioFormat::AudioFlac { sample_rate: None },
+            "ogg" | "oga" | "opus" => AudioFormat::AudioOggCodecsOpus { sample_rate: None },
+            "mp3" => AudioFormat::AudioMp3 { sample_rate: None },
+            "mpeg" | "mpga" => AudioFormat::AudioMpeg { sample_rate: None },
+            "wav" | "wave" => AudioFormat::AudioWav { sample_rate: None },
+            "webm" => AudioFormat::AudioWebm,
+            _ => return None,
+        };
+        Some(format)
+    }
+
+    /// The conventional file extension, without the leading dot, for audio in this format
+    pub fn extension(&self) -> &str {
+        match self {
+            AudioFormat::AudioAlaw { .. } => "alaw",
+            AudioFormat::AudioBasic => "au",
+            AudioFormat::AudioFlac { .. } => "flac",
+            AudioFormat::AudioL16 { .. } => "pcm",
+            AudioFormat::AudioOgg { .. }
+            | AudioFormat::AudioOggCodecsOpus { .. }
+            | AudioFormat::AudioOggCodecsVorbis { .. } => "ogg",
+            AudioFormat::AudioMp3 { .. } => "mp3",
+            AudioFormat::AudioMpeg { .. } => "mpeg",
+            AudioFormat::AudioMulaw { .. } => "ulaw",
+            AudioFormat::AudioWav { .. } => "wav",
+            AudioFormat::AudioWebm
+            | AudioFormat::AudioWebmCodecsOpus
+            | AudioFormat::AudioWebmCodecsVorbis { .. } => "webm",
+        }
+    }
+
+    /// Checks that the sample rate is one that the codec of the format supports, so that an unsupported rate fails before a request is made instead of with a `406 Not Acceptable` from the service. Formats whose rate is fixed or left to the service default are always valid
+    ///
+    /// # Example
+    /// ```
+    /// # use ibm_watson::tts::synthesis::AudioFormat;
+    /// let format = AudioFormat::AudioOggCodecsOpus {
+    ///     sample_rate: Some(44100),
+    /// };
+    /// assert!(format.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), AudioFormatError> {
+        const OPUS_RATES: [u16; 5] = [8000, 12000, 16000, 24000, 48000];
+        let (format, rate) = match *self {
+            AudioFormat::AudioAlaw { sample_rate } => ("audio/alaw", Some(sample_rate)),
+            AudioFormat::AudioFlac { sample_rate } => ("audio/flac", sample_rate),
+            AudioFormat::AudioL16 { sample_rate, .. } => ("audio/l16", Some(sample_rate)),
+            AudioFormat::AudioOgg { sample_rate } => ("audio/ogg", sample_rate),
+            AudioFormat::AudioOggCodecsOpus { sample_rate } => {
+                ("audio/ogg;codecs=opus", sample_rate)
+            }
+            AudioFormat::AudioOggCodecsVorbis { sample_rate } => {
+                ("audio/ogg;codecs=vorbis", sample_rate)
+            }
+            AudioFormat::AudioMp3 { sample_rate } => ("audio/mp3", sample_rate),
+            AudioFormat::AudioMpeg { sample_rate } => ("audio/mpeg", sample_rate),
+            AudioFormat::AudioMulaw { sample_rate } => ("audio/mulaw", Some(sample_rate)),
+            AudioFormat::AudioWav { sample_rate } => ("audio/wav", sample_rate),
             AudioFormat::AudioWebmCodecsVorbis { sample_rate } => {
-                let url = format!(
-                    "audio/webm;codecs=vorbis/rate={}",
-                    sample_rate.unwrap_or(22050)
-                );
-                serialise_bytes(&url)
+                ("audio/webm;codecs=vorbis", sample_rate)
+            }
+            AudioFormat::AudioBasic | AudioFormat::AudioWebm | AudioFormat::AudioWebmCodecsOpus => {
+                return Ok(())
             }
+        };
+        let rate = match rate {
+            Some(rate) => rate,
+            None => return Ok(()),
+        };
+        let (supported, expected) = match self {
+            // audio/ogg without a codec is encoded with opus
+            AudioFormat::AudioOgg { .. } | AudioFormat::AudioOggCodecsOpus { .. } => (
+                OPUS_RATES.contains(&rate),
+                "one of 8000, 12000, 16000, 24000 or 48000 Hz",
+            ),
+            AudioFormat::AudioMp3 { .. } | AudioFormat::AudioMpeg { .. } => (
+                (8000..=48000).contains(&rate),
+                "a rate between 8000 and 48000 Hz",
+            ),
+            _ => (rate >= 8000, "a rate of at least 8000 Hz"),
+        };
+        if supported {
+            Ok(())
+        } else {
+            Err(AudioFormatError::UnsupportedSampleRate {
+                format,
+                rate,
+                expected,
+            })
         }
     }
 }
@@ -141,7 +317,97 @@ fn serialise_bytes(url: &str) -> Cow<'static, str> {
     Cow::from(url)
 }
 
-#[derive(Default, Clone, Copy)]
+/// Checks that `voice` supports the features that a synthesis request uses
+pub(crate) fn check_supported_features(
+    text: &SynthesisInput,
+    voice: &Voice,
+    customisation_id: Option<&str>,
+) -> Result<(), SynthesisError> {
+    let unsupported = |feature: &str| {
+        Err(SynthesisError::UnsupportedFeature(
+            voice.name.to_owned(),
+            feature.to_owned(),
+        ))
+    };
+    if let SynthesisInput::Ssml(ssml) = text {
+        if ssml.contains("<voice-transformation") && !voice.supported_features.voice_transformation
+        {
+            return unsupported("voice_transformation");
+        }
+    }
+    if customisation_id.is_some() && !voice.supported_features.custom_pronunciation {
+        return unsupported("custom_pronunciation");
+    }
+    Ok(())
+}
+
+/// Builds a `data:` URI with the base64 encoded `bytes`
+pub(crate) fn data_uri(content_type: &str, bytes: &[u8]) -> String {
+    format!("data:{content_type};base64,{}", STANDARD.encode(bytes))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// The input to synthesise. The service accepts either plain text or text that is annotated with SSML
+pub enum SynthesisInput {
+    /// Plain text. It is sent unchanged, so the service interprets any SSML markup in it. Pass the text through [`ssml::escape()`] first if characters such as `<` and `&` are to be read out as they are
+    ///
+    /// [`ssml::escape()`]: crate::tts::ssml::escape()
+    Text(String),
+    /// SSML markup, for example `<speak>Hello <break time="1s"/> world</speak>`. The markup is sent untouched; you are responsible for making sure it is well-formed. Use [`ssml::escape()`] to embed plain text in it, or build the markup with an [`ssml::Builder`]
+    ///
+    /// [`ssml::escape()`]: crate::tts::ssml::escape()
+    /// [`ssml::Builder`]: crate::tts::ssml::Builder
+    Ssml(String),
+}
+
+impl SynthesisInput {
+    /// The text that is sent to the service
+    pub fn as_str(&self) -> &str {
+        match self {
+            SynthesisInput::Text(text) | SynthesisInput::Ssml(text) => text,
+        }
+    }
+}
+
+impl From<&str> for SynthesisInput {
+    fn from(text: &str) -> Self {
+        SynthesisInput::Text(text.to_owned())
+    }
+}
+
+impl From<String> for SynthesisInput {
+    fn from(text: String) -> Self {
+        SynthesisInput::Text(text)
+    }
+}
+
+impl From<&String> for SynthesisInput {
+    fn from(text: &String) -> Self {
+        SynthesisInput::Text(text.to_owned())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Synthesised audio along with the format that the service returned it in
+pub struct SynthesisOutput {
+    /// The synthesised audio
+    pub bytes: Bytes,
+    /// The `Content-Type` header of the response, for example `audio/ogg;codecs=opus`. Empty if the service did not send one
+    pub content_type: String,
+}
+
+impl SynthesisOutput {
+    /// The [`AudioFormat`] that the service returned the audio in, parsed from [`content_type`], or [`None`] if it is missing or not a format that this library knows about
+    ///
+    /// [`AudioFormat`]: self::AudioFormat
+    /// [`content_type`]: Self::content_type
+    /// [`None`]: std::option::Option::None
+    pub fn format(&self) -> Option<AudioFormat> {
+        AudioFormat::from_mime(&self.content_type)
+    }
+}
+
+#[derive(Default, Clone, Copy, Debug)]
 /// The server expects the following values for audio endianness
 pub enum AudioEndianness {
     /// Big Endian
@@ -163,15 +429,16 @@ impl AudioEndianness {
     }
 }
 
-impl TextToSpeech<'_> {
+impl TextToSpeech {
     /// Synthesises text to audio that is spoken in the [`specified voice`]. The service bases its understanding of the language for the input text on the specified voice. Use a voice that matches the language of the input text.
     ///
     /// # Parameters
     ///
-    /// * `text` - The text to synthesise
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
     /// * `format` - The requested [`AudioFormat`] (MIME type) of the audio. Defaults to [`AudioOggCodecsOpus`]
     /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. If a custom model is specified, it works only if it matches the [`language`] of the indicated voice. You must make the request with credentials for the instance of the service that owns the custom model. Omit the parameter to use the specified voice with no customisation
     ///
+    /// [`input`]: super::synthesis::SynthesisInput
     /// [`AudioFormat`]: super::synthesis::AudioFormat
     /// [`AudioOggCodecsOpus`]: super::synthesis::AudioFormat::AudioOggCodecsOpus
     /// [`name`]: super::voices::Voice::name
@@ -193,41 +460,675 @@ impl TextToSpeech<'_> {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn synthesise(
         &self,
-        text: impl AsRef<str>,
+        text: impl Into<SynthesisInput>,
         format: Option<AudioFormat>,
         customisation_id: Option<&str>,
     ) -> Result<bytes::Bytes, SynthesisError> {
-        let mut url = Url::parse(self.service_url).unwrap();
-        url.set_path("v1/synthesize");
-        url.set_query(customisation_id);
-        url.query_pairs_mut().append_pair("text", text.as_ref());
-        url.query_pairs_mut().append_pair("voice", self.voice.id());
-        if let Some(format) = format {
-            url.query_pairs_mut().append_pair("accept", &format.id());
-        }
-        let mut req = Request::new(Method::GET, url);
+        let response = self.synthesise_raw(text, format, customisation_id).await?;
+        let bytes = response.bytes().await?;
+        Ok(bytes)
+    }
+
+    /// Synthesises text to audio in the [`specified voice`] with additional [`parameters`], such as a simple change of the speaking [`rate`] or [`pitch`]. See [`synthesise()`] for more details
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
+    /// * `params` - The [`parameters`] to send with the request
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{synthesis::SynthesisParams, TextToSpeech},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let params = SynthesisParams::new().rate_percentage(25).pitch_percentage(-10);
+    /// let synth_bytes = tts.synthesise_with_params("Hey there", &params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// [`specified voice`]: super::TextToSpeech::set_voice()
+    /// [`parameters`]: self::SynthesisParams
+    /// [`rate`]: self::SynthesisParams::rate_percentage()
+    /// [`pitch`]: self::SynthesisParams::pitch_percentage()
+    /// [`synthesise()`]: Self::synthesise()
+    /// [`input`]: super::synthesis::SynthesisInput
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn synthesise_with_params(
+        &self,
+        text: impl Into<SynthesisInput>,
+        params: &SynthesisParams,
+    ) -> Result<Bytes, SynthesisError> {
+        params.validate()?;
+        let response = self
+            .voice_synthesis_response(
+                params.apply(text.into()),
+                self.voice.id(),
+                params.get_format(),
+                params.get_customisation_id(),
+                None,
+            )
+            .await?;
+        let bytes = response.bytes().await?;
+        Ok(bytes)
+    }
+
+    /// Synthesises text to audio like [`synthesise()`], but gives up if the request has not completed within `timeout`, including reading the audio. This overrides any timeout that the client was built with, so that a long synthesis can be given more time than other requests
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
+    /// * `format` - The requested [`AudioFormat`] (MIME type) of the audio. Defaults to [`AudioOggCodecsOpus`]
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. Omit the parameter to use the specified voice with no customisation
+    /// * `timeout` - How long to wait for the request to complete. If it elapses, [`Timeout`] is returned rather than [`ConnectionError`]
+    ///
+    /// [`synthesise()`]: Self::synthesise()
+    /// [`input`]: super::synthesis::SynthesisInput
+    /// [`AudioFormat`]: super::synthesis::AudioFormat
+    /// [`AudioOggCodecsOpus`]: super::synthesis::AudioFormat::AudioOggCodecsOpus
+    /// [`model`]: super::customisations::Model
+    /// [`Timeout`]: super::synthesis::errors::SynthesisError::Timeout
+    /// [`ConnectionError`]: super::synthesis::errors::SynthesisError::ConnectionError
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use std::time::Duration;
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{synthesis::errors::SynthesisError, TextToSpeech},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// match tts
+    ///     .synthesise_with_timeout("Hey there", None, None, Duration::from_secs(60))
+    ///     .await
+    /// {
+    ///     Ok(synth_bytes) => println!("{} bytes", synth_bytes.len()),
+    ///     Err(SynthesisError::Timeout(elapsed)) => println!("gave up after {elapsed:?}"),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn synthesise_with_timeout(
+        &self,
+        text: impl Into<SynthesisInput>,
+        format: Option<AudioFormat>,
+        customisation_id: Option<&str>,
+        timeout: Duration,
+    ) -> Result<Bytes, SynthesisError> {
+        let response = self
+            .voice_synthesis_response(
+                text,
+                self.voice.id(),
+                format,
+                customisation_id,
+                Some(timeout),
+            )
+            .await?;
+        response
+            .bytes()
+            .await
+            .map_err(|e| synthesis_timeout(e, Some(timeout)))
+    }
+
+    /// Synthesises several inputs concurrently in the [`specified voice`], with at most `concurrency` requests in flight at once. Each input is synthesised as if by [`synthesise()`], and a failure only affects its own result
+    ///
+    /// # Parameters
+    ///
+    /// * `inputs` - The [`input`]s to synthesise, each with the requested [`AudioFormat`] of its audio
+    /// * `concurrency` - The most requests to send at the same time. A value of 0 is treated as 1
+    ///
+    /// # Returns
+    ///
+    /// The result of each synthesis, in the order of `inputs`
+    ///
+    /// [`specified voice`]: super::TextToSpeech::set_voice()
+    /// [`synthesise()`]: Self::synthesise()
+    /// [`input`]: super::synthesis::SynthesisInput
+    /// [`AudioFormat`]: super::synthesis::AudioFormat
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let phrases = vec![("Hello", None), ("Goodbye", None), ("Thank you", None)];
+    /// for clip in tts.synthesise_batch(phrases, 2).await {
+    ///     println!("synthesised {} bytes", clip?.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"))
+    )]
+    pub async fn synthesise_batch(
+        &self,
+        inputs: Vec<(impl Into<SynthesisInput>, Option<AudioFormat>)>,
+        concurrency: usize,
+    ) -> Vec<Result<Bytes, SynthesisError>> {
+        let mut results: Vec<_> = stream::iter(inputs.into_iter().enumerate())
+            .map(|(index, (text, format))| async move {
+                (index, self.synthesise(text, format, None).await)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+        results.sort_unstable_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Synthesises text to audio with the given [`Voice`] after checking that the voice supports the features that the request uses. A voice that lacks a feature makes the service fail with a generic `400 Bad Request`; this fails before the request is sent with an [`UnsupportedFeature`] error that names the feature instead. The following are checked against the [`supported features`] of the voice:
+    ///
+    /// * `voice_transformation` - SSML input that contains a `<voice-transformation>` element
+    /// * `custom_pronunciation` - A `customisation_id`
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
+    /// * `voice` - The [`Voice`] to synthesise with, as returned by [`get_voice()`] or [`list_voices()`]. It is used in place of the [`specified voice`]
+    /// * `format` - The requested [`AudioFormat`] (MIME type) of the audio. Defaults to [`AudioOggCodecsOpus`]
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. Omit the parameter to use the voice with no customisation
+    ///
+    /// [`Voice`]: super::voices::Voice
+    /// [`UnsupportedFeature`]: super::synthesis::errors::SynthesisError::UnsupportedFeature
+    /// [`supported features`]: super::voices::Voice::supported_features
+    /// [`input`]: super::synthesis::SynthesisInput
+    /// [`get_voice()`]: Self::get_voice()
+    /// [`list_voices()`]: Self::list_voices()
+    /// [`specified voice`]: super::TextToSpeech::set_voice()
+    /// [`AudioFormat`]: super::synthesis::AudioFormat
+    /// [`AudioOggCodecsOpus`]: super::synthesis::AudioFormat::AudioOggCodecsOpus
+    /// [`model`]: super::customisations::Model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{synthesis::SynthesisInput, voices::WatsonVoice, TextToSpeech},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let voice = tts.get_voice(WatsonVoice::EnUsAllisonV3, None, None).await?;
+    /// let ssml = SynthesisInput::Ssml(String::from(
+    ///     r#"<speak><voice-transformation type="Young">Hey there</voice-transformation></speak>"#,
+    /// ));
+    /// let synth_bytes = tts.synthesise_checked(ssml, &voice, None, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn synthesise_checked(
+        &self,
+        text: impl Into<SynthesisInput>,
+        voice: &Voice,
+        format: Option<AudioFormat>,
+        customisation_id: Option<&str>,
+    ) -> Result<Bytes, SynthesisError> {
+        let text = text.into();
+        check_supported_features(&text, voice, customisation_id)?;
+        let response = self
+            .voice_synthesis_response(text, &voice.name, format, customisation_id, None)
+            .await?;
+        let bytes = response.bytes().await?;
+        Ok(bytes)
+    }
+
+    /// Synthesises text to audio like [`synthesise()`], but also returns the `Content-Type` of the audio that the service sent back. The service may negotiate a slightly different container than the one requested, so use this when you need to know exactly what you received, for example to pick a file extension or to relay the audio over HTTP
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
+    /// * `format` - The requested [`AudioFormat`] (MIME type) of the audio. Defaults to [`AudioOggCodecsOpus`]
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. Omit the parameter to use the specified voice with no customisation
+    ///
+    /// [`synthesise()`]: Self::synthesise()
+    /// [`input`]: super::synthesis::SynthesisInput
+    /// [`AudioFormat`]: super::synthesis::AudioFormat
+    /// [`AudioOggCodecsOpus`]: super::synthesis::AudioFormat::AudioOggCodecsOpus
+    /// [`model`]: super::customisations::Model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{voices::WatsonVoice, TextToSpeech},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let output = tts.synthesise_with_format("Hey there", None, None).await?;
+    /// println!("received {} as {}", output.bytes.len(), output.content_type);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn synthesise_with_format(
+        &self,
+        text: impl Into<SynthesisInput>,
+        format: Option<AudioFormat>,
+        customisation_id: Option<&str>,
+    ) -> Result<SynthesisOutput, SynthesisError> {
+        let response = self.synthesise_raw(text, format, customisation_id).await?;
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_owned();
+        let bytes = response.bytes().await?;
+        Ok(SynthesisOutput {
+            bytes,
+            content_type,
+        })
+    }
+
+    /// Synthesises text to audio like [`synthesise_with_format()`], but returns it as [`SynthesizedAudio`], which implements [`Read`] and [`Seek`] so that it can be passed straight to an audio library or written to a file
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
+    /// * `format` - The requested [`AudioFormat`] (MIME type) of the audio. Defaults to [`AudioOggCodecsOpus`]
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. Omit the parameter to use the specified voice with no customisation
+    ///
+    /// [`synthesise_with_format()`]: Self::synthesise_with_format()
+    /// [`SynthesizedAudio`]: super::synthesis::SynthesizedAudio
+    /// [`Read`]: std::io::Read
+    /// [`Seek`]: std::io::Seek
+    /// [`input`]: super::synthesis::SynthesisInput
+    /// [`AudioFormat`]: super::synthesis::AudioFormat
+    /// [`AudioOggCodecsOpus`]: super::synthesis::AudioFormat::AudioOggCodecsOpus
+    /// [`model`]: super::customisations::Model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let mut audio = tts.synthesise_audio("Hey there", None, None).await?;
+    /// audio.write_to("/home/user/hey.ogg")?;
+    /// std::io::copy(&mut audio, &mut std::io::sink())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn synthesise_audio(
+        &self,
+        text: impl Into<SynthesisInput>,
+        format: Option<AudioFormat>,
+        customisation_id: Option<&str>,
+    ) -> Result<SynthesizedAudio, SynthesisError> {
+        let output = self
+            .synthesise_with_format(text, format, customisation_id)
+            .await?;
+        Ok(SynthesizedAudio::from(output))
+    }
+
+    /// Synthesises text to audio and returns it as a `data:` URI, for example `data:audio/ogg;codecs=opus;base64,T2dnUw...`, that can be used directly as the `src` of an HTML `<audio>` element or embedded in JSON. The MIME type is the `Content-Type` that the service returned, see [`synthesise_with_format()`]
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
+    /// * `format` - The requested [`AudioFormat`] (MIME type) of the audio. Defaults to [`AudioOggCodecsOpus`]
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. Omit the parameter to use the specified voice with no customisation
+    ///
+    /// [`synthesise_with_format()`]: Self::synthesise_with_format()
+    /// [`input`]: super::synthesis::SynthesisInput
+    /// [`AudioFormat`]: super::synthesis::AudioFormat
+    /// [`AudioOggCodecsOpus`]: super::synthesis::AudioFormat::AudioOggCodecsOpus
+    /// [`model`]: super::customisations::Model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{voices::WatsonVoice, TextToSpeech},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let uri = tts.synthesise_base64("Hey there", None, None).await?;
+    /// let html = format!(r#"<audio controls src="{uri}"></audio>"#);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn synthesise_base64(
+        &self,
+        text: impl Into<SynthesisInput>,
+        format: Option<AudioFormat>,
+        customisation_id: Option<&str>,
+    ) -> Result<String, SynthesisError> {
+        let output = self
+            .synthesise_with_format(text, format, customisation_id)
+            .await?;
+        Ok(data_uri(&output.content_type, &output.bytes))
+    }
+
+    /// Synthesises text to audio like [`synthesise()`], but returns the audio as a stream of chunks as they arrive from the service instead of buffering the whole clip. Use this to pipe long syntheses to a player or a file while keeping memory usage flat
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
+    /// * `format` - The requested [`AudioFormat`] (MIME type) of the audio. Defaults to [`AudioOggCodecsOpus`]
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. Omit the parameter to use the specified voice with no customisation
+    ///
+    /// [`synthesise()`]: Self::synthesise()
+    /// [`input`]: super::synthesis::SynthesisInput
+    /// [`AudioFormat`]: super::synthesis::AudioFormat
+    /// [`AudioOggCodecsOpus`]: super::synthesis::AudioFormat::AudioOggCodecsOpus
+    /// [`model`]: super::customisations::Model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{voices::WatsonVoice, TextToSpeech},
+    /// # };
+    /// # use futures_util::StreamExt;
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let mut stream = tts.synthesise_stream("Hey there", None, None).await?;
+    /// while let Some(chunk) = stream.next().await {
+    ///     println!("received {} bytes", chunk?.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn synthesise_stream(
+        &self,
+        text: impl Into<SynthesisInput>,
+        format: Option<AudioFormat>,
+        customisation_id: Option<&str>,
+    ) -> Result<impl Stream<Item = Result<Bytes, SynthesisError>>, SynthesisError> {
+        let response = self.synthesise_raw(text, format, customisation_id).await?;
+        Ok(response.bytes_stream().map_err(SynthesisError::from))
+    }
 
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
+    /// Synthesises text to audio like [`synthesise_stream()`], tuned for interactive use such as voice agents, where the delay before the first audio matters more than the size of the clip. The audio is always [`AudioOggCodecsOpus`] at 48,000 Hz, the native rate of the codec, so the service does not resample it, and chunks are returned as soon as they arrive. With [`synthesise()`], nothing can be played until the whole clip has been received. The synthesize API has no parameter of its own to lower latency, so there is nothing else to set
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. Omit the parameter to use the specified voice with no customisation
+    ///
+    /// [`synthesise_stream()`]: Self::synthesise_stream()
+    /// [`synthesise()`]: Self::synthesise()
+    /// [`input`]: super::synthesis::SynthesisInput
+    /// [`AudioOggCodecsOpus`]: super::synthesis::AudioFormat::AudioOggCodecsOpus
+    /// [`model`]: super::customisations::Model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # use futures_util::StreamExt;
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let mut stream = tts.synthesise_low_latency("How can I help?", None).await?;
+    /// while let Some(chunk) = stream.next().await {
+    ///     // hand each chunk to the player as soon as it arrives
+    ///     println!("received {} bytes", chunk?.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn synthesise_low_latency(
+        &self,
+        text: impl Into<SynthesisInput>,
+        customisation_id: Option<&str>,
+    ) -> Result<impl Stream<Item = Result<Bytes, SynthesisError>>, SynthesisError> {
+        self.synthesise_stream(text, Some(LOW_LATENCY_FORMAT), customisation_id)
+            .await
+    }
+
+    /// Synthesises text to audio and writes it to the file at `path` as the audio arrives from the service. The file is created if it does not exist and truncated if it does. If `path` has no extension, the [`extension()`] of the requested `format` is added to it; an existing extension is left as is
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
+    /// * `format` - The requested [`AudioFormat`] (MIME type) of the audio. Defaults to [`AudioOggCodecsOpus`]
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. Omit the parameter to use the specified voice with no customisation
+    /// * `path` - The file that the audio is written to
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written to the file
+    ///
+    /// [`extension()`]: super::synthesis::AudioFormat::extension()
+    /// [`input`]: super::synthesis::SynthesisInput
+    /// [`AudioFormat`]: super::synthesis::AudioFormat
+    /// [`AudioOggCodecsOpus`]: super::synthesis::AudioFormat::AudioOggCodecsOpus
+    /// [`model`]: super::customisations::Model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{voices::WatsonVoice, TextToSpeech},
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let written = tts
+    ///     .synthesise_to_file("Hey there", None, None, "/home/user/hey.ogg")
+    ///     .await?;
+    /// println!("wrote {written} bytes");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn synthesise_to_file(
+        &self,
+        text: impl Into<SynthesisInput>,
+        format: Option<AudioFormat>,
+        customisation_id: Option<&str>,
+        path: impl AsRef<Path>,
+    ) -> Result<u64, SynthesisError> {
+        let stream = self
+            .synthesise_stream(text, format, customisation_id)
+            .await?;
+        let mut stream = Box::pin(stream);
+        let path = path.as_ref();
+        let path = match path.extension() {
+            Some(_) => path.to_path_buf(),
+            None => path.with_extension(format.unwrap_or_default().extension()),
+        };
+        let file = tokio::fs::File::create(&path)
+            .await
+            .map_err(|e| SynthesisError::FileWriteError(e.to_string()))?;
+        let mut writer = BufWriter::new(file);
+        let mut written = 0;
+        while let Some(chunk) = stream.try_next().await? {
+            writer
+                .write_all(&chunk)
+                .await
+                .map_err(|e| SynthesisError::FileWriteError(e.to_string()))?;
+            written += chunk.len() as u64;
         }
+        writer
+            .flush()
+            .await
+            .map_err(|e| SynthesisError::FileWriteError(e.to_string()))?;
+        Ok(written)
+    }
+
+    /// Synthesises text to audio like [`synthesise()`], but returns the [`Response`] of the service as is once it has succeeded, so that its headers can be inspected. For example, the `X-Global-Transaction-Id` header identifies the request to IBM support. Errors are mapped the same way as for [`synthesise()`]
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
+    /// * `format` - The requested [`AudioFormat`] (MIME type) of the audio. Defaults to [`AudioOggCodecsOpus`]
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. Omit the parameter to use the specified voice with no customisation
+    ///
+    /// [`synthesise()`]: Self::synthesise()
+    /// [`Response`]: reqwest::Response
+    /// [`input`]: super::synthesis::SynthesisInput
+    /// [`AudioFormat`]: super::synthesis::AudioFormat
+    /// [`AudioOggCodecsOpus`]: super::synthesis::AudioFormat::AudioOggCodecsOpus
+    /// [`model`]: super::customisations::Model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let response = tts.synthesise_raw("Hey there", None, None).await?;
+    /// println!("{:?}", response.headers().get("x-global-transaction-id"));
+    /// let synth_bytes = response.bytes().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn synthesise_raw(
+        &self,
+        text: impl Into<SynthesisInput>,
+        format: Option<AudioFormat>,
+        customisation_id: Option<&str>,
+    ) -> Result<Response, SynthesisError> {
+        self.voice_synthesis_response(text, self.voice.id(), format, customisation_id, None)
+            .await
+    }
+
+    async fn voice_synthesis_response(
+        &self,
+        text: impl Into<SynthesisInput>,
+        voice: &str,
+        format: Option<AudioFormat>,
+        customisation_id: Option<&str>,
+        timeout: Option<Duration>,
+    ) -> Result<Response, SynthesisError> {
+        let url = synthesis_url(
+            &self.service_url,
+            &text.into(),
+            voice,
+            format,
+            customisation_id,
+        )?;
+        let mut req = Request::new(Method::GET, url);
+        *req.timeout_mut() = timeout;
+        // The format is requested with the accept query parameter, which must not be contradicted
+        req.headers_mut()
+            .insert(ACCEPT, HeaderValue::from_static("*/*"));
 
         let client = self.get_client();
-        let response = client.execute(req).await?;
-        assert_eq!(response.status(), 200);
-        match response.status() {
-            StatusCode::OK => {
-                let bytes = response.bytes().await.unwrap();
-                Ok(bytes)
-            }
-            StatusCode::NOT_ACCEPTABLE => Err(SynthesisError::NotAcceptable406),
-            StatusCode::UNSUPPORTED_MEDIA_TYPE => Err(SynthesisError::UnsupportedMediaType415),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(SynthesisError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(SynthesisError::ServiceUnavailable500),
-            StatusCode::BAD_REQUEST => Err(SynthesisError::BadRequest400),
-            StatusCode::NOT_FOUND => Err(SynthesisError::NotFound404),
-            _ => Err(SynthesisError::UnmappedResponse(response.status().as_u16())),
+        let response = client
+            .execute(req)
+            .await
+            .map_err(|e| synthesis_timeout(e, timeout))?;
+        let status = response.status();
+        if status == StatusCode::OK {
+            return Ok(response);
         }
+        match synthesis_error(status) {
+            Some(error) => Err(error(WatsonError::from_response(response).await)),
+            None => Err(CommonError::from_response(response).await.into()),
+        }
+    }
+}
+
+/// The url of a synthesis request. Shared by the async and blocking clients
+pub(crate) fn synthesis_url(
+    service_url: &Url,
+    text: &SynthesisInput,
+    voice: &str,
+    format: Option<AudioFormat>,
+    customisation_id: Option<&str>,
+) -> Result<Url, SynthesisError> {
+    let mut url = service_url.clone();
+    url.set_path("v1/synthesize");
+    if let Some(id) = customisation_id {
+        url.query_pairs_mut().append_pair("customization_id", id);
+    }
+    url.query_pairs_mut().append_pair("text", text.as_str());
+    url.query_pairs_mut().append_pair("voice", voice);
+    if let Some(format) = format {
+        format.validate()?;
+        url.query_pairs_mut().append_pair("accept", &format.mime());
+    }
+    Ok(url)
+}
+
+/// A [`Timeout`] if `error` is because the request took longer than `timeout`
+///
+/// [`Timeout`]: SynthesisError::Timeout
+fn synthesis_timeout(error: reqwest::Error, timeout: Option<Duration>) -> SynthesisError {
+    match timeout {
+        Some(timeout) if error.is_timeout() => SynthesisError::Timeout(timeout),
+        _ => SynthesisError::ConnectionError(error),
+    }
+}
+
+/// The error for an unsuccessful synthesis response, if the status is one that the service documents
+/// for synthesis. Other statuses, such as 500 and 503, are mapped by [`CommonError`]
+pub(crate) fn synthesis_error(status: StatusCode) -> Option<fn(WatsonError) -> SynthesisError> {
+    match status {
+        StatusCode::NOT_ACCEPTABLE => Some(SynthesisError::NotAcceptable406),
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => Some(SynthesisError::UnsupportedMediaType415),
+        StatusCode::BAD_REQUEST => Some(SynthesisError::BadRequest400),
+        StatusCode::NOT_FOUND => Some(SynthesisError::NotFound404),
+        _ => None,
     }
 }
@@ -0,0 +1,127 @@
+use std::fmt::Write;
+
+use super::{errors::SynthesisError, AudioFormat, SynthesisInput};
+use crate::tts::ssml;
+
+/// The furthest that the rate or pitch can be changed from the default of the voice, in percent
+const MAX_PERCENTAGE: i8 = 100;
+
+#[derive(Clone, Debug, Default)]
+/// Optional parameters that control how the service synthesises text. Parameters that are not
+/// set are omitted from the request so that the service defaults apply
+///
+/// # Example
+/// ```
+/// # use ibm_watson::tts::synthesis::{AudioFormat, SynthesisParams};
+/// let params = SynthesisParams::new()
+///     .format(AudioFormat::AudioMp3 { sample_rate: None })
+///     .rate_percentage(20)
+///     .pitch_percentage(-10);
+/// ```
+pub struct SynthesisParams {
+    format: Option<AudioFormat>,
+    customisation_id: Option<String>,
+    rate_percentage: Option<i8>,
+    pitch_percentage: Option<i8>,
+}
+
+impl SynthesisParams {
+    /// Creates a new set of parameters with all the service defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The requested [`AudioFormat`] (MIME type) of the audio. If it is not set, the service returns [`AudioOggCodecsOpus`]
+    ///
+    /// [`AudioFormat`]: super::AudioFormat
+    /// [`AudioOggCodecsOpus`]: super::AudioFormat::AudioOggCodecsOpus
+    pub fn format(mut self, format: AudioFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// The customisation ID (GUID) of a custom [`model`] to use for the synthesis. It must match the language of the voice, and you must make the request with credentials for the instance of the service that owns it
+    ///
+    /// [`model`]: crate::tts::customisations::Model
+    pub fn customisation_id(mut self, customisation_id: impl Into<String>) -> Self {
+        self.customisation_id = Some(customisation_id.into());
+        self
+    }
+
+    /// How much faster (positive) or slower (negative) than the default of the voice to speak, as a percentage from -100 to 100. The text is wrapped in an SSML `<prosody>` element, so the voice must support SSML
+    pub fn rate_percentage(mut self, percentage: i8) -> Self {
+        self.rate_percentage = Some(percentage);
+        self
+    }
+
+    /// How much higher (positive) or lower (negative) than the default of the voice to speak, as a percentage from -100 to 100. The text is wrapped in an SSML `<prosody>` element, so the voice must support SSML
+    pub fn pitch_percentage(mut self, percentage: i8) -> Self {
+        self.pitch_percentage = Some(percentage);
+        self
+    }
+
+    pub(crate) fn get_format(&self) -> Option<AudioFormat> {
+        self.format
+    }
+
+    pub(crate) fn get_customisation_id(&self) -> Option<&str> {
+        self.customisation_id.as_deref()
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), SynthesisError> {
+        let percentages = [
+            ("rate_percentage", self.rate_percentage),
+            ("pitch_percentage", self.pitch_percentage),
+        ];
+        for (name, percentage) in percentages {
+            let out_of_range =
+                |percentage: &i8| !(-MAX_PERCENTAGE..=MAX_PERCENTAGE).contains(percentage);
+            if let Some(percentage) = percentage.filter(out_of_range) {
+                return Err(SynthesisError::InvalidParameter(format!(
+                    "{name} must be between -{MAX_PERCENTAGE} and {MAX_PERCENTAGE}, got {percentage}"
+                )));
+            }
+        }
+        if let Some(format) = &self.format {
+            format.validate()?;
+        }
+        Ok(())
+    }
+
+    /// Wraps `input` in a `<prosody>` element if a rate or pitch is set. Plain text is escaped
+    /// first, and SSML is wrapped inside its `<speak>` element
+    pub(crate) fn apply(&self, input: SynthesisInput) -> SynthesisInput {
+        if self.rate_percentage.is_none() && self.pitch_percentage.is_none() {
+            return input;
+        }
+        let mut prosody = String::from("<prosody");
+        if let Some(rate) = self.rate_percentage {
+            let _ = write!(prosody, r#" rate="{rate:+}%""#);
+        }
+        if let Some(pitch) = self.pitch_percentage {
+            let _ = write!(prosody, r#" pitch="{pitch:+}%""#);
+        }
+        prosody.push('>');
+        let ssml = match input {
+            SynthesisInput::Text(text) => {
+                format!("<speak>{prosody}{}</prosody></speak>", ssml::escape(&text))
+            }
+            SynthesisInput::Ssml(markup) => {
+                let start = markup
+                    .find("<speak")
+                    .and_then(|speak| markup[speak..].find('>').map(|end| speak + end + 1));
+                let end = markup.rfind("</speak>");
+                match (start, end) {
+                    (Some(start), Some(end)) if start <= end => format!(
+                        "{}{prosody}{}</prosody>{}",
+                        &markup[..start],
+                        &markup[start..end],
+                        &markup[end..]
+                    ),
+                    _ => format!("<speak>{prosody}{markup}</prosody></speak>"),
+                }
+            }
+        };
+        SynthesisInput::Ssml(ssml)
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures_util::{stream, SinkExt, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{client::IntoClientRequest, Message},
+    MaybeTlsStream, WebSocketStream,
+};
+
+use super::{errors::SynthesisError, AudioFormat, SynthesisInput};
+use crate::tts::TextToSpeech;
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "(String, f64, f64)", into = "(String, f64, f64)")]
+/// The time at which a word is spoken in the synthesised audio
+pub struct WordTiming {
+    /// The word as it appears in the input text
+    pub word: String,
+    /// The time in seconds at which the word starts in the audio
+    pub start: f64,
+    /// The time in seconds at which the word ends in the audio
+    pub end: f64,
+}
+
+impl From<(String, f64, f64)> for WordTiming {
+    fn from((word, start, end): (String, f64, f64)) -> Self {
+        Self { word, start, end }
+    }
+}
+
+impl From<WordTiming> for (String, f64, f64) {
+    fn from(timing: WordTiming) -> Self {
+        (timing.word, timing.start, timing.end)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "(String, f64)", into = "(String, f64)")]
+/// The time at which an SSML `<mark>` element is reached in the synthesised audio
+pub struct Mark {
+    /// The name of the mark
+    pub mark: String,
+    /// The time in seconds at which the mark is reached in the audio
+    pub time: f64,
+}
+
+impl From<(String, f64)> for Mark {
+    fn from((mark, time): (String, f64)) -> Self {
+        Self { mark, time }
+    }
+}
+
+impl From<Mark> for (String, f64) {
+    fn from(mark: Mark) -> Self {
+        (mark.mark, mark.time)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+/// An item received while synthesising over a WebSocket connection
+pub enum SynthesisEvent {
+    /// A chunk of the synthesised audio
+    Audio(Bytes),
+    /// Timing information for words in the input
+    Words(Vec<WordTiming>),
+    /// Timing information for SSML `<mark>` elements in the input
+    Marks(Vec<Mark>),
+}
+
+/// A stream of [`SynthesisEvent`]s received over a WebSocket connection. The stream ends once the
+/// service has sent all of the audio
+///
+/// [`SynthesisEvent`]: super::SynthesisEvent
+pub struct SynthesisStream {
+    inner: Pin<Box<dyn Stream<Item = Result<SynthesisEvent, SynthesisError>> + Send>>,
+}
+
+impl Stream for SynthesisStream {
+    type Item = Result<SynthesisEvent, SynthesisError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ServerMessage {
+    Words { words: Vec<WordTiming> },
+    Marks { marks: Vec<Mark> },
+    Error { error: String },
+    Other(#[allow(dead_code)] serde_json::Value),
+}
+
+struct Session {
+    socket: Socket,
+    done: bool,
+}
+
+impl Session {
+    async fn next_event(mut self) -> Option<(Result<SynthesisEvent, SynthesisError>, Self)> {
+        if self.done {
+            return None;
+        }
+        loop {
+            match self.socket.next().await {
+                Some(Ok(Message::Binary(audio))) => {
+                    return Some((Ok(SynthesisEvent::Audio(Bytes::from(audio))), self))
+                }
+                Some(Ok(Message::Text(text))) => match serde_json::from_str(&text) {
+                    Ok(ServerMessage::Words { words }) => {
+                        return Some((Ok(SynthesisEvent::Words(words)), self))
+                    }
+                    Ok(ServerMessage::Marks { marks }) => {
+                        return Some((Ok(SynthesisEvent::Marks(marks)), self))
+                    }
+                    Ok(ServerMessage::Error { error }) => return self.fail(error),
+                    // the content type of the binary stream and warnings
+                    Ok(ServerMessage::Other(_)) => continue,
+                    Err(e) => return self.fail(e.to_string()),
+                },
+                Some(Ok(Message::Close(_))) | None => return None,
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => return self.fail(e.to_string()),
+            }
+        }
+    }
+
+    fn fail(mut self, reason: String) -> Option<(Result<SynthesisEvent, SynthesisError>, Self)> {
+        self.done = true;
+        Some((Err(SynthesisError::WebSocketError(reason)), self))
+    }
+}
+
+impl TextToSpeech {
+    /// Synthesises text to audio over a WebSocket connection. Besides the audio, the service sends the times at which each word is spoken and at which any SSML `<mark>` elements in the input are reached, which is useful for lip-syncing or highlighting text as it is spoken
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The [`input`] to synthesise, either plain text or SSML. A `&str` or `String` is treated as plain text
+    /// * `format` - The requested [`AudioFormat`] (MIME type) of the audio. Defaults to [`AudioOggCodecsOpus`]
+    /// * `customisation_id` - The customisation ID (GUID) of a custom [`model`] to use for the synthesis. Omit the parameter to use the specified voice with no customisation
+    ///
+    /// [`input`]: super::SynthesisInput
+    /// [`AudioFormat`]: super::AudioFormat
+    /// [`AudioOggCodecsOpus`]: super::AudioFormat::AudioOggCodecsOpus
+    /// [`model`]: crate::tts::customisations::Model
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::{synthesis::SynthesisEvent, TextToSpeech},
+    /// # };
+    /// # use futures_util::StreamExt;
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let mut events = tts.synthesise_ws("Hey there", None, None).await?;
+    /// while let Some(event) = events.next().await {
+    ///     match event? {
+    ///         SynthesisEvent::Audio(chunk) => println!("received {} bytes", chunk.len()),
+    ///         SynthesisEvent::Words(words) => println!("{:#?}", words),
+    ///         SynthesisEvent::Marks(marks) => println!("{:#?}", marks),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn synthesise_ws(
+        &self,
+        text: impl Into<SynthesisInput>,
+        format: Option<AudioFormat>,
+        customisation_id: Option<&str>,
+    ) -> Result<SynthesisStream, SynthesisError> {
+        if let Some(format) = &format {
+            format.validate()?;
+        }
+        let mut url = self.service_url.clone();
+        url.set_path("v1/synthesize");
+        let scheme = match url.scheme() {
+            "http" => "ws",
+            _ => "wss",
+        };
+        url.set_scheme(scheme)
+            .map_err(|_| SynthesisError::WebSocketError(format!("invalid url: {url}")))?;
+        url.query_pairs_mut().append_pair("voice", self.voice.id());
+        if let Some(customisation_id) = customisation_id {
+            url.query_pairs_mut()
+                .append_pair("customization_id", customisation_id);
+        }
+        let mut request = url
+            .as_str()
+            .into_client_request()
+            .map_err(|e| SynthesisError::WebSocketError(e.to_string()))?;
+        request.headers_mut().extend(self.client.headers().clone());
+
+        let (mut socket, _) = connect_async(request)
+            .await
+            .map_err(|e| SynthesisError::WebSocketError(e.to_string()))?;
+
+        // the socket expects the plain MIME type rather than the url encoded one
+        let accept = format.unwrap_or_default().mime();
+        let start = serde_json::json!({
+            "text": text.into().as_str(),
+            "accept": accept,
+            "timings": ["words"],
+        });
+        socket
+            .send(Message::Text(start.to_string()))
+            .await
+            .map_err(|e| SynthesisError::WebSocketError(e.to_string()))?;
+
+        let session = Session {
+            socket,
+            done: false,
+        };
+        let inner = stream::unfold(session, Session::next_event);
+        Ok(SynthesisStream {
+            inner: Box::pin(inner),
+        })
+    }
+}
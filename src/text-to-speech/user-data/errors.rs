@@ -1,4 +1,7 @@
 use thiserror::Error;
+
+use crate::error::impl_from_common_error;
+
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum DeleteLabeledDataError {
@@ -18,3 +21,5 @@ pub enum DeleteLabeledDataError {
     #[error("{0}")]
     UnmappedResponse(u16),
 }
+
+impl_from_common_error!(DeleteLabeledDataError);
@@ -1,12 +1,13 @@
-use reqwest::{Method, Request, StatusCode, Url, Version};
+use reqwest::{Method, Request, StatusCode};
 pub mod errors;
 
 use self::errors::DeleteLabeledDataError;
+use crate::error::CommonError;
 
 use super::TextToSpeech;
 
-impl TextToSpeech<'_> {
-    /// Deletes all data that is associated with a specified customer ID. The method deletes all data for the customer ID, regardless of the method by which the information was added. The method has no effect if no data is associated with the customer ID. You must issue the request with credentials for the same instance of the service that was used to associate the customer ID with the data
+impl TextToSpeech {
+    /// Deletes all data that is associated with a specified customer ID, as set with [`set_customer_id()`]. The method deletes all data for the customer ID, regardless of the method by which the information was added. The method has no effect if no data is associated with the customer ID. You must issue the request with credentials for the same instance of the service that was used to associate the customer ID with the data
     ///
     /// # Parameters
     /// * `customer_id` - The customer ID for which all data is to be deleted
@@ -25,30 +26,27 @@ impl TextToSpeech<'_> {
     /// # Ok(())
     /// # }
     /// ```
+    /// [`set_customer_id()`]: crate::tts::TextToSpeech::set_customer_id()
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn delete_labeled_data(
         &self,
         customer_id: impl AsRef<str>,
     ) -> Result<(), DeleteLabeledDataError> {
-        let mut url = Url::parse(self.service_url).unwrap();
-        url.set_path(&format!("v1/user_data/{}", customer_id.as_ref()));
-        let mut req = Request::new(Method::DELETE, url);
-
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        let mut url = self.service_url.clone();
+        url.set_path("v1/user_data");
+        url.query_pairs_mut()
+            .append_pair("customer_id", customer_id.as_ref());
+        let req = Request::new(Method::DELETE, url);
 
         let client = self.get_client();
         let response = client.execute(req).await?;
         match response.status() {
             StatusCode::NO_CONTENT => Ok(()),
             StatusCode::BAD_REQUEST => Err(DeleteLabeledDataError::BadRequest400),
-            StatusCode::INTERNAL_SERVER_ERROR => {
-                Err(DeleteLabeledDataError::InternalServerError500)
-            }
-            StatusCode::SERVICE_UNAVAILABLE => Err(DeleteLabeledDataError::ServiceUnavailable503),
-            _ => Err(DeleteLabeledDataError::UnmappedResponse(
-                response.status().as_u16(),
-            )),
+            _ => Err(CommonError::from_response(response).await.into()),
         }
     }
 }
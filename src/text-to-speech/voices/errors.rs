@@ -1,26 +1,34 @@
 use thiserror::Error;
 
+use crate::error::{impl_from_common_error, WatsonError};
+
 #[derive(Error, Debug)]
 #[non_exhaustive]
 /// Errors that may be returned when listing [`Watson Voices`]
 ///
 /// [`Watson Voices`]: crate::tts::voices::WatsonVoice
 pub enum ListVoicesError {
-    #[error("The request specified an Accept header with an incompatible content type.")]
-    /// The request specified an Accept header with an incompatible content type
-    NotAcceptable406, //406
-    #[error("The request specified an unacceptable media type.")]
+    #[error("The request specified an Accept header with an incompatible content type: {0}")]
+    /// The request specified an Accept header with an incompatible content type. Requests ask for `application/json`, so this means that the content negotiation failed, for example because a proxy or a preconfigured client changed the header
+    NotAcceptable406(WatsonError), //406
+    #[error("The request specified an unacceptable media type: {0}")]
     /// The request specified an unacceptable media type
-    UnsupportedMediaType415, // 415
-    #[error("The service experienced an internal error.")]
+    UnsupportedMediaType415(WatsonError), // 415
+    #[error("The service experienced an internal error: {0}")]
     /// The service experienced an internal error
-    InternalServerError500, // 500
-    #[error("The service is currently unavailable.")]
+    InternalServerError500(WatsonError), // 500
+    #[error("The service is currently unavailable: {0}")]
     /// The service is currently unavailable
-    ServiceUnavailable503, // 503
+    ServiceUnavailable503(WatsonError), // 503
     /// There was an error making the request
     #[error("There was an error establishing the connection")]
     ConnectionError(#[from] reqwest::Error),
+    /// The request did not complete within the timeout that was given for it
+    #[error("The request timed out after {0:?}")]
+    Timeout(std::time::Duration),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response that the server sends back
     #[error("{0}")]
     UnmappedResponse(u16),
@@ -38,25 +46,46 @@ pub enum GetVoiceError {
     #[error("A required input parameter is null or a specified input parameter or header value is invalid or not supported. Please check your customisation id")]
     /// A required input parameter is null or a specified input parameter or header value is invalid or not supported. Please check your customisation id
     BadRequest400,
+    #[error("The url {0} does not belong to the configured service")]
+    /// The url passed to [`get_voice_by_url()`] could not be parsed, or its scheme, host or port differ from those of the service url that the client was created with. No request is made
+    ///
+    /// [`get_voice_by_url()`]: crate::tts::TextToSpeech::get_voice_by_url()
+    InvalidUrl(String),
     #[error("The specified customisation_id {0} is invalid for the requesting credentials")]
     /// The specified customisation_id is invalid for the requesting credentials
     Unauthorised401(String),
-    #[error("The request specified an Accept header with an incompatible content type.")]
-    /// The request specified an Accept header with an incompatible content type
-    NotAcceptable406,
-    #[error("The request specified an unacceptable media type.")]
+    #[error("The request specified an Accept header with an incompatible content type: {0}")]
+    /// The request specified an Accept header with an incompatible content type. Requests ask for `application/json`, so this means that the content negotiation failed, for example because a proxy or a preconfigured client changed the header
+    NotAcceptable406(WatsonError),
+    #[error("The request specified an unacceptable media type: {0}")]
     /// The request specified an unacceptable media type
-    UnsupportedMediaType415,
-    #[error("The service experienced an internal error.")]
+    UnsupportedMediaType415(WatsonError),
+    #[error("The service experienced an internal error: {0}")]
     /// The service experienced an internal error
-    InternalServerError500,
-    #[error("The service is currently unavailable.")]
+    InternalServerError500(WatsonError),
+    #[error("The service is currently unavailable: {0}")]
     /// The service is currently unavailable
-    ServiceUnavailable503,
+    ServiceUnavailable503(WatsonError),
     /// There was an error making the request
     #[error("There was an error establishing the connection")]
     ConnectionError(#[from] reqwest::Error),
+    /// The response body could not be deserialised. Contains the reason
+    #[error("The response could not be deserialised: {0}")]
+    DeserializationError(String),
     /// The response that the server sends back
     #[error("{0}")]
     UnmappedResponse(u16),
 }
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+/// Errors that may be returned when parsing a [`Watson Voice`] from its id
+///
+/// [`Watson Voice`]: crate::tts::voices::WatsonVoice
+pub enum ParseVoiceError {
+    #[error("{0} is not a known voice id")]
+    /// The string is not the id of any known voice
+    UnknownVoice(String),
+}
+
+impl_from_common_error!(with_body: ListVoicesError, GetVoiceError);
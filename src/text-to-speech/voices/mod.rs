@@ -1,14 +1,26 @@
-use reqwest::{Method, Request, StatusCode, Url, Version};
+use std::{
+    fmt::Display,
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
+
+use reqwest::{Method, Request, Response, StatusCode, Url};
 use serde::{Deserialize, Serialize};
 
 /// Errors that may be returned in making Voice requests
 pub mod errors;
 
-use crate::tts::voices::errors::GetVoiceError;
+use crate::{
+    client::ServiceClient, error::WatsonError, rate_limit::RateLimit,
+    tts::voices::errors::GetVoiceError,
+};
 
-use self::errors::ListVoicesError;
+use self::errors::{ListVoicesError, ParseVoiceError};
 
-use super::{customisations::Model, TextToSpeech};
+use super::{
+    customisations::{Language, Model},
+    TextToSpeech,
+};
 #[derive(Clone, Debug, Eq, PartialEq, Default, Serialize, Deserialize)]
 /// Voices available for use in Watson
 pub struct Voice {
@@ -16,8 +28,10 @@ pub struct Voice {
     /// The URI of the voice
     pub url: String,
     #[serde(rename = "gender")]
-    /// The gender of the voice: male or female
-    pub gender: String,
+    /// The [`gender`] of the voice
+    ///
+    /// [`gender`]: self::Gender
+    pub gender: Gender,
     #[serde(rename = "name")]
     /// The name of the voice. Use this as the voice identifier in all requests
     pub name: String,
@@ -38,6 +52,70 @@ pub struct Voice {
     pub customisation: Option<Box<Model>>,
 }
 
+impl Voice {
+    /// The [`language`] of the voice as a [`Language`], or [`None`] if it is not one that this library knows about
+    ///
+    /// [`language`]: Self::language
+    /// [`Language`]: super::customisations::Language
+    pub fn language_enum(&self) -> Option<Language> {
+        self.language.parse().ok()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+/// The gender of a voice
+pub enum Gender {
+    /// A male voice
+    Male,
+    /// A female voice
+    Female,
+    /// A gender that is not known to this library, as the service reported it
+    Other(String),
+}
+
+impl Default for Gender {
+    fn default() -> Self {
+        Gender::Other(String::default())
+    }
+}
+
+impl Gender {
+    /// The value that the server uses for a particular gender
+    pub fn id(&self) -> &str {
+        match self {
+            Gender::Male => "male",
+            Gender::Female => "female",
+            Gender::Other(gender) => gender,
+        }
+    }
+}
+
+impl Display for Gender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+impl From<String> for Gender {
+    fn from(gender: String) -> Self {
+        match gender.as_str() {
+            "male" => Gender::Male,
+            "female" => Gender::Female,
+            _ => Gender::Other(gender),
+        }
+    }
+}
+
+impl From<Gender> for String {
+    fn from(gender: Gender) -> Self {
+        match gender {
+            Gender::Other(gender) => gender,
+            gender => gender.id().to_owned(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Default, Serialize, Deserialize)]
 /// Additional service features that are supported with the voice
 pub struct SupportedFeatures {
@@ -49,7 +127,7 @@ pub struct SupportedFeatures {
     pub voice_transformation: bool,
 }
 
-#[derive(Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 /// All voices that Watson can use
 pub enum WatsonVoice {
@@ -65,35 +143,63 @@ pub enum WatsonVoice {
     DeDeErikaV3,
     /// Craig - English (Australia)
     EnAuCraig,
+    /// HeidiExpressive - English (Australia)
+    EnAuHeidiExpressive,
+    /// JackExpressive - English (Australia)
+    EnAuJackExpressive,
     /// Madison - English (Australia)
     EnAuMadison,
     /// Steve - English (Australia)
     EnAuSteve,
     /// CharlotteV3 - English (United Kingdom)
     EnGbCharlotteV3,
+    /// ChloeNatural - English (United Kingdom)
+    EnGbChloeNatural,
+    /// GeorgeNatural - English (United Kingdom)
+    EnGbGeorgeNatural,
     /// JamesV3 - English (United Kingdom)
     EnGbJamesV3,
     /// KateV3 - English (United Kingdom)
     EnGbKateV3,
+    /// AllisonExpressive - English (United States)
+    EnUsAllisonExpressive,
     /// AllisonV3 - English (United States)
     EnUsAllisonV3,
+    /// EllieNatural - English (United States)
+    EnUsEllieNatural,
     /// EmilyV3 - English (United States)
     EnUsEmilyV3,
+    /// EmmaExpressive - English (United States)
+    EnUsEmmaExpressive,
+    /// EmmaNatural - English (United States)
+    EnUsEmmaNatural,
+    /// EthanNatural - English (United States)
+    EnUsEthanNatural,
     /// HenryV3 - English (United States)
     EnUsHenryV3,
+    /// JacksonNatural - English (United States)
+    EnUsJacksonNatural,
     /// KevinV3 - English (United States)
     EnUsKevinV3,
+    /// LisaExpressive - English (United States)
+    EnUsLisaExpressive,
     /// LisaV3 - English (United States)
     EnUsLisaV3,
+    /// MichaelExpressive - English (United States)
+    EnUsMichaelExpressive,
     #[default]
     /// MichaelV3 - English (United States)
     EnUsMichaelV3,
     /// OliviaV3 - English (United States)
     EnUsOliviaV3,
+    /// VictoriaNatural - English (United States)
+    EnUsVictoriaNatural,
     /// EnriqueV3 - Spanish (Spain)
     EsEsEnriqueV3,
     /// LauraV3 - Spanish (Spain)
     EsEsLauraV3,
+    /// DanielaExpressive - Spanish (Latin America)
+    EsLaDanielaExpressive,
     /// SofiaV3 - Spanish (Latin America)
     EsLaSofiaV3,
     /// SofiaV3 - Spanish (United States)
@@ -110,6 +216,8 @@ pub enum WatsonVoice {
     JaJpEmiV3,
     /// Hyunjun - Koren (South Korea)
     KoKrHyunjun,
+    /// JinV3 - Koren (South Korea)
+    KoKrJinV3,
     /// SiWoo - Koren (South Korea)
     KoKrSiWoo,
     /// Youngmi - Koren (South Korea)
@@ -124,8 +232,16 @@ pub enum WatsonVoice {
     NlNlEmma,
     /// Liam - Dutch (Netherlands)
     NlNlLiam,
+    /// MerelV3 - Dutch (Netherlands)
+    NlNlMerelV3,
+    /// CamilaNatural - Portuguese (Brazil)
+    PtBrCamilaNatural,
     /// Isabela - Portuguese (Brazil)
     PtBrIsabelaV3,
+    /// LucasExpressive - Portuguese (Brazil)
+    PtBrLucasExpressive,
+    /// LucasNatural - Portuguese (Brazil)
+    PtBrLucasNatural,
     /// Ingrid - Swedish (Sweden)
     SvSeIngrid,
     /// LiNa - Chinese (PRC)
@@ -134,32 +250,112 @@ pub enum WatsonVoice {
     ZhCnWangWei,
     /// ZhangJing - Chinese (PRC)
     ZhCnZhangJing,
+    /// A voice that has no variant of its own, by the id that the server uses for it, for example one that IBM released after this version of the library. [`id()`] returns it verbatim, so it is up to you to make sure that the service knows it
+    ///
+    /// [`id()`]: Self::id()
+    Custom(String),
 }
 
-impl ToString for WatsonVoice {
+const ALL_VOICES: &[WatsonVoice] = &[
+    WatsonVoice::ArMsOmar,
+    WatsonVoice::CsCzAlena,
+    WatsonVoice::DeDeBirgitV3,
+    WatsonVoice::DeDeDieterV3,
+    WatsonVoice::DeDeErikaV3,
+    WatsonVoice::EnAuCraig,
+    WatsonVoice::EnAuHeidiExpressive,
+    WatsonVoice::EnAuJackExpressive,
+    WatsonVoice::EnAuMadison,
+    WatsonVoice::EnAuSteve,
+    WatsonVoice::EnGbCharlotteV3,
+    WatsonVoice::EnGbChloeNatural,
+    WatsonVoice::EnGbGeorgeNatural,
+    WatsonVoice::EnGbJamesV3,
+    WatsonVoice::EnGbKateV3,
+    WatsonVoice::EnUsAllisonExpressive,
+    WatsonVoice::EnUsAllisonV3,
+    WatsonVoice::EnUsEllieNatural,
+    WatsonVoice::EnUsEmilyV3,
+    WatsonVoice::EnUsEmmaExpressive,
+    WatsonVoice::EnUsEmmaNatural,
+    WatsonVoice::EnUsEthanNatural,
+    WatsonVoice::EnUsHenryV3,
+    WatsonVoice::EnUsJacksonNatural,
+    WatsonVoice::EnUsKevinV3,
+    WatsonVoice::EnUsLisaExpressive,
+    WatsonVoice::EnUsLisaV3,
+    WatsonVoice::EnUsMichaelExpressive,
+    WatsonVoice::EnUsMichaelV3,
+    WatsonVoice::EnUsOliviaV3,
+    WatsonVoice::EnUsVictoriaNatural,
+    WatsonVoice::EsEsEnriqueV3,
+    WatsonVoice::EsEsLauraV3,
+    WatsonVoice::EsLaDanielaExpressive,
+    WatsonVoice::EsLaSofiaV3,
+    WatsonVoice::EsUsSofiaV3,
+    WatsonVoice::FrCaLouiseV3,
+    WatsonVoice::FrFrNicolasV3,
+    WatsonVoice::FrFrReneeV3,
+    WatsonVoice::ItItFrancescaV3,
+    WatsonVoice::JaJpEmiV3,
+    WatsonVoice::KoKrHyunjun,
+    WatsonVoice::KoKrJinV3,
+    WatsonVoice::KoKrSiWoo,
+    WatsonVoice::KoKrYoungmi,
+    WatsonVoice::KoKrYuna,
+    WatsonVoice::NlBeAdele,
+    WatsonVoice::NlBeBram,
+    WatsonVoice::NlNlEmma,
+    WatsonVoice::NlNlLiam,
+    WatsonVoice::NlNlMerelV3,
+    WatsonVoice::PtBrCamilaNatural,
+    WatsonVoice::PtBrIsabelaV3,
+    WatsonVoice::PtBrLucasExpressive,
+    WatsonVoice::PtBrLucasNatural,
+    WatsonVoice::SvSeIngrid,
+    WatsonVoice::ZhCnLiNa,
+    WatsonVoice::ZhCnWangWei,
+    WatsonVoice::ZhCnZhangJing,
+];
+
+impl Display for WatsonVoice {
     /// The human readable format for Watson Voices
-    fn to_string(&self) -> String {
-        match &self {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match &self {
             WatsonVoice::ArMsOmar => "ArMsOmar",
             WatsonVoice::CsCzAlena => "Alena - Czech (Czechia)",
             WatsonVoice::DeDeBirgitV3 => "Birgit - German (Germany)",
             WatsonVoice::DeDeDieterV3 => "DieterV3 - German (Germany)",
             WatsonVoice::DeDeErikaV3 => "ErikaV3 - German (Germany)",
             WatsonVoice::EnAuCraig => "Craig - English (Australia)",
+            WatsonVoice::EnAuHeidiExpressive => "HeidiExpressive - English (Australia)",
+            WatsonVoice::EnAuJackExpressive => "JackExpressive - English (Australia)",
             WatsonVoice::EnAuMadison => "Madison - English (Australia)",
             WatsonVoice::EnAuSteve => "Steve - English (Australia)",
             WatsonVoice::EnGbCharlotteV3 => "CharlotteV3 - English (United Kingdom)",
+            WatsonVoice::EnGbChloeNatural => "ChloeNatural - English (United Kingdom)",
+            WatsonVoice::EnGbGeorgeNatural => "GeorgeNatural - English (United Kingdom)",
             WatsonVoice::EnGbJamesV3 => "JamesV3 - English (United Kingdom)",
             WatsonVoice::EnGbKateV3 => "KateV3 - English (United Kingdom)",
+            WatsonVoice::EnUsAllisonExpressive => "AllisonExpressive - English (United States)",
             WatsonVoice::EnUsAllisonV3 => "AllisonV3 - English (United States)",
+            WatsonVoice::EnUsEllieNatural => "EllieNatural - English (United States)",
             WatsonVoice::EnUsEmilyV3 => "EmilyV3 - English (United States)",
+            WatsonVoice::EnUsEmmaExpressive => "EmmaExpressive - English (United States)",
+            WatsonVoice::EnUsEmmaNatural => "EmmaNatural - English (United States)",
+            WatsonVoice::EnUsEthanNatural => "EthanNatural - English (United States)",
             WatsonVoice::EnUsHenryV3 => "HenryV3 - English (United States)",
+            WatsonVoice::EnUsJacksonNatural => "JacksonNatural - English (United States)",
             WatsonVoice::EnUsKevinV3 => "KevinV3 - English (United States)",
+            WatsonVoice::EnUsLisaExpressive => "LisaExpressive - English (United States)",
             WatsonVoice::EnUsLisaV3 => "LisaV3 - English (United States)",
+            WatsonVoice::EnUsMichaelExpressive => "MichaelExpressive - English (United States)",
             WatsonVoice::EnUsMichaelV3 => "MichaelV3 - English (United States)",
             WatsonVoice::EnUsOliviaV3 => "OliviaV3 - English (United States)",
+            WatsonVoice::EnUsVictoriaNatural => "VictoriaNatural - English (United States)",
             WatsonVoice::EsEsEnriqueV3 => "EnriqueV3 - Spanish (Spain)",
             WatsonVoice::EsEsLauraV3 => "LauraV3 - Spanish (Spain)",
+            WatsonVoice::EsLaDanielaExpressive => "DanielaExpressive - Spanish (Latin America)",
             WatsonVoice::EsLaSofiaV3 => "SofiaV3 - Spanish (Latin America)",
             WatsonVoice::EsUsSofiaV3 => "SofiaV3 - Spanish (United States)",
             WatsonVoice::FrCaLouiseV3 => "LouiseV3 - French (Canada)",
@@ -168,6 +364,7 @@ impl ToString for WatsonVoice {
             WatsonVoice::ItItFrancescaV3 => "FrancescaV3 - Italian (Italy)",
             WatsonVoice::JaJpEmiV3 => "EmiV3 - Japanese (Japan)",
             WatsonVoice::KoKrHyunjun => "Hyunjun - Koren (South Korea)",
+            WatsonVoice::KoKrJinV3 => "JinV3 - Koren (South Korea)",
             WatsonVoice::KoKrSiWoo => "SiWoo - Koren (South Korea)",
             WatsonVoice::KoKrYoungmi => "Youngmi - Koren (South Korea)",
             WatsonVoice::KoKrYuna => "Yuna - Koren (South Korea)",
@@ -175,17 +372,87 @@ impl ToString for WatsonVoice {
             WatsonVoice::NlBeBram => "Bram - Dutch (Belgium)",
             WatsonVoice::NlNlEmma => "Emma - Dutch (Netherlands)",
             WatsonVoice::NlNlLiam => "Liam - Dutch (Netherlands)",
+            WatsonVoice::NlNlMerelV3 => "MerelV3 - Dutch (Netherlands)",
+            WatsonVoice::PtBrCamilaNatural => "CamilaNatural - Portuguese (Brazil)",
             WatsonVoice::PtBrIsabelaV3 => "Isabela - Portuguese (Brazil)",
+            WatsonVoice::PtBrLucasExpressive => "LucasExpressive - Portuguese (Brazil)",
+            WatsonVoice::PtBrLucasNatural => "LucasNatural - Portuguese (Brazil)",
             WatsonVoice::SvSeIngrid => "Ingrid - Swedish (Sweden)",
             WatsonVoice::ZhCnLiNa => "LiNa - Chinese (PRC)",
             WatsonVoice::ZhCnWangWei => "WangWei - Chinese (PRC)",
             WatsonVoice::ZhCnZhangJing => "ZhangJing - Chinese (PRC)",
-        }
-        .to_string()
+            WatsonVoice::Custom(id) => id,
+        };
+        write!(f, "{name}")
     }
 }
 
 impl WatsonVoice {
+    /// Every voice that this library knows about, in the order in which they are declared, apart from [`Custom`]. Use it to list the voices in a menu, with their [`id()`] and human readable name
+    ///
+    /// # Example
+    /// ```
+    /// # use ibm_watson::tts::voices::WatsonVoice;
+    /// for voice in WatsonVoice::all() {
+    ///     println!("{voice}: {}", voice.id());
+    /// }
+    /// ```
+    ///
+    /// [`Custom`]: Self::Custom
+    /// [`id()`]: Self::id()
+    pub fn all() -> &'static [WatsonVoice] {
+        ALL_VOICES
+    }
+
+    /// A sensible voice for a language, for example one that was detected by the Language Translator. `lang` is either a language and region such as `fr-FR`, or a language alone such as `fr`, which picks its most widely spoken region. A region that has no voices of its own, such as `en-IN`, falls back to the language alone. The case of `lang` is ignored
+    ///
+    /// # Parameters
+    ///
+    /// * `lang` - The language code, for example `en-US` or `en`
+    ///
+    /// # Returns
+    ///
+    /// The [`default voice`] of the language, or [`None`] if the service has no voices for it
+    ///
+    /// # Example
+    /// ```
+    /// # use ibm_watson::tts::voices::WatsonVoice;
+    /// assert_eq!(
+    ///     WatsonVoice::default_for_language("en-US"),
+    ///     Some(WatsonVoice::EnUsMichaelV3)
+    /// );
+    /// assert_eq!(
+    ///     WatsonVoice::default_for_language("fr"),
+    ///     Some(WatsonVoice::FrFrReneeV3)
+    /// );
+    /// assert_eq!(WatsonVoice::default_for_language("xx"), None);
+    /// ```
+    ///
+    /// [`default voice`]: super::customisations::Language::default_voice()
+    /// [`None`]: std::option::Option::None
+    pub fn default_for_language(lang: &str) -> Option<WatsonVoice> {
+        let language = lang.parse().ok().or_else(|| {
+            let primary = lang.split(['-', '_']).next().unwrap_or_default();
+            match primary.to_ascii_lowercase().as_str() {
+                "ar" => Some(Language::ArMs),
+                "cs" => Some(Language::CsCz),
+                "de" => Some(Language::DeDe),
+                "en" => Some(Language::EnUs),
+                "es" => Some(Language::EsEs),
+                "fr" => Some(Language::FrFr),
+                "it" => Some(Language::ItIt),
+                "ja" => Some(Language::JaJp),
+                "ko" => Some(Language::KoKr),
+                "nl" => Some(Language::NlNl),
+                "pt" => Some(Language::PtBr),
+                "sv" => Some(Language::SvSe),
+                "zh" => Some(Language::ZhCn),
+                _ => None,
+            }
+        })?;
+        Some(language.default_voice())
+    }
+
     /// The id that the server expects for that voice
     pub fn id(&self) -> &str {
         match &self {
@@ -195,20 +462,34 @@ impl WatsonVoice {
             WatsonVoice::DeDeDieterV3 => "de-DE_DieterV3Voice",
             WatsonVoice::DeDeErikaV3 => "de-DE_ErikaV3Voice",
             WatsonVoice::EnAuCraig => "en-AU_CraigVoice",
+            WatsonVoice::EnAuHeidiExpressive => "en-AU_HeidiExpressive",
+            WatsonVoice::EnAuJackExpressive => "en-AU_JackExpressive",
             WatsonVoice::EnAuMadison => "en-AU_MadisonVoice",
             WatsonVoice::EnAuSteve => "en-AU_SteveVoice",
             WatsonVoice::EnGbCharlotteV3 => "en-GB_CharlotteV3Voice",
+            WatsonVoice::EnGbChloeNatural => "en-GB_ChloeNatural",
+            WatsonVoice::EnGbGeorgeNatural => "en-GB_GeorgeNatural",
             WatsonVoice::EnGbJamesV3 => "en-GB_JamesV3Voice",
             WatsonVoice::EnGbKateV3 => "en-GB_KateV3Voice",
+            WatsonVoice::EnUsAllisonExpressive => "en-US_AllisonExpressive",
             WatsonVoice::EnUsAllisonV3 => "en-US_AllisonV3Voice",
+            WatsonVoice::EnUsEllieNatural => "en-US_EllieNatural",
             WatsonVoice::EnUsEmilyV3 => "en-US_EmilyV3Voice",
+            WatsonVoice::EnUsEmmaExpressive => "en-US_EmmaExpressive",
+            WatsonVoice::EnUsEmmaNatural => "en-US_EmmaNatural",
+            WatsonVoice::EnUsEthanNatural => "en-US_EthanNatural",
             WatsonVoice::EnUsHenryV3 => "en-US_HenryV3Voice",
+            WatsonVoice::EnUsJacksonNatural => "en-US_JacksonNatural",
             WatsonVoice::EnUsKevinV3 => "en-US_KevinV3Voice",
+            WatsonVoice::EnUsLisaExpressive => "en-US_LisaExpressive",
             WatsonVoice::EnUsLisaV3 => "en-US_LisaV3Voice",
+            WatsonVoice::EnUsMichaelExpressive => "en-US_MichaelExpressive",
             WatsonVoice::EnUsMichaelV3 => "en-US_MichaelV3Voice",
             WatsonVoice::EnUsOliviaV3 => "en-US_OliviaV3Voice",
+            WatsonVoice::EnUsVictoriaNatural => "en-US_VictoriaNatural",
             WatsonVoice::EsEsEnriqueV3 => "es-ES_EnriqueV3Voice",
             WatsonVoice::EsEsLauraV3 => "es-ES_LauraV3Voice",
+            WatsonVoice::EsLaDanielaExpressive => "es-LA_DanielaExpressive",
             WatsonVoice::EsLaSofiaV3 => "es-LA_SofiaV3Voice",
             WatsonVoice::EsUsSofiaV3 => "es-US_SofiaV3Voice",
             WatsonVoice::FrCaLouiseV3 => "fr-CA_LouiseV3Voice",
@@ -217,6 +498,7 @@ impl WatsonVoice {
             WatsonVoice::ItItFrancescaV3 => "it-IT_FrancescaV3Voice",
             WatsonVoice::JaJpEmiV3 => "ja-JP_EmiV3Voice",
             WatsonVoice::KoKrHyunjun => "ko-KR_HyunjunVoice",
+            WatsonVoice::KoKrJinV3 => "ko-KR_JinV3Voice",
             WatsonVoice::KoKrSiWoo => "ko-KR_SiWooVoice",
             WatsonVoice::KoKrYoungmi => "ko-KR_YoungmiVoice",
             WatsonVoice::KoKrYuna => "ko-KR_YunaVoice",
@@ -224,16 +506,93 @@ impl WatsonVoice {
             WatsonVoice::NlBeBram => "nl-BE_BramVoice",
             WatsonVoice::NlNlEmma => "nl-NL_EmmaVoice",
             WatsonVoice::NlNlLiam => "nl-NL_LiamVoice",
+            WatsonVoice::NlNlMerelV3 => "nl-NL_MerelV3Voice",
+            WatsonVoice::PtBrCamilaNatural => "pt-BR_CamilaNatural",
             WatsonVoice::PtBrIsabelaV3 => "pt-BR_IsabelaV3Voice",
+            WatsonVoice::PtBrLucasExpressive => "pt-BR_LucasExpressive",
+            WatsonVoice::PtBrLucasNatural => "pt-BR_LucasNatural",
             WatsonVoice::SvSeIngrid => "sv-SE_IngridVoice",
             WatsonVoice::ZhCnLiNa => "zh-CN_LiNaVoice",
             WatsonVoice::ZhCnWangWei => "zh-CN_WangWeiVoice",
             WatsonVoice::ZhCnZhangJing => "zh-CN_ZhangJingVoice",
+            WatsonVoice::Custom(id) => id,
+        }
+    }
+}
+
+impl FromStr for WatsonVoice {
+    type Err = ParseVoiceError;
+
+    /// Parses a voice from the id that the server uses for it, for example `en-GB_KateV3Voice`. Ids that this library does not know about are rejected; wrap them in [`Custom`] to use them anyway
+    ///
+    /// [`Custom`]: Self::Custom
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ar-MS_OmarVoice" => Ok(WatsonVoice::ArMsOmar),
+            "cs-CZ_AlenaVoice" => Ok(WatsonVoice::CsCzAlena),
+            "de-DE_BirgitV3Voice" => Ok(WatsonVoice::DeDeBirgitV3),
+            "de-DE_DieterV3Voice" => Ok(WatsonVoice::DeDeDieterV3),
+            "de-DE_ErikaV3Voice" => Ok(WatsonVoice::DeDeErikaV3),
+            "en-AU_CraigVoice" => Ok(WatsonVoice::EnAuCraig),
+            "en-AU_HeidiExpressive" => Ok(WatsonVoice::EnAuHeidiExpressive),
+            "en-AU_JackExpressive" => Ok(WatsonVoice::EnAuJackExpressive),
+            "en-AU_MadisonVoice" => Ok(WatsonVoice::EnAuMadison),
+            "en-AU_SteveVoice" => Ok(WatsonVoice::EnAuSteve),
+            "en-GB_CharlotteV3Voice" => Ok(WatsonVoice::EnGbCharlotteV3),
+            "en-GB_ChloeNatural" => Ok(WatsonVoice::EnGbChloeNatural),
+            "en-GB_GeorgeNatural" => Ok(WatsonVoice::EnGbGeorgeNatural),
+            "en-GB_JamesV3Voice" => Ok(WatsonVoice::EnGbJamesV3),
+            "en-GB_KateV3Voice" => Ok(WatsonVoice::EnGbKateV3),
+            "en-US_AllisonExpressive" => Ok(WatsonVoice::EnUsAllisonExpressive),
+            "en-US_AllisonV3Voice" => Ok(WatsonVoice::EnUsAllisonV3),
+            "en-US_EllieNatural" => Ok(WatsonVoice::EnUsEllieNatural),
+            "en-US_EmilyV3Voice" => Ok(WatsonVoice::EnUsEmilyV3),
+            "en-US_EmmaExpressive" => Ok(WatsonVoice::EnUsEmmaExpressive),
+            "en-US_EmmaNatural" => Ok(WatsonVoice::EnUsEmmaNatural),
+            "en-US_EthanNatural" => Ok(WatsonVoice::EnUsEthanNatural),
+            "en-US_HenryV3Voice" => Ok(WatsonVoice::EnUsHenryV3),
+            "en-US_JacksonNatural" => Ok(WatsonVoice::EnUsJacksonNatural),
+            "en-US_KevinV3Voice" => Ok(WatsonVoice::EnUsKevinV3),
+            "en-US_LisaExpressive" => Ok(WatsonVoice::EnUsLisaExpressive),
+            "en-US_LisaV3Voice" => Ok(WatsonVoice::EnUsLisaV3),
+            "en-US_MichaelExpressive" => Ok(WatsonVoice::EnUsMichaelExpressive),
+            "en-US_MichaelV3Voice" => Ok(WatsonVoice::EnUsMichaelV3),
+            "en-US_OliviaV3Voice" => Ok(WatsonVoice::EnUsOliviaV3),
+            "en-US_VictoriaNatural" => Ok(WatsonVoice::EnUsVictoriaNatural),
+            "es-ES_EnriqueV3Voice" => Ok(WatsonVoice::EsEsEnriqueV3),
+            "es-ES_LauraV3Voice" => Ok(WatsonVoice::EsEsLauraV3),
+            "es-LA_DanielaExpressive" => Ok(WatsonVoice::EsLaDanielaExpressive),
+            "es-LA_SofiaV3Voice" => Ok(WatsonVoice::EsLaSofiaV3),
+            "es-US_SofiaV3Voice" => Ok(WatsonVoice::EsUsSofiaV3),
+            "fr-CA_LouiseV3Voice" => Ok(WatsonVoice::FrCaLouiseV3),
+            "fr-FR_NicolasV3Voice" => Ok(WatsonVoice::FrFrNicolasV3),
+            "fr-FR_ReneeV3Voice" => Ok(WatsonVoice::FrFrReneeV3),
+            "it-IT_FrancescaV3Voice" => Ok(WatsonVoice::ItItFrancescaV3),
+            "ja-JP_EmiV3Voice" => Ok(WatsonVoice::JaJpEmiV3),
+            "ko-KR_HyunjunVoice" => Ok(WatsonVoice::KoKrHyunjun),
+            "ko-KR_JinV3Voice" => Ok(WatsonVoice::KoKrJinV3),
+            "ko-KR_SiWooVoice" => Ok(WatsonVoice::KoKrSiWoo),
+            "ko-KR_YoungmiVoice" => Ok(WatsonVoice::KoKrYoungmi),
+            "ko-KR_YunaVoice" => Ok(WatsonVoice::KoKrYuna),
+            "nl-BE_AdeleVoice" => Ok(WatsonVoice::NlBeAdele),
+            "nl-BE_BramVoice" => Ok(WatsonVoice::NlBeBram),
+            "nl-NL_EmmaVoice" => Ok(WatsonVoice::NlNlEmma),
+            "nl-NL_LiamVoice" => Ok(WatsonVoice::NlNlLiam),
+            "nl-NL_MerelV3Voice" => Ok(WatsonVoice::NlNlMerelV3),
+            "pt-BR_CamilaNatural" => Ok(WatsonVoice::PtBrCamilaNatural),
+            "pt-BR_IsabelaV3Voice" => Ok(WatsonVoice::PtBrIsabelaV3),
+            "pt-BR_LucasExpressive" => Ok(WatsonVoice::PtBrLucasExpressive),
+            "pt-BR_LucasNatural" => Ok(WatsonVoice::PtBrLucasNatural),
+            "sv-SE_IngridVoice" => Ok(WatsonVoice::SvSeIngrid),
+            "zh-CN_LiNaVoice" => Ok(WatsonVoice::ZhCnLiNa),
+            "zh-CN_WangWeiVoice" => Ok(WatsonVoice::ZhCnWangWei),
+            "zh-CN_ZhangJingVoice" => Ok(WatsonVoice::ZhCnZhangJing),
+            _ => Err(ParseVoiceError::UnknownVoice(s.to_owned())),
         }
     }
 }
 
-impl TextToSpeech<'_> {
+impl TextToSpeech {
     /// Lists all voices available for use with the service. The information includes the [`name`], [`language`], [`gender`], and other details about the voice.
     /// The ordering of the list of voices can
     /// change from call to call; do not rely on an alphabetized or static list of voices. To see
@@ -256,39 +615,201 @@ impl TextToSpeech<'_> {
     /// [`name`]: super::voices::Voice::name
     /// [`language`]: super::voices::Voice::language
     /// [`gender`]: super::voices::Voice::gender
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn list_voices(&self) -> Result<Vec<Voice>, ListVoicesError> {
-        let mut url = Url::parse(self.service_url).unwrap();
-        Self::set_voices_path(&mut url);
-        let mut req = Request::new(Method::GET, url);
+        let response = self.list_voices_raw().await?;
+        let root: VoiceList = response
+            .json()
+            .await
+            .map_err(|e| ListVoicesError::DeserializationError(e.to_string()))?;
 
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+        Ok(root.voices)
+    }
 
-        let client = self.get_client();
-        let response = client.execute(req).await?;
-        match response.status() {
-            StatusCode::OK => {
-                #[derive(Deserialize)]
-                struct Root {
-                    voices: Vec<Voice>,
-                }
-                let root: Root = response.json().await.unwrap();
-
-                Ok(root.voices)
+    /// Lists all voices like [`list_voices()`], along with the [`RateLimit`] that the service reported in the headers of the response, if it sent one
+    ///
+    /// [`list_voices()`]: Self::list_voices()
+    /// [`RateLimit`]: crate::rate_limit::RateLimit
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let (voices, rate_limit) = tts.list_voices_with_meta().await?;
+    /// if let Some(rate_limit) = rate_limit {
+    ///     println!("{} of {} requests left", rate_limit.remaining, rate_limit.limit);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn list_voices_with_meta(
+        &self,
+    ) -> Result<(Vec<Voice>, Option<RateLimit>), ListVoicesError> {
+        let response = self.list_voices_raw().await?;
+        let rate_limit = RateLimit::from_headers(response.headers());
+        let root: VoiceList = response
+            .json()
+            .await
+            .map_err(|e| ListVoicesError::DeserializationError(e.to_string()))?;
+
+        Ok((root.voices, rate_limit))
+    }
+
+    /// Lists all voices like [`list_voices()`], but gives up if the request has not completed within `timeout`, including reading the list. This overrides any timeout that the client was built with, so that a quick lookup can fail fast
+    ///
+    /// # Parameters
+    ///
+    /// * `timeout` - How long to wait for the request to complete. If it elapses, [`Timeout`] is returned rather than [`ConnectionError`]
+    ///
+    /// [`list_voices()`]: Self::list_voices()
+    /// [`Timeout`]: super::voices::errors::ListVoicesError::Timeout
+    /// [`ConnectionError`]: super::voices::errors::ListVoicesError::ConnectionError
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use std::time::Duration;
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let voices = tts.list_voices_with_timeout(Duration::from_secs(2)).await?;
+    /// println!("Total: {}", voices.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn list_voices_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Vec<Voice>, ListVoicesError> {
+        let response = self.voices_response(Some(timeout)).await?;
+        let root: VoiceList = response.json().await.map_err(|e| {
+            if e.is_timeout() {
+                ListVoicesError::Timeout(timeout)
+            } else {
+                ListVoicesError::DeserializationError(e.to_string())
             }
-            StatusCode::NOT_ACCEPTABLE => Err(ListVoicesError::NotAcceptable406),
-            StatusCode::UNSUPPORTED_MEDIA_TYPE => Err(ListVoicesError::UnsupportedMediaType415),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(ListVoicesError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(ListVoicesError::ServiceUnavailable503),
-            _ => Err(ListVoicesError::UnmappedResponse(
-                response.status().as_u16(),
-            )),
+        })?;
+
+        Ok(root.voices)
+    }
+
+    /// Lists the voices available for use with the service like [`list_voices()`], keeping only those that match the given criteria. The service has no filters of its own, so every voice is still fetched
+    ///
+    /// # Parameters
+    ///
+    /// * `language` - Keep only voices whose [`language`] starts with this language tag, ignoring case. A tag matches whole subtags, so `"en"` matches `en-US` and `en-GB` while `"en-US"` matches only `en-US`. Omit the parameter to keep voices of every language
+    /// * `customisable_only` - If true, keep only voices that can be [`customised`]
+    ///
+    /// [`list_voices()`]: Self::list_voices()
+    /// [`language`]: super::voices::Voice::language
+    /// [`customised`]: super::voices::Voice::customisable
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let english = tts.list_voices_filtered(Some("en"), true).await?;
+    /// println!("Total: {}", english.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn list_voices_filtered(
+        &self,
+        language: Option<&str>,
+        customisable_only: bool,
+    ) -> Result<Vec<Voice>, ListVoicesError> {
+        let voices = self.list_voices().await?;
+        Ok(filter_voices(voices, language, customisable_only))
+    }
+
+    /// Lists all voices like [`list_voices()`], but returns the [`Response`] of the service as is once it has succeeded, so that its headers can be inspected. For example, the `X-Global-Transaction-Id` header identifies the request to IBM support
+    ///
+    /// [`list_voices()`]: Self::list_voices()
+    /// [`Response`]: reqwest::Response
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let response = tts.list_voices_raw().await?;
+    /// println!("{:?}", response.headers().get("x-global-transaction-id"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn list_voices_raw(&self) -> Result<Response, ListVoicesError> {
+        self.voices_response(None).await
+    }
+
+    async fn voices_response(
+        &self,
+        timeout: Option<Duration>,
+    ) -> Result<Response, ListVoicesError> {
+        let mut req = Request::new(Method::GET, voices_url(&self.service_url));
+        *req.timeout_mut() = timeout;
+
+        let client = self.get_client();
+        let response = client.execute(req).await.map_err(|e| match timeout {
+            Some(timeout) if e.is_timeout() => ListVoicesError::Timeout(timeout),
+            _ => ListVoicesError::ConnectionError(e),
+        })?;
+        let status = response.status();
+        if status == StatusCode::OK {
+            return Ok(response);
+        }
+        match list_voices_error(status) {
+            Some(error) => Err(error(WatsonError::from_response(response).await)),
+            None => Err(ListVoicesError::UnmappedResponse(status.as_u16())),
         }
     }
 
-    fn set_voices_path(uri: &mut Url) {
-        uri.set_path("v1/voices");
+    pub(crate) fn voice_url(
+        service_url: &Url,
+        voice: &WatsonVoice,
+        customisation_id: Option<&str>,
+    ) -> Url {
+        let mut url = service_url.clone();
+        url.set_path(&format!("v1/voices/{}", voice.id()));
+        if let Some(id) = customisation_id {
+            url.query_pairs_mut().append_pair("customization_id", id);
+        }
+        url
     }
 
     /// Returns information about the specified [`Voice`]. The information includes the [`name`], [`language`], [`gender`], and other details about the voice. Specify a customisation ID to obtain information for a custom model that is defined for the language of the specified voice. To list information about all available voices, use  [list_voices()](`Self::list_voices()`)
@@ -297,7 +818,9 @@ impl TextToSpeech<'_> {
     ///
     /// * `voice` - The particular [`WatsonVoice`] you want information about
     /// * `customisation_id` - The customisation ID (GUID) of a custom model for which information is to be returned. You must make the request with credentials for the instance of the service that owns the custom model. Omit the parameter to see information about the specified voice with no customisation
+    /// * `if_modified_since` - If set, the service returns [`NotModified304`] instead of the voice when it has not changed since that time. Use it to cheaply poll for changes
     ///
+    /// [`NotModified304`]: super::voices::errors::GetVoiceError::NotModified304
     /// [`WatsonVoice`]: super::voices::WatsonVoice
     /// [`Voice`]: super::voices::Voice
     /// [`name`]: super::voices::Voice::name
@@ -313,41 +836,140 @@ impl TextToSpeech<'_> {
     /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
     /// # let auth = IamAuthenticator::new("api_key").await?;
     /// # let tts = TextToSpeech::new(&auth, "service_url");
-    /// let kate = tts.get_voice(WatsonVoice::EnGbKateV3, None).await?;
+    /// let kate = tts.get_voice(WatsonVoice::EnGbKateV3, None, None).await?;
     /// println!("Gender: {}", kate.gender);
     /// # Ok(())
     /// # }
     /// ```
-
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
     pub async fn get_voice(
         &self,
         voice: WatsonVoice,
         customisation_id: Option<&str>,
+        if_modified_since: Option<SystemTime>,
     ) -> Result<Voice, GetVoiceError> {
-        let mut url = Url::parse(self.service_url).unwrap();
-        let id = voice.id();
-        Self::set_voices_path(&mut url);
-        let mut url = Url::parse(&format!("{}/{}", url, id)).unwrap();
-        url.set_query(customisation_id);
+        let url = Self::voice_url(&self.service_url, &voice, customisation_id);
         let mut req = Request::new(Method::GET, url);
+        ServiceClient::if_modified_since(&mut req, if_modified_since);
+        self.voice_response(req).await
+    }
 
-        if cfg!(feature = "http2") {
-            *req.version_mut() = Version::HTTP_2;
-        }
+    /// Returns information about a voice like [`get_voice()`], from the [`url`] that the service reported for it, for example in the results of [`list_voices()`]. Use it to look up a voice that this library has no [`WatsonVoice`] for
+    ///
+    /// # Parameters
+    ///
+    /// * `url` - The fully-qualified url of the voice. Its scheme, host and port must match those of the service url that the client was created with, otherwise [`InvalidUrl`] is returned without making a request
+    ///
+    /// [`get_voice()`]: Self::get_voice()
+    /// [`url`]: super::voices::Voice::url
+    /// [`list_voices()`]: Self::list_voices()
+    /// [`WatsonVoice`]: super::voices::WatsonVoice
+    /// [`InvalidUrl`]: super::voices::errors::GetVoiceError::InvalidUrl
+    ///
+    /// # Example
+    /// ``` no_run
+    /// # use ibm_watson::{
+    /// #     auth::IamAuthenticator,
+    /// #     tts::TextToSpeech,
+    /// # };
+    /// # async fn foo()-> Result<(), Box<dyn std::error::Error>> {
+    /// # let auth = IamAuthenticator::new("api_key").await?;
+    /// # let tts = TextToSpeech::new(&auth, "service_url");
+    /// let voices = tts.list_voices().await?;
+    /// let voice = tts.get_voice_by_url(&voices[0].url).await?;
+    /// println!("Gender: {}", voice.gender);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(service = "text_to_speech"), err)
+    )]
+    pub async fn get_voice_by_url(&self, url: &str) -> Result<Voice, GetVoiceError> {
+        let url = same_service_url(&self.service_url, url)
+            .ok_or_else(|| GetVoiceError::InvalidUrl(url.to_owned()))?;
+        self.voice_response(Request::new(Method::GET, url)).await
+    }
+
+    async fn voice_response(&self, req: Request) -> Result<Voice, GetVoiceError> {
         let client = self.get_client();
         let response = client.execute(req).await?;
-        assert_eq!(response.status(), 200);
-        match response.status() {
-            StatusCode::OK => {
-                let root: Voice = response.json().await.unwrap();
+        let status = response.status();
+        if status == StatusCode::OK {
+            return response
+                .json()
+                .await
+                .map_err(|e| GetVoiceError::DeserializationError(e.to_string()));
+        }
+        match get_voice_error(status) {
+            Some(error) => Err(error(WatsonError::from_response(response).await)),
+            None => Err(GetVoiceError::UnmappedResponse(status.as_u16())),
+        }
+    }
+}
 
-                Ok(root)
+pub(crate) fn filter_voices(
+    voices: Vec<Voice>,
+    language: Option<&str>,
+    customisable_only: bool,
+) -> Vec<Voice> {
+    voices
+        .into_iter()
+        .filter(|voice| !customisable_only || voice.customisable)
+        .filter(|voice| match language {
+            Some(language) => {
+                let tag = voice.language.as_bytes();
+                tag.len() >= language.len()
+                    && tag[..language.len()].eq_ignore_ascii_case(language.as_bytes())
+                    && matches!(tag.get(language.len()), None | Some(b'-'))
             }
-            StatusCode::NOT_ACCEPTABLE => Err(GetVoiceError::NotAcceptable406),
-            StatusCode::UNSUPPORTED_MEDIA_TYPE => Err(GetVoiceError::UnsupportedMediaType415),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(GetVoiceError::InternalServerError500),
-            StatusCode::SERVICE_UNAVAILABLE => Err(GetVoiceError::ServiceUnavailable503),
-            _ => Err(GetVoiceError::UnmappedResponse(response.status().as_u16())),
-        }
+            None => true,
+        })
+        .collect()
+}
+
+/// `url` parsed, if it has the same scheme, host and port as `service_url`, so that a url taken
+/// from a response cannot send the credentials of the client to another host
+pub(crate) fn same_service_url(service_url: &Url, url: &str) -> Option<Url> {
+    Url::parse(url)
+        .ok()
+        .filter(|url| url.origin() == service_url.origin())
+}
+
+#[derive(Deserialize)]
+pub(crate) struct VoiceList {
+    pub(crate) voices: Vec<Voice>,
+}
+
+/// The url that lists the voices. Shared by the async and blocking clients
+pub(crate) fn voices_url(service_url: &Url) -> Url {
+    let mut url = service_url.clone();
+    url.set_path("v1/voices");
+    url
+}
+
+/// The error for an unsuccessful response when getting a voice, if the status is one that the service documents
+pub(crate) fn get_voice_error(status: StatusCode) -> Option<fn(WatsonError) -> GetVoiceError> {
+    match status {
+        StatusCode::NOT_MODIFIED => Some(|_| GetVoiceError::NotModified304),
+        StatusCode::NOT_ACCEPTABLE => Some(GetVoiceError::NotAcceptable406),
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => Some(GetVoiceError::UnsupportedMediaType415),
+        StatusCode::INTERNAL_SERVER_ERROR => Some(GetVoiceError::InternalServerError500),
+        StatusCode::SERVICE_UNAVAILABLE => Some(GetVoiceError::ServiceUnavailable503),
+        _ => None,
+    }
+}
+
+/// The error for an unsuccessful response when listing voices, if the status is one that the service documents
+pub(crate) fn list_voices_error(status: StatusCode) -> Option<fn(WatsonError) -> ListVoicesError> {
+    match status {
+        StatusCode::NOT_ACCEPTABLE => Some(ListVoicesError::NotAcceptable406),
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => Some(ListVoicesError::UnsupportedMediaType415),
+        StatusCode::INTERNAL_SERVER_ERROR => Some(ListVoicesError::InternalServerError500),
+        StatusCode::SERVICE_UNAVAILABLE => Some(ListVoicesError::ServiceUnavailable503),
+        _ => None,
     }
 }
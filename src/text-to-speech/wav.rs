@@ -0,0 +1,139 @@
+use std::time::Duration;
+
+/// The lowest sampling rate that the service accepts for enrollment and prompt audio
+pub(crate) const MIN_SAMPLE_RATE: u32 = 16000;
+
+const WAVE_FORMAT_PCM: u16 = 0x0001;
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// What the header of a PCM WAV file says about its audio
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct WavInfo {
+    pub(crate) sample_rate: u32,
+    pub(crate) duration: Duration,
+}
+
+/// Reads the RIFF header and the `fmt ` and `data` chunks of a WAV file. Only the header is
+/// looked at, so the samples themselves are never decoded
+pub(crate) fn parse(audio: &[u8]) -> Result<WavInfo, String> {
+    check_riff(audio)?;
+    let mut format = None;
+    for (id, body, size) in chunks(audio) {
+        match id {
+            b"fmt " => {
+                let chunk = audio
+                    .get(body..body + 16)
+                    .ok_or_else(|| "The WAV file has a truncated fmt chunk".to_owned())?;
+                let tag = u16::from_le_bytes([chunk[0], chunk[1]]);
+                if tag != WAVE_FORMAT_PCM && tag != WAVE_FORMAT_EXTENSIBLE {
+                    return Err(format!(
+                        "The WAV file is not PCM encoded (format tag {tag})"
+                    ));
+                }
+                let sample_rate = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+                let byte_rate = u32::from_le_bytes([chunk[8], chunk[9], chunk[10], chunk[11]]);
+                format = Some((sample_rate, byte_rate));
+            }
+            b"data" => {
+                let (sample_rate, byte_rate) = format
+                    .ok_or_else(|| "The WAV file has no fmt chunk before its data".to_owned())?;
+                if byte_rate == 0 {
+                    return Err("The WAV file has a byte rate of 0".to_owned());
+                }
+                let size = data_size(audio, body, size);
+                let duration = Duration::from_secs_f64(size as f64 / f64::from(byte_rate));
+                return Ok(WavInfo {
+                    sample_rate,
+                    duration,
+                });
+            }
+            _ => {}
+        }
+    }
+    Err("The WAV file has no data chunk".to_owned())
+}
+
+/// Joins WAV files that share the same format into one, by appending the samples of the others
+/// to the first and fixing up the sizes in its header
+pub(crate) fn concatenate(parts: &[impl AsRef<[u8]>]) -> Result<Vec<u8>, String> {
+    let mut joined = Vec::new();
+    let mut header_len = 0;
+    for part in parts {
+        let part = part.as_ref();
+        check_riff(part)?;
+        let (body, size) = chunks(part)
+            .find(|(id, ..)| *id == b"data")
+            .map(|(_, body, size)| (body, data_size(part, body, size)))
+            .ok_or_else(|| "The WAV file has no data chunk".to_owned())?;
+        if header_len == 0 {
+            header_len = body;
+            joined.extend_from_slice(&part[..body]);
+        }
+        joined.extend_from_slice(&part[body..body + size]);
+    }
+    if header_len == 0 {
+        return Err("There are no WAV files to join".to_owned());
+    }
+    let data_size = u32::try_from(joined.len() - header_len)
+        .map_err(|_| "The joined WAV file is larger than 4 GiB".to_owned())?;
+    if data_size & 1 == 1 {
+        joined.push(0);
+    }
+    let riff_size = u32::try_from(joined.len() - 8)
+        .map_err(|_| "The joined WAV file is larger than 4 GiB".to_owned())?;
+    joined[header_len - 4..header_len].copy_from_slice(&data_size.to_le_bytes());
+    joined[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    Ok(joined)
+}
+
+fn check_riff(audio: &[u8]) -> Result<(), String> {
+    if audio.len() < 12 || &audio[0..4] != b"RIFF" || &audio[8..12] != b"WAVE" {
+        return Err("The audio is not a WAV file".to_owned());
+    }
+    Ok(())
+}
+
+/// The id, the offset of the body and the size that the header gives for each chunk of a WAV file
+fn chunks(audio: &[u8]) -> impl Iterator<Item = (&[u8], usize, usize)> {
+    let mut offset = 12;
+    std::iter::from_fn(move || {
+        let header = audio.get(offset..offset + 8)?;
+        let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let body = offset + 8;
+        // Chunks are padded to an even number of bytes
+        offset = body.saturating_add(size).saturating_add(size & 1);
+        Some((&header[0..4], body, size))
+    })
+}
+
+/// The size of the `data` chunk that starts at `body`. Files that were written as a stream may
+/// not know the size of their data, so it runs to the end of the file if the size is unset or
+/// too large
+fn data_size(audio: &[u8], body: usize, size: usize) -> usize {
+    let available = audio.len() - body;
+    if size == 0 || size > available {
+        available
+    } else {
+        size
+    }
+}
+
+/// Checks that `audio` is a PCM WAV file that the service will accept: sampled at 16 kHz or more
+/// and no longer than `max_duration`
+pub(crate) fn validate(audio: &[u8], max_duration: Duration) -> Result<WavInfo, String> {
+    let info = parse(audio)?;
+    if info.sample_rate < MIN_SAMPLE_RATE {
+        return Err(format!(
+            "The audio is sampled at {} Hz, but at least {MIN_SAMPLE_RATE} Hz is required",
+            info.sample_rate
+        ));
+    }
+    if info.duration > max_duration {
+        return Err(format!(
+            "The audio is {:.1} seconds long, but at most {} seconds are allowed",
+            info.duration.as_secs_f64(),
+            max_duration.as_secs()
+        ));
+    }
+    Ok(info)
+}